@@ -1,11 +1,14 @@
-use cosmwasm_std::{ContractInfoResponse, DepsMut, Env, MessageInfo, Response, WasmQuery};
+use cosmwasm_std::{ContractInfoResponse, DepsMut, Env, Event, MessageInfo, Response, WasmQuery};
+use cw2::get_contract_version;
 use cw721::{ContractInfoResponse as Cw721ContractInfoResponse};
 use cw_utils::nonpayable;
+use semver::Version;
 use dega_inj::cw721::{CollectionInfo, InstantiateMsg, MigrateMsg, RoyaltySettings};
-use dega_inj::helpers::{save_item_wrapped, set_contract_version_wrapped};
+use dega_inj::helpers::{save_item_wrapped, set_contract_version_wrapped, share_validate};
 use crate::error::ContractError;
-use crate::helpers::{initialize_owner_wrapped, share_validate};
+use crate::helpers::initialize_owner_wrapped;
 use crate::state::DegaCw721Contract;
+use crate::upgrades::run_upgrades;
 
 const CONTRACT_NAME: &str = "dega-cw721";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -88,54 +91,54 @@ impl<'a> DegaCw721Contract<'a>
         )
     }
 
-    pub(crate) fn migrate(&self, _deps: DepsMut, _env: Env, _migrate_msg: MigrateMsg) -> Result<Response, ContractError> {
-
-        Ok(Response::new())
-
-        // if migrate_msg.is_dev {
-        //
-        //     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
-        //         .map_err(|e| ContractError::Std("Unable to set contract version".to_string(), e))?;
-        //
-        //     Ok(
-        //         Response::new()
-        //             .add_attribute("is_dev", "true")
-        //             .add_attribute("dev_version", migrate_msg.dev_version)
-        //     )
-        // } else {
-        //     let prev_contract_version = cw2::get_contract_version(deps.storage)
-        //         .map_err(|e| ContractError::Std("Unable to get contract version".to_string(), e))?;
-        //
-        //     let valid_contract_names = [CONTRACT_NAME.to_string()];
-        //     if !valid_contract_names.contains(&prev_contract_version.contract) {
-        //         return Err(ContractError::Migration("Invalid contract name for migration".to_string()));
-        //     }
-        //
-        //     #[allow(clippy::cmp_owned)]
-        //     if prev_contract_version.version >= CONTRACT_VERSION.to_string() {
-        //         return Err(ContractError::Migration("Must upgrade contract version".to_string()));
-        //     }
-        //
-        //     let mut response = Response::new();
-        //
-        //     #[allow(clippy::cmp_owned)]
-        //     if prev_contract_version.version < "1.0.0".to_string() {
-        //         response = crate::upgrades::v1_0_0::upgrade(deps.branch(), &env, response)?;
-        //     }
-        //
-        //     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
-        //         .map_err(|e| ContractError::Std("Unable to set contract version".to_string(), e))?;
-        //
-        //     response = response.add_event(
-        //         Event::new("migrate")
-        //             .add_attribute("from_name", prev_contract_version.contract)
-        //             .add_attribute("from_version", prev_contract_version.version)
-        //             .add_attribute("to_name", CONTRACT_NAME)
-        //             .add_attribute("to_version", CONTRACT_VERSION),
-        //     );
-        //
-        //     Ok(response)
-        // }
+    pub(crate) fn migrate(&self, mut deps: DepsMut, env: Env, migrate_msg: MigrateMsg) -> Result<Response, ContractError> {
+
+        let prev_contract_version = get_contract_version(deps.storage)
+            .map_err(|e| ContractError::Std("Unable to get contract version".to_string(), e))?;
+
+        let valid_contract_names = [CONTRACT_NAME.to_string()];
+        if !valid_contract_names.contains(&prev_contract_version.contract) {
+            return Err(ContractError::Migration(
+                format!("Invalid contract name for migration: {}", prev_contract_version.contract)));
+        }
+
+        let prev_version = Version::parse(&prev_contract_version.version)
+            .map_err(|e| ContractError::Migration(
+                format!("Invalid stored contract version ({}): {}", prev_contract_version.version, e)))?;
+
+        let new_version = Version::parse(CONTRACT_VERSION)
+            .map_err(|e| ContractError::Migration(
+                format!("Invalid new contract version ({}): {}", CONTRACT_VERSION, e)))?;
+
+        // Dev migrations may redeploy the same version, but never downgrade
+        if new_version < prev_version || (new_version == prev_version && !migrate_msg.is_dev) {
+            return Err(ContractError::Migration(format!(
+                "Must upgrade contract version | Stored version: {} | New version: {}",
+                prev_version,
+                new_version,
+            )));
+        }
+
+        let mut response = run_upgrades(deps.branch(), &env, &prev_version, &new_version, Response::new())?;
+
+        set_contract_version_wrapped(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
+            .map_err(|e| ContractError::Std("Unable to set contract version".to_string(), e))?;
+
+        let mut event = Event::new("migrate")
+            .add_attribute("from_name", prev_contract_version.contract)
+            .add_attribute("from_version", prev_contract_version.version)
+            .add_attribute("to_name", CONTRACT_NAME)
+            .add_attribute("to_version", CONTRACT_VERSION);
+
+        if migrate_msg.is_dev {
+            event = event
+                .add_attribute("is_dev", "true")
+                .add_attribute("dev_version", migrate_msg.dev_version);
+        }
+
+        response = response.add_event(event);
+
+        Ok(response)
     }
 }
 
@@ -289,4 +292,78 @@ mod tests {
         assert!(err.to_string().contains("Collection must be instantiated by contract"));
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn migration() {
+        let contract = DegaCw721Contract::default();
+
+        let migrate_msg = MigrateMsg {
+            is_dev: false,
+            dev_version: "".to_string(),
+        };
+
+        // Normal upgrade from an older version runs the upgrade steps and bumps the version
+        let mut deps = mock_dependencies();
+        template_collection(&mut deps, mock_env(), &contract).unwrap();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.9.0").unwrap();
+        let response = contract.migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap();
+        assert!(response.events.iter().any(|event| event.ty == "migrate-1.0.0"));
+        let migrate_event = response.events.iter().find(|event| event.ty == "migrate").unwrap();
+        assert!(migrate_event.attributes.iter().any(|attr| attr.key == "from_version" && attr.value == "0.9.0"));
+        assert!(migrate_event.attributes.iter().any(|attr| attr.key == "to_version" && attr.value == CONTRACT_VERSION));
+        assert_eq!(get_contract_version(&deps.storage).unwrap().version, CONTRACT_VERSION);
+
+        // Migrating to the same version is refused outside of dev mode
+        let err = contract.migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        assert!(err.to_string().contains("Must upgrade contract version"));
+
+        // Dev mode allows redeploying the same version
+        let response = contract.migrate(deps.as_mut(), mock_env(), MigrateMsg {
+            is_dev: true,
+            dev_version: "dev-build-2".to_string(),
+        }).unwrap();
+        let migrate_event = response.events.iter().find(|event| event.ty == "migrate").unwrap();
+        assert!(migrate_event.attributes.iter().any(|attr| attr.key == "dev_version" && attr.value == "dev-build-2"));
+        assert!(!response.events.iter().any(|event| event.ty == "migrate-1.0.0"));
+
+        // Downgrades are refused, even in dev mode, and use semver rather than string ordering
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.10.0").unwrap();
+        let err = contract.migrate(deps.as_mut(), mock_env(), MigrateMsg {
+            is_dev: true,
+            dev_version: "dev".to_string(),
+        }).unwrap_err();
+        assert!(err.to_string().contains("Must upgrade contract version"));
+
+        // Wrong contract name
+        cw2::set_contract_version(deps.as_mut().storage, "some-other-contract", "0.1.0").unwrap();
+        let err = contract.migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Migration("Invalid contract name for migration: some-other-contract".to_string()));
+
+        // Unparseable stored version
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "not-a-version").unwrap();
+        let err = contract.migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        assert!(err.to_string().contains("Invalid stored contract version (not-a-version)"));
+    }
+
+    #[test]
+    fn migration_errors() {
+        let contract = DegaCw721Contract::default();
+
+        let migrate_msg = MigrateMsg {
+            is_dev: false,
+            dev_version: "".to_string(),
+        };
+
+        // No contract version stored
+        let err = contract.migrate(mock_dependencies().as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        assert!(err.to_string().contains("Unable to get contract version"));
+
+        // Unable to set the new contract version
+        let mut deps = mock_dependencies();
+        template_collection(&mut deps, mock_env(), &contract).unwrap();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.9.0").unwrap();
+        set_contract_version_error(true);
+        let err = contract.migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        set_contract_version_error(false);
+        assert!(err.to_string().contains("Unable to set contract version"));
+    }
+}
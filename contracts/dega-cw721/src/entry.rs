@@ -84,6 +84,7 @@ mod tests {
         let token_info_response: NftInfoResponse<Extension> = from_json(query_binary).unwrap();
         assert_eq!(token_info_response.token_uri, token_uri, "checking for the newly minted token with the correct URI");
 
+        cw2::set_contract_version(deps.as_mut().storage, "dega-cw721", "0.9.0").unwrap();
         migrate(deps.as_mut(), mock_env(), MigrateMsg {
             is_dev: false,
             dev_version: "".to_string(),
@@ -3,8 +3,9 @@ use cw721_base::Extension;
 use cw721_base::state::TokenInfo;
 use cw_utils::nonpayable;
 use dega_inj::cw721::{ExecuteMsg, NftParams, RoyaltySettings, UpdateCollectionInfoMsg};
-use dega_inj::helpers::{load_item_wrapped, save_item_wrapped};
-use crate::helpers::{assert_minter_owner, get_dega_minter_settings, get_substring_before_bracket, increment_tokens_wrapped, is_minter_admin, share_validate};
+use dega_inj::minter::Role;
+use dega_inj::helpers::{load_item_wrapped, save_item_wrapped, share_validate};
+use crate::helpers::{assert_minter_owner, get_dega_minter_settings, get_owner_minter, get_substring_before_bracket, has_minter_role, increment_tokens_wrapped};
 use crate::error::{check_for_better_base_err_msg, ContractError};
 use crate::state::DegaCw721Contract;
 
@@ -138,12 +139,28 @@ impl<'a> DegaCw721Contract<'a>
         update_collection_msg: UpdateCollectionInfoMsg,
     ) -> Result<Response, ContractError> {
 
-        let is_minter_admin = is_minter_admin(&deps.as_ref(), &info.sender)
-            .map_err(|e| ContractError::Std("Unable to check for admin permission".to_string(), e))?;
+        // the minter itself forwards royalty updates approved by its admins
+        let sent_by_minter = get_owner_minter(deps.storage)
+            .map_or(false, |minter_addr| minter_addr == info.sender);
 
-        // only minter admin can update collection info
-        if !is_minter_admin {
-            return Err(ContractError::Unauthorized("Only minter admins can update collection info".to_string()));
+        if !sent_by_minter {
+            let is_collection_editor = has_minter_role(&deps.as_ref(), &info.sender, Role::CollectionEditor)
+                .map_err(|e| ContractError::Std("Unable to check for collection editor permission".to_string(), e))?;
+
+            // only minter admins and collection editors can update collection info and royalties
+            if !is_collection_editor {
+                return Err(ContractError::Unauthorized("Only minter admins and collection editors can update collection info".to_string()));
+            }
+
+            if update_collection_msg.royalty_settings.is_some() {
+                let minter_config = get_dega_minter_settings(&deps.as_ref())
+                    .map_err(|e| ContractError::Std("Unable to get minter settings".to_string(), e))?;
+
+                // with multisig approval enabled on the minter, royalty changes must be proposed there
+                if minter_config.dega_minter_settings.multisig.is_some() {
+                    return Err(ContractError::Unauthorized("Royalty changes must be proposed on the minter while multisig approval is enabled".to_string()));
+                }
+            }
         }
 
         let mut event =
@@ -296,8 +313,9 @@ mod tests {
     use cw_ownable::{Action, get_ownership, update_ownership};
     use cw_utils::Expiration::Never;
     use dega_inj::cw721::{CollectionInfoResponse, DegaAllNftInfoResponse, QueryMsg, RoyaltySettingsResponse};
+    use dega_inj::minter::MultisigSettings;
     use dega_inj::test_helpers::{add_load_error_item, add_save_error_item, clear_load_error_items, clear_save_error_items};
-    use crate::test_helpers::{NFT_OWNER_ADDR, INJ_DENOM, MINTER_CONTRACT_ADDR, template_collection, MINTER_ADMIN_ONE_ADDR, MINTER_CONFIG_QUERY_ERROR, MINTING_PAUSED, INCREMENT_TOKENS_ERROR, MINTER_IS_ADMIN_QUERY_ERROR};
+    use crate::test_helpers::{NFT_OWNER_ADDR, INJ_DENOM, MINTER_CONTRACT_ADDR, template_collection, MINTER_ADMIN_ONE_ADDR, MINTER_CONFIG_QUERY_ERROR, MINTING_PAUSED, INCREMENT_TOKENS_ERROR, MINTER_HAS_ROLE_QUERY_ERROR, MINTER_MULTISIG, MINTER_ROLES};
     #[allow(unused_imports)]
     use super::*;
 
@@ -516,11 +534,11 @@ mod tests {
         }).unwrap_err().to_string();
         assert!(err_string.contains("No owner set"));
         assert!(err_string.contains("Error getting minter address"));
-        assert!(err_string.contains("Unable to check for admin permission"));
+        assert!(err_string.contains("Unable to check for collection editor permission"));
         assert_eq!(contract.collection_info.load(&deps.storage).unwrap().description, default_collection_info.description);
 
         // Check that we error when we get an error back from the minter query for admins
-        MINTER_IS_ADMIN_QUERY_ERROR.set(true);
+        MINTER_HAS_ROLE_QUERY_ERROR.set(true);
         deps = mock_dependencies();
         template_collection(&mut deps, mock_env(), &contract).unwrap();
         err_string = contract.execute_update_collection_info(deps.as_mut(), mock_env(), random_user_msg_info.clone(), UpdateCollectionInfoMsg {
@@ -529,11 +547,11 @@ mod tests {
             external_link: None,
             royalty_settings: None,
         }).unwrap_err().to_string();
-        assert!(err_string.contains("Mock minter is admin query error"));
-        assert!(err_string.contains("Error during minter admin check query"));
-        assert!(err_string.contains("Unable to check for admin permission"));
+        assert!(err_string.contains("Mock minter has role query error"));
+        assert!(err_string.contains("Error during minter role check query"));
+        assert!(err_string.contains("Unable to check for collection editor permission"));
         assert_eq!(contract.collection_info.load(&deps.storage).unwrap().description, default_collection_info.description);
-        MINTER_IS_ADMIN_QUERY_ERROR.set(false);
+        MINTER_HAS_ROLE_QUERY_ERROR.set(false);
 
         // Check that we get an error when we try to update the settings as a non minter admin
         deps = mock_dependencies();
@@ -544,9 +562,44 @@ mod tests {
             external_link: None,
             royalty_settings: None,
         }).unwrap_err().to_string();
-        assert!(err_string.contains("Only minter admins can update collection info"));
+        assert!(err_string.contains("Only minter admins and collection editors can update collection info"));
         assert_eq!(contract.collection_info.load(&deps.storage).unwrap().description, default_collection_info.description);
 
+        // Collection editors can update the collection info without being minter admins
+        MINTER_ROLES.set(vec![(random_user_addr.to_string(), Role::CollectionEditor)]);
+        contract.execute_update_collection_info(deps.as_mut(), mock_env(), random_user_msg_info.clone(), UpdateCollectionInfoMsg {
+            description: Some(new_description_string.clone()),
+            image: None,
+            external_link: None,
+            royalty_settings: None,
+        }).unwrap();
+        assert_eq!(contract.collection_info.load(&deps.storage).unwrap().description, new_description_string);
+        MINTER_ROLES.set(vec![]);
+
+        // Royalty changes can't be made directly while multisig approval is enabled on the minter
+        MINTER_MULTISIG.set(Some(MultisigSettings {
+            threshold: 2,
+            proposal_duration: 3600,
+        }));
+        err_string = contract.execute_update_collection_info(deps.as_mut(), mock_env(), minter_admin_msg_info.clone(), UpdateCollectionInfoMsg {
+            description: None,
+            image: None,
+            external_link: None,
+            royalty_settings: Some(None),
+        }).unwrap_err().to_string();
+        assert!(err_string.contains("Royalty changes must be proposed on the minter while multisig approval is enabled"));
+        assert!(contract.collection_info.load(&deps.storage).unwrap().royalty_settings.is_some());
+
+        // The minter forwards the royalty changes its admins approved
+        contract.execute_update_collection_info(deps.as_mut(), mock_env(), mock_info(MINTER_CONTRACT_ADDR, &[]), UpdateCollectionInfoMsg {
+            description: None,
+            image: None,
+            external_link: None,
+            royalty_settings: Some(None),
+        }).unwrap();
+        assert!(contract.collection_info.load(&deps.storage).unwrap().royalty_settings.is_none());
+        MINTER_MULTISIG.set(None);
+
         // Error when we can't load the old collection info
         add_load_error_item(&contract.collection_info);
         deps = mock_dependencies();
@@ -1,20 +1,10 @@
-use cosmwasm_std::{Addr, Api, Decimal, Deps, Empty, StdError, StdResult, Storage};
+use cosmwasm_std::{Addr, Api, Deps, Empty, StdError, StdResult, Storage};
 use cw721_base::Cw721Contract;
 use cw_ownable::{get_ownership, Ownership, OwnershipError};
 use dega_inj::cw721::Extension;
-use dega_inj::minter::DegaMinterConfigResponse;
+use dega_inj::minter::{DegaMinterConfigResponse, Role};
 
 
-pub(crate) fn share_validate(share: Decimal) -> StdResult<Decimal> {
-    if share > Decimal::one() {
-        return Err(StdError::generic_err(
-            "Share cannot be greater than 100%".to_string(),
-        ));
-    }
-
-    Ok(share)
-}
-
 pub(crate) fn get_owner_minter(storage: &dyn Storage) -> StdResult<Addr> {
     let ownership = get_ownership_wrapped(storage)
         .map_err(|e| StdError::generic_err(format!("Error during query for owner minter: {}", e)))?;
@@ -53,19 +43,21 @@ pub(crate) fn get_dega_minter_settings(deps: &Deps) -> StdResult<DegaMinterConfi
     Ok(config_response)
 }
 
-pub(crate) fn is_minter_admin(deps: &Deps, address: &Addr) -> StdResult<bool> {
+// Minter admins hold every role
+pub(crate) fn has_minter_role(deps: &Deps, address: &Addr, role: Role) -> StdResult<bool> {
 
     let minter_addr = get_owner_minter(deps.storage)
         .map_err(|e| StdError::generic_err(format!("Error getting minter address: {}", e)))?;
 
-    let is_admin: bool = deps.querier.query_wasm_smart(
+    let has_role: bool = deps.querier.query_wasm_smart(
         minter_addr.clone(),
-        &dega_inj::minter::QueryMsg::IsAdmin {
+        &dega_inj::minter::QueryMsg::HasRole {
             address: address.to_string(),
+            role,
         },
-    ).map_err(|e| StdError::generic_err(format!("Error during minter admin check query: {}", e)))?;
+    ).map_err(|e| StdError::generic_err(format!("Error during minter role check query: {}", e)))?;
 
-    Ok(is_admin)
+    Ok(has_role)
 }
 
 pub(crate) fn initialize_owner_wrapped(
@@ -121,7 +113,7 @@ mod tests {
     use super::*;
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use crate::state::DegaCw721Contract;
-    use crate::test_helpers::{COLLECTION_CONTRACT_ADDR, MINTER_SIGNER_PUBKEY, template_collection, GET_OWNERSHIP_ERROR, MINTING_PAUSED, MINTER_CONFIG_QUERY_ERROR, MINTER_IS_ADMIN_QUERY_ERROR, MINTER_ADMIN_LIST, MINTER_ADMIN_ONE_ADDR, MINTER_ADMIN_TWO_ADDR};
+    use crate::test_helpers::{COLLECTION_CONTRACT_ADDR, MINTER_SIGNER_PUBKEY, template_collection, GET_OWNERSHIP_ERROR, MINTING_PAUSED, MINTER_CONFIG_QUERY_ERROR, MINTER_HAS_ROLE_QUERY_ERROR, MINTER_ADMIN_LIST, MINTER_ADMIN_ONE_ADDR, MINTER_ADMIN_TWO_ADDR, MINTER_ROLES};
     #[test]
     fn normal_minter_queries() {
         let mut deps = mock_dependencies();
@@ -144,16 +136,26 @@ mod tests {
         let admin_one_addr = deps.api.addr_validate(MINTER_ADMIN_ONE_ADDR).unwrap();
         let admin_two_addr = deps.api.addr_validate(MINTER_ADMIN_TWO_ADDR).unwrap();
 
-        assert!(is_minter_admin(&deps.as_ref(), &admin_one_addr).unwrap());
-        assert!(!is_minter_admin(&deps.as_ref(), &admin_two_addr).unwrap());
+        let is_editor = |address: &Addr| has_minter_role(&deps.as_ref(), address, Role::CollectionEditor).unwrap();
+
+        assert!(is_editor(&admin_one_addr));
+        assert!(!is_editor(&admin_two_addr));
 
         MINTER_ADMIN_LIST.set(vec![MINTER_ADMIN_TWO_ADDR.to_string()]);
-        assert!(!is_minter_admin(&deps.as_ref(), &admin_one_addr).unwrap());
-        assert!(is_minter_admin(&deps.as_ref(), &admin_two_addr).unwrap());
+        assert!(!is_editor(&admin_one_addr));
+        assert!(is_editor(&admin_two_addr));
 
         MINTER_ADMIN_LIST.set(vec![MINTER_ADMIN_ONE_ADDR.to_string(), MINTER_ADMIN_TWO_ADDR.to_string()]);
-        assert!(is_minter_admin(&deps.as_ref(), &admin_one_addr).unwrap());
-        assert!(is_minter_admin(&deps.as_ref(), &admin_two_addr).unwrap());
+        assert!(is_editor(&admin_one_addr));
+        assert!(is_editor(&admin_two_addr));
+
+        // Roles granted without being an admin
+        MINTER_ADMIN_LIST.set(vec![]);
+        MINTER_ROLES.set(vec![(MINTER_ADMIN_ONE_ADDR.to_string(), Role::Pauser), (MINTER_ADMIN_TWO_ADDR.to_string(), Role::CollectionEditor)]);
+        assert!(!is_editor(&admin_one_addr));
+        assert!(is_editor(&admin_two_addr));
+        MINTER_ROLES.set(vec![]);
+        MINTER_ADMIN_LIST.set(vec![MINTER_ADMIN_ONE_ADDR.to_string()]);
     }
 
 
@@ -225,17 +227,17 @@ mod tests {
 
         // Ownership error (unable to get minter address) during admin check
         GET_OWNERSHIP_ERROR.set(true);
-        err_string = is_minter_admin(&deps.as_ref(), &admin_query_addr).unwrap_err().to_string();
+        err_string = has_minter_role(&deps.as_ref(), &admin_query_addr, Role::CollectionEditor).unwrap_err().to_string();
         assert!(err_string.contains("Mock get ownership error"));
         assert!(err_string.contains("Error during query for owner minter"));
         assert!(err_string.contains("Error getting minter address"));
         GET_OWNERSHIP_ERROR.set(false);
 
-        MINTER_IS_ADMIN_QUERY_ERROR.set(true);
-        err_string = is_minter_admin(&deps.as_ref(), &admin_query_addr).unwrap_err().to_string();
-        assert!(err_string.contains("Mock minter is admin query error"));
-        assert!(err_string.contains("Error during minter admin check query"));
-        MINTER_IS_ADMIN_QUERY_ERROR.set(false);
+        MINTER_HAS_ROLE_QUERY_ERROR.set(true);
+        err_string = has_minter_role(&deps.as_ref(), &admin_query_addr, Role::CollectionEditor).unwrap_err().to_string();
+        assert!(err_string.contains("Mock minter has role query error"));
+        assert!(err_string.contains("Error during minter role check query"));
+        MINTER_HAS_ROLE_QUERY_ERROR.set(false);
     }
 
     #[test]
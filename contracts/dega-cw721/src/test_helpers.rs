@@ -3,7 +3,7 @@ use cosmwasm_std::{Binary, ContractInfoResponse, ContractResult, Decimal, Deps,
 use cosmwasm_std::testing::{MOCK_CONTRACT_ADDR, mock_env, mock_info, MockApi, MockQuerier, MockStorage};
 use cw721::{OwnerOfResponse, TokensResponse};
 use dega_inj::cw721::{CollectionInfoResponse, InstantiateMsg, QueryMsg, RoyaltySettingsResponse};
-use dega_inj::minter::{AdminsResponse, DegaMinterConfigResponse, DegaMinterConfigSettings};
+use dega_inj::minter::{AdminsResponse, DegaMinterConfigResponse, DegaMinterConfigSettings, MintRequestEncoding, MultisigSettings, OverpaymentMode, Role, SignatureScheme};
 use crate::error::ContractError;
 
 use crate::state::DegaCw721Contract;
@@ -26,10 +26,12 @@ thread_local! {
     pub(crate) static INITIALIZE_OWNER_ERROR: Cell<bool> = Cell::new(false);
     pub(crate) static GET_OWNERSHIP_ERROR: Cell<bool> = Cell::new(false);
     pub(crate) static MINTING_PAUSED: Cell<bool> = Cell::new(false);
+    pub(crate) static MINTER_MULTISIG: RefCell<Option<MultisigSettings>> = RefCell::new(None);
     pub(crate) static MINTER_ADMIN_LIST: RefCell<Vec<String>> = RefCell::new(vec![MINTER_ADMIN_ONE_ADDR.to_string()]);
     pub(crate) static MINTER_CONFIG_QUERY_ERROR: Cell<bool> = Cell::new(false);
     pub(crate) static MINTER_ADMINS_QUERY_ERROR: Cell<bool> = Cell::new(false);
-    pub(crate) static MINTER_IS_ADMIN_QUERY_ERROR: Cell<bool> = Cell::new(false);
+    pub(crate) static MINTER_ROLES: RefCell<Vec<(String, Role)>> = RefCell::new(vec![]);
+    pub(crate) static MINTER_HAS_ROLE_QUERY_ERROR: Cell<bool> = Cell::new(false);
     pub(crate) static INCREMENT_TOKENS_ERROR: Cell<bool> = Cell::new(false);
 }
 
@@ -136,13 +138,26 @@ fn mock_query_minter(query: dega_inj::minter::QueryMsg) -> ContractResult<Binary
                         dega_minter_settings: DegaMinterConfigSettings {
                             signer_pub_key: MINTER_SIGNER_PUBKEY.to_string(),
                             minting_paused: MINTING_PAUSED.get(),
+                            signer_address: None,
+                            signature_scheme: SignatureScheme::Sha256,
+                            mint_request_encoding: MintRequestEncoding::Json,
+                            revenue_splits: vec![],
+                            platform_fee: None,
+                            max_supply: None,
+                            max_mints_per_wallet: None,
+                            public_sale: None,
+                            allowlist_merkle_root: None,
+                            dutch_auction: None,
+                            overpayment_mode: OverpaymentMode::Strict,
+                            admin_action_delay: 0,
+                            multisig: MINTER_MULTISIG.with(|cell| { cell.borrow().clone() }),
                         },
                         collection_address: COLLECTION_CONTRACT_ADDR.to_string(),
                     }
                 ).unwrap())
             }
         },
-        dega_inj::minter::QueryMsg::Admins { } => {
+        dega_inj::minter::QueryMsg::Admins { .. } => {
             if MINTER_ADMINS_QUERY_ERROR.get() {
                 ContractResult::Err("Mock minter admins query error".to_string())
             } else {
@@ -155,15 +170,16 @@ fn mock_query_minter(query: dega_inj::minter::QueryMsg) -> ContractResult<Binary
             }
         },
 
-        dega_inj::minter::QueryMsg::IsAdmin { address } => {
+        dega_inj::minter::QueryMsg::HasRole { address, role } => {
 
-            if MINTER_IS_ADMIN_QUERY_ERROR.get() {
-                ContractResult::Err("Mock minter is admin query error".to_string())
+            if MINTER_HAS_ROLE_QUERY_ERROR.get() {
+                ContractResult::Err("Mock minter has role query error".to_string())
             } else {
                 let admin_list = MINTER_ADMIN_LIST.with(|cell| { cell.borrow().clone() });
+                let roles = MINTER_ROLES.with(|cell| { cell.borrow().clone() });
 
                 ContractResult::Ok(to_json_binary(
-                    &admin_list.contains(&address)
+                    &(admin_list.contains(&address) || roles.contains(&(address, role)))
                 ).unwrap())
             }
         },
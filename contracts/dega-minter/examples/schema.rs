@@ -2,7 +2,7 @@ use std::env::current_dir;
 use std::fs::create_dir_all;
 
 use cosmwasm_schema::{cw_serde, export_schema, export_schema_with_title, remove_schemas, schema_for};
-use dega_inj::minter::{AdminsResponse, CheckSigResponse, DegaMinterConfigResponse, InstantiateMsg};
+use dega_inj::minter::{AdminsResponse, CheckAllowlistResponse, CheckSigResponse, CurrentPhaseResponse, CurrentPriceResponse, DegaMinterConfigResponse, InstantiateMsg, MintCountResponse, PendingAdminActionsResponse, PhasesResponse, ProposalResponse, ProposalsResponse, RolesResponse, SignersResponse, SupplyResponse, UuidCountResponse, UuidsResponse, UuidStatusResponse};
 use dega_inj::minter::MigrateMsg;
 use dega_inj::minter::ExecuteMsg;
 use dega_inj::minter::QueryMsg;
@@ -11,7 +11,21 @@ use dega_inj::minter::QueryMsg;
 struct QueryResponses {
     dega_minter_config_response: DegaMinterConfigResponse,
     check_sig_response: CheckSigResponse,
+    check_allowlist_response: CheckAllowlistResponse,
     admins_response: AdminsResponse,
+    roles_response: RolesResponse,
+    signers_response: SignersResponse,
+    supply_response: SupplyResponse,
+    mint_count_response: MintCountResponse,
+    phases_response: PhasesResponse,
+    current_phase_response: CurrentPhaseResponse,
+    current_price_response: CurrentPriceResponse,
+    uuid_status_response: UuidStatusResponse,
+    uuids_response: UuidsResponse,
+    uuid_count_response: UuidCountResponse,
+    pending_admin_actions_response: PendingAdminActionsResponse,
+    proposal_response: ProposalResponse,
+    proposals_response: ProposalsResponse,
 }
 
 fn main() {
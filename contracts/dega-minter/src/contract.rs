@@ -1,5 +1,7 @@
-use cosmwasm_std::{DepsMut, Empty, Env, MessageInfo, Reply, Response, SubMsg, WasmMsg};
+use cosmwasm_std::{DepsMut, Empty, Env, Event, MessageInfo, Reply, Response, SubMsg, WasmMsg};
+use cw2::get_contract_version;
 use cw_utils::parse_reply_instantiate_data;
+use semver::Version;
 
 use dega_inj::minter::{InstantiateMsg, MigrateMsg};
 
@@ -9,7 +11,9 @@ use crate::state::{ADMIN_LIST, DEGA_MINTER_SETTINGS};
 use dega_inj::cw721::{InstantiateMsg as DegaCw721InstantiateMsg};
 use dega_inj::helpers::{save_item_wrapped, save_map_item_wrapped, set_contract_version_wrapped, to_json_binary_wrapped};
 
-use crate::helpers::verify_compressed_pub_key;
+use crate::helpers::{validate_dutch_auction, validate_multisig, validate_platform_fee, validate_public_sale, validate_revenue_splits, verify_compressed_pub_key};
+use crate::merkle::parse_merkle_hash;
+use crate::upgrades::run_upgrades;
 
 
 const CONTRACT_NAME: &str = "dega-minter";
@@ -58,10 +62,47 @@ pub(crate) fn run_instantiate(
     verify_compressed_pub_key(dega_minter_settings.signer_pub_key.clone())
         .map_err(|e| ContractError::Std("Invalid signer compressed public key".to_string(), e))?;
 
+    if let Some(signer_address) = &dega_minter_settings.signer_address {
+        deps.api.addr_validate(signer_address)
+            .map_err(|e| ContractError::Std("Invalid signer address".to_string(), e))?;
+    }
+
+    validate_revenue_splits(deps.api, &dega_minter_settings.revenue_splits)
+        .map_err(|e| ContractError::Std("Invalid revenue splits".to_string(), e))?;
+
+    if let Some(platform_fee) = &dega_minter_settings.platform_fee {
+        validate_platform_fee(deps.api, platform_fee)
+            .map_err(|e| ContractError::Std("Invalid platform fee".to_string(), e))?;
+    }
+
+    if let Some(public_sale) = &dega_minter_settings.public_sale {
+        validate_public_sale(deps.api, public_sale)
+            .map_err(|e| ContractError::Std("Invalid public sale settings".to_string(), e))?;
+    }
+
+    if let Some(dutch_auction) = &dega_minter_settings.dutch_auction {
+        validate_dutch_auction(dutch_auction)
+            .map_err(|e| ContractError::Std("Invalid dutch auction settings".to_string(), e))?;
+    }
+
+    if let Some(merkle_root) = &dega_minter_settings.allowlist_merkle_root {
+        parse_merkle_hash(merkle_root)
+            .map_err(|e| ContractError::Std("Invalid allowlist merkle root".to_string(), e))?;
+    }
+
+    // The initial admin is the only admin at instantiation
+    if let Some(multisig) = &dega_minter_settings.multisig {
+        validate_multisig(multisig, 1)
+            .map_err(|e| ContractError::Std("Invalid multisig settings".to_string(), e))?;
+    }
+
     save_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS, &dega_minter_settings)
         .map_err(|e| ContractError::Std("Error while saving dega minter settings".to_string(), e))?;
 
-    save_map_item_wrapped(deps.storage, &ADMIN_LIST, msg.minter_params.initial_admin, &Empty {})
+    let initial_admin = deps.api.addr_validate(&msg.minter_params.initial_admin)
+        .map_err(|e| ContractError::Std("Invalid initial admin address".to_string(), e))?;
+
+    save_map_item_wrapped(deps.storage, &ADMIN_LIST, initial_admin, &Empty {})
         .map_err(|e| ContractError::Std("Error while saving initial admin".to_string(), e))?;
 
     Ok(
@@ -100,71 +141,74 @@ pub(crate) fn run_reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response
 }
 
 pub(crate) fn run_migrate(
-    _deps: DepsMut,
-    _env: Env,
-    _migrate_msg: MigrateMsg,
+    mut deps: DepsMut,
+    env: Env,
+    migrate_msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
 
-    Ok(Response::new())
-
-    // if migrate_msg.is_dev {
-    //
-    //     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
-    //         .map_err(|e| ContractError::Std("Unable to set contract version".to_string(), e))?;
-    //
-    //     Ok(
-    //         Response::new()
-    //             .add_attribute("is_dev", "true")
-    //             .add_attribute("dev_version", migrate_msg.dev_version)
-    //     )
-    // } else {
-    //     let prev_contract_version = cw2::get_contract_version(deps.storage)
-    //         .map_err(|e| ContractError::Std("Unable to get contract version".to_string(), e))?;
-    //
-    //     let valid_contract_names = [CONTRACT_NAME.to_string()];
-    //     if !valid_contract_names.contains(&prev_contract_version.contract) {
-    //         return Err(ContractError::Migration("Invalid contract name for migration".to_string()));
-    //     }
-    //
-    //     #[allow(clippy::cmp_owned)]
-    //     if prev_contract_version.version >= CONTRACT_VERSION.to_string() {
-    //         return Err(ContractError::Migration("Must upgrade contract version".to_string()));
-    //     }
-    //
-    //     let mut response = Response::new();
-    //
-    //     #[allow(clippy::cmp_owned)]
-    //     if prev_contract_version.version < "1.0.0".to_string() {
-    //         response = crate::upgrades::v1_0_0::upgrade(deps.branch(), &env, response)?;
-    //     }
-    //
-    //     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
-    //         .map_err(|e| ContractError::Std("Unable to set contract version".to_string(), e))?;
-    //
-    //     response = response.add_event(
-    //         Event::new("migrate")
-    //             .add_attribute("from_name", prev_contract_version.contract)
-    //             .add_attribute("from_version", prev_contract_version.version)
-    //             .add_attribute("to_name", CONTRACT_NAME)
-    //             .add_attribute("to_version", CONTRACT_VERSION),
-    //     );
-    //
-    //     Ok(response)
-    // }
+    let prev_contract_version = get_contract_version(deps.storage)
+        .map_err(|e| ContractError::Std("Unable to get contract version".to_string(), e))?;
+
+    let valid_contract_names = [CONTRACT_NAME.to_string()];
+    if !valid_contract_names.contains(&prev_contract_version.contract) {
+        return Err(ContractError::Migration(
+            format!("Invalid contract name for migration: {}", prev_contract_version.contract)));
+    }
+
+    let prev_version = Version::parse(&prev_contract_version.version)
+        .map_err(|e| ContractError::Migration(
+            format!("Invalid stored contract version ({}): {}", prev_contract_version.version, e)))?;
+
+    let new_version = Version::parse(CONTRACT_VERSION)
+        .map_err(|e| ContractError::Migration(
+            format!("Invalid new contract version ({}): {}", CONTRACT_VERSION, e)))?;
+
+    // Dev migrations may redeploy the same version, but never downgrade
+    if new_version < prev_version || (new_version == prev_version && !migrate_msg.is_dev) {
+        return Err(ContractError::Migration(format!(
+            "Must upgrade contract version | Stored version: {} | New version: {}",
+            prev_version,
+            new_version,
+        )));
+    }
+
+    let mut response = run_upgrades(deps.branch(), &env, &prev_version, &new_version, Response::new())?;
+
+    set_contract_version_wrapped(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
+        .map_err(|e| ContractError::Std("Unable to set contract version".to_string(), e))?;
+
+    let mut event = Event::new("migrate")
+        .add_attribute("from_name", prev_contract_version.contract)
+        .add_attribute("from_version", prev_contract_version.version)
+        .add_attribute("to_name", CONTRACT_NAME)
+        .add_attribute("to_version", CONTRACT_VERSION);
+
+    if migrate_msg.is_dev {
+        event = event
+            .add_attribute("is_dev", "true")
+            .add_attribute("dev_version", migrate_msg.dev_version);
+    }
+
+    response = response.add_event(event);
+
+    Ok(response)
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{SubMsgResult, to_json_binary};
+    use cosmwasm_std::{Decimal, SubMsgResult, to_json_binary, Uint128};
     #[allow(unused_imports)]
     use super::*;
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use k256::ecdsa::SigningKey;
     use k256::elliptic_curve::rand_core::OsRng;
-    use dega_inj::minter::{DegaMinterConfigResponse};
+    use dega_inj::minter::{DegaMinterConfigResponse, DutchAuction, MultisigSettings, PlatformFee, PublicSaleSettings, RevenueSplit};
     use dega_inj::test_helpers::{add_save_error_item, add_save_error_map, clear_save_error_items, set_binary_for_json_error, set_contract_version_error};
     use crate::query::{query_admins, query_config};
-    use crate::test_helpers::{COLLECTION_CONTRACT_ADDR, get_signer_pub_key, make_reply_msg, template_instantiate_msg, template_minter, template_minter_via_msg, template_reply_msg, USER_ADMIN_ADDR};
+    use cosmwasm_std::Addr;
+    use cw_storage_plus::Map;
+    use crate::state::{UUID_COUNT, UUID_REGISTRY};
+    use crate::test_helpers::{COLLECTION_CONTRACT_ADDR, get_signer_pub_key, make_reply_msg, NEW_ADMIN_ADDR, ROYALTY_PAYMENT_ADDR, template_instantiate_msg, template_minter, template_minter_via_msg, template_reply_msg, USER_ADMIN_ADDR};
 
     #[test]
     fn normal_initialization() {
@@ -175,7 +219,7 @@ mod tests {
 
         template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
 
-        let admins = query_admins(deps.as_ref(), mock_env()).unwrap();
+        let admins = query_admins(deps.as_ref(), mock_env(), None, None).unwrap();
 
         assert_eq!(admins.admins, vec![USER_ADMIN_ADDR.to_string()]);
 
@@ -254,6 +298,11 @@ mod tests {
         assert!(template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
             .unwrap_err().to_string().contains("Invalid CW721 admin address"));
 
+        msg = template_instantiate_msg(signer_pub_key.clone());
+        msg.minter_params.initial_admin = "User_Admin_Addr".to_string();
+        assert!(template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
+            .unwrap_err().to_string().contains("Invalid initial admin address"));
+
         msg = template_instantiate_msg(signer_pub_key.clone());
         msg.minter_params.dega_minter_settings.signer_pub_key.push('#');
         err_string = template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
@@ -268,6 +317,72 @@ mod tests {
         assert!(err_string.contains("Invalid signer compressed public key"));
         assert!(err_string.contains("Invalid compressed public key, not 33 bytes long"));
 
+        msg = template_instantiate_msg(signer_pub_key.clone());
+        msg.minter_params.dega_minter_settings.signer_address = Some("Invalid Address".to_string());
+        err_string = template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
+            .unwrap_err().to_string();
+        assert!(err_string.contains("Invalid signer address"));
+
+        msg = template_instantiate_msg(signer_pub_key.clone());
+        msg.minter_params.dega_minter_settings.revenue_splits = vec![RevenueSplit {
+            recipient: ROYALTY_PAYMENT_ADDR.to_string(),
+            share: Decimal::percent(101),
+        }];
+        err_string = template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
+            .unwrap_err().to_string();
+        assert!(err_string.contains("Invalid revenue splits"));
+
+        msg = template_instantiate_msg(signer_pub_key.clone());
+        msg.minter_params.dega_minter_settings.platform_fee = Some(PlatformFee {
+            fee_address: ROYALTY_PAYMENT_ADDR.to_string(),
+            share: Decimal::percent(101),
+        });
+        err_string = template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
+            .unwrap_err().to_string();
+        assert!(err_string.contains("Invalid platform fee"));
+
+        msg = template_instantiate_msg(signer_pub_key.clone());
+        msg.minter_params.dega_minter_settings.public_sale = Some(PublicSaleSettings {
+            price: Uint128::new(100),
+            denom: "inj".to_string(),
+            start_timestamp: Uint128::new(1000),
+            end_timestamp: None,
+            base_uri: "https://example.com/".to_string(),
+            sale_recipient: "Invalid Address".to_string(),
+        });
+        err_string = template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
+            .unwrap_err().to_string();
+        assert!(err_string.contains("Invalid public sale settings"));
+
+        msg = template_instantiate_msg(signer_pub_key.clone());
+        msg.minter_params.dega_minter_settings.allowlist_merkle_root = Some("not hex".to_string());
+        err_string = template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
+            .unwrap_err().to_string();
+        assert!(err_string.contains("Invalid allowlist merkle root"));
+
+        msg = template_instantiate_msg(signer_pub_key.clone());
+        msg.minter_params.dega_minter_settings.dutch_auction = Some(DutchAuction {
+            start_price: Uint128::new(100),
+            floor_price: Uint128::new(200),
+            price_decrement: Uint128::new(10),
+            decrement_interval: 60,
+            start_timestamp: Uint128::new(1000),
+            rebates_enabled: false,
+        });
+        err_string = template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
+            .unwrap_err().to_string();
+        assert!(err_string.contains("Invalid dutch auction settings"));
+
+        msg = template_instantiate_msg(signer_pub_key.clone());
+        msg.minter_params.dega_minter_settings.multisig = Some(MultisigSettings {
+            threshold: 2,
+            proposal_duration: 600,
+        });
+        err_string = template_minter_via_msg(&mut mock_dependencies().as_mut(), &msg, &reply_msg, false)
+            .unwrap_err().to_string();
+        assert!(err_string.contains("Invalid multisig settings"));
+        assert!(err_string.contains("Multisig threshold cannot be above the number of admins"));
+
     }
 
     #[test]
@@ -304,4 +419,111 @@ mod tests {
         assert!(err_string.contains("Error instantiating collection contract"));
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn migration() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let migrate_msg = MigrateMsg {
+            is_dev: false,
+            dev_version: "".to_string(),
+        };
+
+        // Normal upgrade from an older version runs the upgrade steps and bumps the version
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.9.0").unwrap();
+        let response = run_migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap();
+        assert!(response.events.iter().any(|event| event.ty == "migrate-1.0.0"));
+        let migrate_event = response.events.iter().find(|event| event.ty == "migrate").unwrap();
+        assert!(migrate_event.attributes.iter().any(|attr| attr.key == "from_version" && attr.value == "0.9.0"));
+        assert!(migrate_event.attributes.iter().any(|attr| attr.key == "to_version" && attr.value == CONTRACT_VERSION));
+        assert_eq!(get_contract_version(&deps.storage).unwrap().version, CONTRACT_VERSION);
+
+        // Migrating to the same version is refused outside of dev mode
+        let err = run_migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        assert!(err.to_string().contains("Must upgrade contract version"));
+
+        // Dev mode allows redeploying the same version
+        let response = run_migrate(deps.as_mut(), mock_env(), MigrateMsg {
+            is_dev: true,
+            dev_version: "dev-build-2".to_string(),
+        }).unwrap();
+        let migrate_event = response.events.iter().find(|event| event.ty == "migrate").unwrap();
+        assert!(migrate_event.attributes.iter().any(|attr| attr.key == "dev_version" && attr.value == "dev-build-2"));
+        assert!(!response.events.iter().any(|event| event.ty == "migrate-1.0.0"));
+
+        // Downgrades are refused, even in dev mode, and use semver rather than string ordering
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.10.0").unwrap();
+        let err = run_migrate(deps.as_mut(), mock_env(), MigrateMsg {
+            is_dev: true,
+            dev_version: "dev".to_string(),
+        }).unwrap_err();
+        assert!(err.to_string().contains("Must upgrade contract version"));
+
+        // Wrong contract name
+        cw2::set_contract_version(deps.as_mut().storage, "some-other-contract", "0.1.0").unwrap();
+        let err = run_migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Migration("Invalid contract name for migration: some-other-contract".to_string()));
+
+        // Unparseable stored version
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "not-a-version").unwrap();
+        let err = run_migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        assert!(err.to_string().contains("Invalid stored contract version (not-a-version)"));
+    }
+
+    #[test]
+    fn migration_from_1_0_0() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let migrate_msg = MigrateMsg {
+            is_dev: false,
+            dev_version: "".to_string(),
+        };
+
+        // State as left by a 1.0.0 minter: mixed case admins and UUID entries stored as empty objects
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.0.0").unwrap();
+        ADMIN_LIST.save(deps.as_mut().storage, Addr::unchecked("New_Admin_Addr"), &Empty {}).unwrap();
+        let legacy_registry: Map<String, Empty> = Map::new("uuid_registry");
+        legacy_registry.save(deps.as_mut().storage, "LEGACY_UUID".to_string(), &Empty {}).unwrap();
+
+        let response = run_migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap();
+        let event_types: Vec<&str> = response.events.iter().map(|event| event.ty.as_str()).collect();
+        assert_eq!(event_types, vec!["migrate-1.1.0", "migrate-1.2.0", "migrate"]);
+        assert_eq!(get_contract_version(&deps.storage).unwrap().version, CONTRACT_VERSION);
+
+        // The 1.1.0 step gave the legacy UUID an expiration, and the 1.2.0 step normalized the admin and counted the UUID
+        let legacy_record = UUID_REGISTRY.load(deps.as_ref().storage, "LEGACY_UUID".to_string()).unwrap();
+        assert!(legacy_record.validity_end_timestamp.is_some());
+        assert_eq!(query_admins(deps.as_ref(), mock_env(), None, None).unwrap().admins,
+                   vec![NEW_ADMIN_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+        assert_eq!(UUID_COUNT.load(deps.as_ref().storage).unwrap(), 1);
+    }
+
+    #[test]
+    fn migration_errors() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let migrate_msg = MigrateMsg {
+            is_dev: false,
+            dev_version: "".to_string(),
+        };
+
+        // No contract version stored
+        let err = run_migrate(mock_dependencies().as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        assert!(err.to_string().contains("Unable to get contract version"));
+
+        // Unable to set the new contract version
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.9.0").unwrap();
+        set_contract_version_error(true);
+        let err = run_migrate(deps.as_mut(), mock_env(), migrate_msg.clone()).unwrap_err();
+        set_contract_version_error(false);
+        assert!(err.to_string().contains("Unable to set contract version"));
+    }
+}
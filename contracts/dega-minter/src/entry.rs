@@ -68,6 +68,7 @@ mod tests {
             settings: UpdateDegaMinterConfigSettingsMsg {
                 signer_pub_key: None,
                 minting_paused: Some(true),
+                ..Default::default()
             }
         }).unwrap();
 
@@ -75,6 +76,7 @@ mod tests {
         let config_response: DegaMinterConfigResponse = from_json(query_binary).unwrap();
         assert!(config_response.dega_minter_settings.minting_paused);
 
+        cw2::set_contract_version(deps.as_mut().storage, "dega-minter", "0.9.0").unwrap();
         migrate(deps.as_mut(), mock_env(), MigrateMsg {
             is_dev: false,
             dev_version: "".to_string(),
@@ -25,6 +25,9 @@ pub enum ContractError {
     #[error("( DEGA Minter Error: ( {0} ) )")]
     Generic(String),
 
+    #[error("( DEGA Minter Domain Mismatch Error: ( Mint request {0} does not match | Expected: ( {1} ) | Requested: ( {2} ) ) )")]
+    DomainMismatch(String, String, String),
+
     #[error("( DEGA Minter Error: ( Minting not allowed while minting is paused. ) )")]
     MintingPaused,
 }
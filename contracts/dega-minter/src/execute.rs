@@ -1,10 +1,14 @@
-use cosmwasm_std::{BankMsg, CosmosMsg, DepsMut, Empty, Env, MessageInfo, Order, Response, Uint128, Uint256, WasmMsg};
-use dega_inj::helpers::{load_item_wrapped, save_item_wrapped, save_map_item_wrapped, to_json_binary_wrapped};
-use dega_inj::minter::{ExecuteMsg, MintRequest, SignerSourceType, UpdateAdminCommand, UpdateDegaMinterConfigSettingsMsg, VerifiableMsg};
+use cosmwasm_std::{Addr, Api, BankMsg, Coin, CosmosMsg, DepsMut, Empty, Env, Event, from_json, MessageInfo, Order, Response, StdError, StdResult, Storage, Uint128, Uint256, WasmMsg};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw_storage_plus::Bound;
+use dega_inj::helpers::{load_item_wrapped, map_keys_wrapped, save_item_wrapped, save_map_item_wrapped, to_json_binary_wrapped};
+use dega_inj::cw721::{RoyaltySettingsResponse, UpdateCollectionInfoMsg};
+use dega_inj::minter::{AdminAction, AllowlistProof, DegaMinterConfigSettings, ExecuteMsg, MintRequest, OverpaymentMode, PendingAdminAction, PhaseEligibility, PlatformFee, ProposalAction, ReceiveMsg, RevenueSplit, Role, SalePhase, SignerKeyInfo, SignerSourceType, UpdateAdminCommand, UpdateDegaMinterConfigSettingsMsg, VerifiableMsg};
 use crate::error::ContractError;
-use crate::helpers::{increment_token_index, verify_compressed_pub_key};
+use crate::helpers::{active_phase, auction_clearing_price, decrease_uuid_count, dutch_auction_price, has_role, increment_admin_action_count, increment_proposal_count, increment_token_index, increment_uuid_count, MAX_UUID_PRUNE_LIMIT, PRIMARY_SIGNER_LABEL, role_name, save_used_uuid, SIGNER_ADDRESS_LABEL, split_proceeds, validate_dutch_auction, validate_multisig, validate_platform_fee, validate_public_sale, validate_revenue_splits, validate_sale_phases, verify_compressed_pub_key};
+use crate::merkle::{allowlist_leaf, allowlist_root, parse_merkle_hash, verify_merkle_proof};
 use crate::query::query_check_sig;
-use crate::state::{ADMIN_LIST, COLLECTION_ADDRESS, DEGA_MINTER_SETTINGS, UUID_REGISTRY};
+use crate::state::{ADMIN_ACTIONS, ADMIN_LIST, AUCTION_CLEARING_PRICE, AUCTION_LAST_PRICE, AUCTION_PURCHASES, AUCTION_SALE, AuctionSale, COLLECTION_ADDRESS, DEGA_MINTER_SETTINGS, MINT_COUNTS, PHASE_MINT_COUNTS, Proposal, PROPOSALS, ROLES, SALE_PHASES, SIGNER_KEYS, TOKEN_INDEX, UUID_EXPIRATIONS, UUID_REGISTRY, UuidRecord};
 
 
 pub(crate) fn run_execute(
@@ -24,33 +28,135 @@ pub(crate) fn run_execute(
         ExecuteMsg::UpdateAdmin { address, command } => {
             execute_update_admin(&mut deps, &env, &info, address, command)
         }
+        ExecuteMsg::GrantRole { address, role } => {
+            execute_grant_role(&mut deps, &env, &info, address, role)
+        }
+        ExecuteMsg::RevokeRole { address, role } => {
+            execute_revoke_role(&mut deps, &env, &info, address, role)
+        }
+        ExecuteMsg::AddSigner { label, signer } => {
+            execute_add_signer(&mut deps, &env, &info, label, signer)
+        }
+        ExecuteMsg::RemoveSigner { label } => {
+            execute_remove_signer(&mut deps, &env, &info, label)
+        }
+        ExecuteMsg::ExecuteAdminAction { id } => {
+            execute_admin_action(&mut deps, &env, &info, id)
+        }
+        ExecuteMsg::AcceptAdminNomination { id } => {
+            execute_accept_admin_nomination(&mut deps, &env, &info, id)
+        }
+        ExecuteMsg::CancelAdminAction { id } => {
+            execute_cancel_admin_action(&mut deps, &env, &info, id)
+        }
+        ExecuteMsg::UpdateRoyaltySettings { royalty_settings } => {
+            execute_update_royalty_settings(&mut deps, &env, &info, royalty_settings)
+        }
+        ExecuteMsg::ApproveProposal { id } => {
+            execute_approve_proposal(&mut deps, &env, &info, id)
+        }
+        ExecuteMsg::Airdrop { recipients } => {
+            execute_airdrop(&mut deps, &env, &info, recipients)
+        }
+        ExecuteMsg::Receive(cw20_msg) => {
+            execute_receive(deps, env, info, cw20_msg)
+        }
+        ExecuteMsg::PublicMint {} => {
+            execute_public_mint(deps, env, info)
+        }
+        ExecuteMsg::AllowlistMint { proof } => {
+            execute_allowlist_mint(deps, env, info, proof)
+        }
+        ExecuteMsg::ClaimAuctionRebate { address } => {
+            execute_claim_auction_rebate(deps, env, info, address)
+        }
+        ExecuteMsg::UpdatePhases { phases } => {
+            execute_update_phases(&mut deps, &env, &info, phases)
+        }
+        ExecuteMsg::RevokeMintRequests { uuids } => {
+            execute_revoke_mint_requests(&mut deps, &env, &info, uuids)
+        }
+        ExecuteMsg::PruneUuids { limit } => {
+            execute_prune_uuids(&mut deps, &env, &info, limit)
+        }
     }
 }
 
 pub(crate) fn execute_update_settings(
     deps: &mut DepsMut,
-    _env: &Env,
+    env: &Env,
     info: &MessageInfo,
     settings: &UpdateDegaMinterConfigSettingsMsg
 ) -> Result<Response, ContractError> {
 
-    let mut response = Response::new()
-        .add_attribute("action", "update_settings")
-        .add_attribute("sender", info.sender.clone());
+    // Pausing is left to pausers alone, so minting can be stopped without waiting for approvals
+    let updates_pausing_only = *settings == UpdateDegaMinterConfigSettingsMsg {
+        minting_paused: settings.minting_paused,
+        ..Default::default()
+    };
+
+    if !updates_pausing_only {
+        if let Some(response) = propose_if_multisig(deps, env, info, ProposalAction::UpdateSettings {
+            settings: settings.clone(),
+        })? {
+            return Ok(response);
+        }
+    }
+
+    // Pausing and the signing settings are open to their roles, all other settings are for super admins
+    let updates_pausing = settings.minting_paused.is_some();
+    let updates_signing = settings.signer_pub_key.is_some()
+        || settings.signer_address.is_some()
+        || settings.signature_scheme.is_some()
+        || settings.mint_request_encoding.is_some();
+    let updates_other = *settings != UpdateDegaMinterConfigSettingsMsg {
+        signer_pub_key: settings.signer_pub_key.clone(),
+        minting_paused: settings.minting_paused,
+        signer_address: settings.signer_address.clone(),
+        signature_scheme: settings.signature_scheme.clone(),
+        mint_request_encoding: settings.mint_request_encoding.clone(),
+        ..Default::default()
+    };
+
+    if updates_pausing {
+        assert_role(deps.storage, &info.sender, Role::Pauser, "pause or unpause minting")?;
+    }
+
+    if updates_signing {
+        assert_role(deps.storage, &info.sender, Role::SignerManager, "update signer settings")?;
+    }
 
-    if ! ADMIN_LIST.has(deps.storage, info.sender.to_string()) {
-        return Err(ContractError::Unauthorized("Only admins can update settings".to_string()));
+    // Updates changing nothing are still restricted to super admins
+    if updates_other || !(updates_pausing || updates_signing) {
+        assert_role(deps.storage, &info.sender, Role::SuperAdmin, "update settings")?;
     }
 
+    update_settings(deps, env, &info.sender, settings)
+}
+
+fn update_settings(
+    deps: &mut DepsMut,
+    env: &Env,
+    sender: &Addr,
+    settings: &UpdateDegaMinterConfigSettingsMsg
+) -> Result<Response, ContractError> {
+
+    let mut response = Response::new()
+        .add_attribute("action", "update_settings")
+        .add_attribute("sender", sender.clone());
+
     let mut new_settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
         .map_err(|e| ContractError::Std("Error loading dega minter settings".to_string(), e))?;
 
+    // Signing key changes are queued rather than applied
     if let Some(signer_pub_key) = &settings.signer_pub_key {
-        verify_compressed_pub_key(signer_pub_key.clone())
-            .map_err(|e| ContractError::Std("Invalid signer public key".to_string(), e))?;
+        let pending_action = queue_admin_action(deps, env, sender, AdminAction::UpdateSignerPubKey {
+            signer_pub_key: signer_pub_key.clone(),
+        })?;
 
-        new_settings.signer_pub_key.clone_from(signer_pub_key);
-        response = response.add_attribute("signer_pub_key", signer_pub_key.clone());
+        response = response
+            .add_attribute("signer_pub_key", signer_pub_key.clone())
+            .add_attribute("signer_pub_key.admin_action_id", pending_action.id.to_string());
     }
 
     if let Some(minting_paused) = &settings.minting_paused {
@@ -58,6 +164,176 @@ pub(crate) fn execute_update_settings(
         response = response.add_attribute("minting_paused", minting_paused.to_string());
     }
 
+    if let Some(signer_address) = &settings.signer_address {
+        let pending_action = queue_admin_action(deps, env, sender, AdminAction::UpdateSignerAddress {
+            signer_address: signer_address.clone(),
+        })?;
+
+        response = response
+            .add_attribute("signer_address", signer_address.clone().unwrap_or("None".to_string()))
+            .add_attribute("signer_address.admin_action_id", pending_action.id.to_string());
+    }
+
+    // Changing the delay is an admin action itself, so that it can't be lowered to skip the wait
+    if let Some(admin_action_delay) = settings.admin_action_delay {
+        let pending_action = queue_admin_action(deps, env, sender, AdminAction::UpdateAdminActionDelay {
+            admin_action_delay,
+        })?;
+
+        response = response
+            .add_attribute("admin_action_delay", admin_action_delay.to_string())
+            .add_attribute("admin_action_delay.admin_action_id", pending_action.id.to_string());
+    }
+
+    if let Some(signature_scheme) = &settings.signature_scheme {
+        new_settings.signature_scheme = signature_scheme.clone();
+        response = response.add_attribute("signature_scheme", format!("{:?}", signature_scheme));
+    }
+
+    if let Some(mint_request_encoding) = &settings.mint_request_encoding {
+        new_settings.mint_request_encoding = mint_request_encoding.clone();
+        response = response.add_attribute("mint_request_encoding", format!("{:?}", mint_request_encoding));
+    }
+
+    if let Some(overpayment_mode) = &settings.overpayment_mode {
+        new_settings.overpayment_mode = overpayment_mode.clone();
+        response = response.add_attribute("overpayment_mode", format!("{:?}", overpayment_mode));
+    }
+
+    if let Some(revenue_splits) = &settings.revenue_splits {
+        validate_revenue_splits(deps.api, revenue_splits)
+            .map_err(|e| ContractError::Std("Invalid revenue splits".to_string(), e))?;
+
+        new_settings.revenue_splits.clone_from(revenue_splits);
+        response = response.add_attribute("revenue_splits", revenue_splits.iter()
+            .map(|split| format!("{}:{}", split.recipient, split.share))
+            .collect::<Vec<String>>()
+            .join(","));
+    }
+
+    if let Some(maybe_platform_fee) = &settings.platform_fee {
+        if let Some(platform_fee) = maybe_platform_fee {
+            validate_platform_fee(deps.api, platform_fee)
+                .map_err(|e| ContractError::Std("Invalid platform fee".to_string(), e))?;
+
+            new_settings.platform_fee = Some(platform_fee.clone());
+            response = response
+                .add_attribute("platform_fee.fee_address", platform_fee.fee_address.clone())
+                .add_attribute("platform_fee.share", platform_fee.share.to_string());
+        } else {
+            new_settings.platform_fee = None;
+            response = response.add_attribute("platform_fee", "None");
+        }
+    }
+
+    if let Some(max_supply) = settings.max_supply {
+        if let Some(current_max_supply) = new_settings.max_supply {
+            if max_supply > current_max_supply {
+                return Err(ContractError::InvalidInput("Max supply can only be lowered".to_string(), max_supply.to_string()));
+            }
+        }
+
+        let minted = TOKEN_INDEX.may_load(deps.storage)
+            .map_err(|e| ContractError::Std("Error loading token index".to_string(), e))?
+            .unwrap_or_default();
+
+        if max_supply < minted {
+            return Err(ContractError::InvalidInput(
+                format!("Max supply cannot be lower than the number of tokens minted ({})", minted),
+                max_supply.to_string(),
+            ));
+        }
+
+        new_settings.max_supply = Some(max_supply);
+        response = response.add_attribute("max_supply", max_supply.to_string());
+    }
+
+    if let Some(max_mints_per_wallet) = settings.max_mints_per_wallet {
+        new_settings.max_mints_per_wallet = max_mints_per_wallet;
+        response = response.add_attribute(
+            "max_mints_per_wallet",
+            max_mints_per_wallet.map(|max| max.to_string()).unwrap_or("None".to_string()),
+        );
+    }
+
+    if let Some(maybe_dutch_auction) = &settings.dutch_auction {
+        // Held payments and rebates are computed from the auction settings, which must stay as they were sold under
+        // until every purchase is settled. Once settled, changing the auction starts a new one.
+        if !AUCTION_PURCHASES.is_empty(deps.storage) {
+            return Err(ContractError::Generic("Dutch auction cannot be changed while auction purchases are unsettled".to_string()));
+        }
+
+        AUCTION_LAST_PRICE.remove(deps.storage);
+        AUCTION_CLEARING_PRICE.remove(deps.storage);
+        AUCTION_SALE.remove(deps.storage);
+
+        if let Some(dutch_auction) = maybe_dutch_auction {
+            validate_dutch_auction(dutch_auction)
+                .map_err(|e| ContractError::Std("Invalid dutch auction settings".to_string(), e))?;
+
+            new_settings.dutch_auction = Some(dutch_auction.clone());
+            response = response
+                .add_attribute("dutch_auction.start_price", dutch_auction.start_price)
+                .add_attribute("dutch_auction.floor_price", dutch_auction.floor_price)
+                .add_attribute("dutch_auction.price_decrement", dutch_auction.price_decrement)
+                .add_attribute("dutch_auction.decrement_interval", dutch_auction.decrement_interval.to_string())
+                .add_attribute("dutch_auction.start_timestamp", dutch_auction.start_timestamp)
+                .add_attribute("dutch_auction.rebates_enabled", dutch_auction.rebates_enabled.to_string());
+        } else {
+            new_settings.dutch_auction = None;
+            response = response.add_attribute("dutch_auction", "None");
+        }
+    }
+
+    if let Some(maybe_merkle_root) = &settings.allowlist_merkle_root {
+        if let Some(merkle_root) = maybe_merkle_root {
+            parse_merkle_hash(merkle_root)
+                .map_err(|e| ContractError::Std("Invalid allowlist merkle root".to_string(), e))?;
+
+            new_settings.allowlist_merkle_root = Some(merkle_root.clone());
+            response = response.add_attribute("allowlist_merkle_root", merkle_root.clone());
+        } else {
+            new_settings.allowlist_merkle_root = None;
+            response = response.add_attribute("allowlist_merkle_root", "None");
+        }
+    }
+
+    if let Some(maybe_public_sale) = &settings.public_sale {
+        if let Some(public_sale) = maybe_public_sale {
+            validate_public_sale(deps.api, public_sale)
+                .map_err(|e| ContractError::Std("Invalid public sale settings".to_string(), e))?;
+
+            new_settings.public_sale = Some(public_sale.clone());
+            response = response
+                .add_attribute("public_sale.price", public_sale.price)
+                .add_attribute("public_sale.denom", public_sale.denom.clone())
+                .add_attribute("public_sale.start_timestamp", public_sale.start_timestamp)
+                .add_attribute("public_sale.end_timestamp", public_sale.end_timestamp.map(|end| end.to_string()).unwrap_or("None".to_string()))
+                .add_attribute("public_sale.base_uri", public_sale.base_uri.clone())
+                .add_attribute("public_sale.sale_recipient", public_sale.sale_recipient.clone());
+        } else {
+            new_settings.public_sale = None;
+            response = response.add_attribute("public_sale", "None");
+        }
+    }
+
+    if let Some(maybe_multisig) = &settings.multisig {
+        if let Some(multisig) = maybe_multisig {
+            let admin_count = ADMIN_LIST.keys(deps.storage, None, None, Order::Ascending).count();
+
+            validate_multisig(multisig, admin_count)
+                .map_err(|e| ContractError::Std("Invalid multisig settings".to_string(), e))?;
+
+            new_settings.multisig = Some(multisig.clone());
+            response = response
+                .add_attribute("multisig.threshold", multisig.threshold.to_string())
+                .add_attribute("multisig.proposal_duration", multisig.proposal_duration.to_string());
+        } else {
+            new_settings.multisig = None;
+            response = response.add_attribute("multisig", "None");
+        }
+    }
+
     save_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS, &new_settings)
                         .map_err(|e| ContractError::Std("Error while saving dega minter settings".to_string(), e))?;
 
@@ -66,464 +342,2511 @@ pub(crate) fn execute_update_settings(
 
 pub(crate) fn execute_update_admin(
     deps: &mut DepsMut,
-    _env: &Env,
+    env: &Env,
     info: &MessageInfo,
     address: String,
     command: UpdateAdminCommand
 ) -> Result<Response, ContractError> {
 
-    if ! ADMIN_LIST.has(deps.storage, info.sender.to_string()) {
-        return Err(ContractError::Unauthorized("Only admins can update admins".to_string()))
-    }
-
-    let address_is_admin = ADMIN_LIST.has(deps.storage, address.clone());
+    validate_admin_address(deps.api, &address)?;
 
-    match command {
-        UpdateAdminCommand::Add => {
-
-            if address_is_admin {
-                Err(ContractError::Generic("Address to add as admin is already an admin".to_string()))?
-            }
+    if let Some(response) = propose_if_multisig(deps, env, info, ProposalAction::UpdateAdmin {
+        address: address.clone(),
+        command: command.clone(),
+    })? {
+        return Ok(response);
+    }
 
-            save_map_item_wrapped(deps.storage, &ADMIN_LIST, address.clone(), &Empty {})
-                      .map_err(|e| ContractError::Std("Error while saving new admin".to_string(), e))?
-        },
-        UpdateAdminCommand::Remove => {
+    assert_role(deps.storage, &info.sender, Role::SuperAdmin, "update admins")?;
 
-            if ADMIN_LIST.keys(deps.storage, None, None, Order::Ascending).count() < 2 {
-                Err(ContractError::Generic("Cannot remove admin when one or none exists".to_string()))?
-            }
+    update_admin(deps, env, &info.sender, address, command)
+}
 
-            if !address_is_admin {
-                Err(ContractError::Generic("Address to remove as admin is not an admin".to_string()))?
-            }
+fn update_admin(
+    deps: &mut DepsMut,
+    env: &Env,
+    sender: &Addr,
+    address: String,
+    command: UpdateAdminCommand
+) -> Result<Response, ContractError> {
 
-            ADMIN_LIST.remove(deps.storage, address.clone())
-        }
+    let action = match command {
+        UpdateAdminCommand::Add => AdminAction::AddAdmin { address: address.clone() },
+        UpdateAdminCommand::Remove => AdminAction::RemoveAdmin { address: address.clone() },
     };
 
+    let pending_action = queue_admin_action(deps, env, sender, action)?;
+
     Ok(Response::new()
         .add_attribute("action", "update_admin")
         .add_attribute("address", address)
         .add_attribute("command", format!("{:?}", command))
+        .add_attribute("admin_action_id", pending_action.id.to_string())
+        .add_attribute("executable_after", pending_action.executable_after)
     )
 }
 
-pub(crate) fn execute_mint(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    request: MintRequest,
-    signature: String,
+pub(crate) fn execute_grant_role(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: String,
+    role: Role,
 ) -> Result<Response, ContractError> {
 
-    let dega_minter_settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
-        .map_err(|e| ContractError::Std("Error during dega minter settings query".to_string(), e))?;
+    validate_role_address(deps.api, &address)?;
 
-    if dega_minter_settings.minting_paused {
-        return Err(ContractError::MintingPaused);
+    let proposal_action = match role {
+        Role::SuperAdmin => ProposalAction::UpdateAdmin { address: address.clone(), command: UpdateAdminCommand::Add },
+        _ => ProposalAction::GrantRole { address: address.clone(), role: role.clone() },
+    };
+
+    if let Some(response) = propose_if_multisig(deps, env, info, proposal_action)? {
+        return Ok(response);
     }
 
-    let check_sig_result = query_check_sig(
-        deps.as_ref(),
-        env.clone(),
-        VerifiableMsg::MintRequest(request.clone()),
-        signature.clone(),
-        SignerSourceType::ConfigSignerPubKey
-    ).map_err(|e| ContractError::Std("Error during signature verification".to_string(), e))?;
+    assert_role(deps.storage, &info.sender, Role::SuperAdmin, "grant roles")?;
 
-    if !check_sig_result.is_valid {
-        return Err(ContractError::Generic("Signature is invalid".to_string()));
-    }
+    grant_role(deps, env, &info.sender, address, role)
+}
 
-    deps.api.addr_validate(request.to.as_str())
-        .map_err(|e| ContractError::Std("Invalid purchaser address".to_string(), e))?;
+fn grant_role(
+    deps: &mut DepsMut,
+    env: &Env,
+    sender: &Addr,
+    address: String,
+    role: Role,
+) -> Result<Response, ContractError> {
 
-    let epoch_time_128 = Uint128::from(env.block.time.seconds());
+    let mut response = Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("sender", sender.clone())
+        .add_attribute("address", address.clone())
+        .add_attribute("role", format!("{:?}", role));
+
+    if role == Role::SuperAdmin {
+        let pending_action = queue_admin_action(deps, env, sender, AdminAction::AddAdmin {
+            address: address.clone(),
+        })?;
+
+        response = response.add_attribute("admin_action_id", pending_action.id.to_string());
+    } else {
+        let role_address = validate_role_address(deps.api, &address)?;
+
+        let mut roles = ROLES.may_load(deps.storage, role_address.clone())
+            .map_err(|e| ContractError::Std("Error loading roles".to_string(), e))?
+            .unwrap_or_default();
+
+        if roles.contains(&role) {
+            return Err(ContractError::Generic(format!("Address already has the {} role", role_name(&role))));
+        }
 
-    if epoch_time_128 < request.validity_start_timestamp {
-        return Err(ContractError::Generic(
-            format!("Request is not valid yet | Execution time: {} | Validity start: {}",
-                    epoch_time_128,
-                    request.validity_start_timestamp
-            ).to_string()
-        ));
-    }
+        roles.push(role.clone());
 
-    if epoch_time_128 > request.validity_end_timestamp {
-        return Err(ContractError::Generic(
-            format!("Request is no longer valid | Execution time: {} | Validity end: {}",
-                    epoch_time_128,
-                    request.validity_end_timestamp
-            ).to_string()));
+        save_map_item_wrapped(deps.storage, &ROLES, role_address, &roles)
+            .map_err(|e| ContractError::Std("Error while saving roles".to_string(), e))?;
     }
 
-    if UUID_REGISTRY.has(deps.storage, request.uuid.clone()) {
-        return Err(ContractError::Generic("UUID already registered".to_string()));
+    Ok(response)
+}
+
+pub(crate) fn execute_revoke_role(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    address: String,
+    role: Role,
+) -> Result<Response, ContractError> {
+
+    validate_role_address(deps.api, &address)?;
+
+    let proposal_action = match role {
+        Role::SuperAdmin => ProposalAction::UpdateAdmin { address: address.clone(), command: UpdateAdminCommand::Remove },
+        _ => ProposalAction::RevokeRole { address: address.clone(), role: role.clone() },
+    };
+
+    if let Some(response) = propose_if_multisig(deps, env, info, proposal_action)? {
+        return Ok(response);
     }
 
-    save_map_item_wrapped(deps.storage, &UUID_REGISTRY, request.uuid.clone(), &Empty {})
-                 .map_err(|e| ContractError::Std("Error while registering UUID".to_string(), e))?;
+    assert_role(deps.storage, &info.sender, Role::SuperAdmin, "revoke roles")?;
+
+    revoke_role(deps, env, &info.sender, address, role)
+}
 
+fn revoke_role(
+    deps: &mut DepsMut,
+    env: &Env,
+    sender: &Addr,
+    address: String,
+    role: Role,
+) -> Result<Response, ContractError> {
 
-    if info.funds.len() > 1 {
-        return Err(ContractError::Generic("Must only provide one payment currency".to_string()));
+    let mut response = Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("sender", sender.clone())
+        .add_attribute("address", address.clone())
+        .add_attribute("role", format!("{:?}", role));
+
+    if role == Role::SuperAdmin {
+        let pending_action = queue_admin_action(deps, env, sender, AdminAction::RemoveAdmin {
+            address: address.clone(),
+        })?;
+
+        response = response.add_attribute("admin_action_id", pending_action.id.to_string());
+    } else {
+        let role_address = validate_role_address(deps.api, &address)?;
+
+        let mut roles = ROLES.may_load(deps.storage, role_address.clone())
+            .map_err(|e| ContractError::Std("Error loading roles".to_string(), e))?
+            .unwrap_or_default();
+
+        if !roles.contains(&role) {
+            return Err(ContractError::Generic(format!("Address does not have the {} role", role_name(&role))));
+        }
+
+        roles.retain(|granted_role| *granted_role != role);
+
+        if roles.is_empty() {
+            ROLES.remove(deps.storage, role_address);
+        } else {
+            save_map_item_wrapped(deps.storage, &ROLES, role_address, &roles)
+                .map_err(|e| ContractError::Std("Error while saving roles".to_string(), e))?;
+        }
     }
 
-    let funds = match info.funds.first() {
-        Some(funds) => funds,
-        None => return Err(ContractError::Generic("No payment provided".to_string())),
-    };
+    Ok(response)
+}
 
-    if funds.denom != request.currency {
-        return Err(ContractError::Generic(
-            format!("Payment currency does not match requested currency | Payment: {} | Requested: {}",
-                    funds.denom,
-                    request.currency
-            ).to_string()
-        ));
+pub(crate) fn execute_add_signer(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    label: String,
+    signer: SignerKeyInfo,
+) -> Result<Response, ContractError> {
+
+    if let Some(response) = propose_if_multisig(deps, env, info, ProposalAction::AddSigner {
+        label: label.clone(),
+        signer: signer.clone(),
+    })? {
+        return Ok(response);
     }
 
-    if Uint256::from(funds.amount) < request.price {
-        return Err(ContractError::Generic(format!("Insufficient payment | Price: {} | Paid: {}", request.price, funds.amount)));
+    assert_role(deps.storage, &info.sender, Role::SignerManager, "add signers")?;
+
+    add_signer(deps, env, &info.sender, label, signer)
+}
+
+fn add_signer(
+    deps: &mut DepsMut,
+    env: &Env,
+    sender: &Addr,
+    label: String,
+    signer: SignerKeyInfo,
+) -> Result<Response, ContractError> {
+
+    let pending_action = queue_admin_action(deps, env, sender, AdminAction::AddSigner {
+        label: label.clone(),
+        signer: signer.clone(),
+    })?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "add_signer")
+        .add_attribute("sender", sender.clone())
+        .add_attribute("label", label)
+        .add_attribute("pub_key", signer.pub_key)
+        .add_attribute("admin_action_id", pending_action.id.to_string())
+        .add_attribute("executable_after", pending_action.executable_after);
+
+    if let Some(activation_timestamp) = signer.activation_timestamp {
+        response = response.add_attribute("activation_timestamp", activation_timestamp);
     }
 
-    if Uint256::from(funds.amount) > request.price {
-        return Err(ContractError::Generic(format!("Overpayment | Price: {} | Paid: {}", request.price, funds.amount)));
+    if let Some(expiration_timestamp) = signer.expiration_timestamp {
+        response = response.add_attribute("expiration_timestamp", expiration_timestamp);
     }
 
-    let this_collection_address = load_item_wrapped(deps.storage, &COLLECTION_ADDRESS)
-                                                    .map_err(|e| ContractError::Std("Error while loading collection address".to_string(), e))?;
+    Ok(response)
+}
 
-    let request_collection_address = deps.api.addr_validate(request.collection.as_str())
-                                         .map_err(|e| ContractError::Std("Invalid request collection address".to_string(), e))?;
+pub(crate) fn execute_remove_signer(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    label: String,
+) -> Result<Response, ContractError> {
 
-    if this_collection_address != request_collection_address {
-        return Err(ContractError::Generic(format!(
-            "Mint request authorized for collection ({}) sent to incorrect collection ({})",
-            request_collection_address.as_str(),
-            this_collection_address.as_str(),
-        )));
+    if let Some(response) = propose_if_multisig(deps, env, info, ProposalAction::RemoveSigner {
+        label: label.clone(),
+    })? {
+        return Ok(response);
     }
 
-    let token_id = increment_token_index(deps.storage)
-        .map_err(|e| ContractError::Std("Error while incrementing token index".to_string(), e))?;
+    assert_role(deps.storage, &info.sender, Role::SignerManager, "remove signers")?;
 
-    // Create mint msg
-    let mint_exec_msg = dega_inj::cw721::ExecuteMsg::Mint {
-        token_id: token_id.to_string(),
-        owner: request.to.clone(),
-        token_uri: Some(request.uri.clone()),
-        extension: None,
-    };
-    let mint_wasm_msg = CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: this_collection_address.to_string(),
-        msg: to_json_binary_wrapped(&mint_exec_msg)
-            .map_err(|e| ContractError::Std("Error during conversion of mint exec message to binary".to_string(), e))?,
-        funds: vec![],
-    });
+    remove_signer(deps, env, &info.sender, label)
+}
 
-    // Create transfer proceeds msg
-    let sale_recipient_addr = deps.api.addr_validate(request.primary_sale_recipient.as_str())
-                                  .map_err(|e| ContractError::Std("Invalid primary sale recipient address".to_string(), e))?;
+fn remove_signer(
+    deps: &mut DepsMut,
+    env: &Env,
+    sender: &Addr,
+    label: String,
+) -> Result<Response, ContractError> {
 
-    let transfer_proceeds_msg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: sale_recipient_addr.to_string(),
-        amount: vec![funds.clone()],
-    });
+    let pending_action = queue_admin_action(deps, env, sender, AdminAction::RemoveSigner {
+        label: label.clone(),
+    })?;
 
     Ok(Response::new()
-        .add_message(mint_wasm_msg)
-        .add_message(transfer_proceeds_msg)
-        .add_attribute("action", "mint")
-        .add_attribute("sender", info.sender.clone())
-        .add_attribute("signature", signature)
-        .add_attribute("token_id", token_id.to_string())
-        .add_attribute("collection_address", this_collection_address.to_string())
-        .add_attribute("request.to", request.to)
-        .add_attribute("request.primary_sale_recipient", request.primary_sale_recipient)
-        .add_attribute("request.uri", request.uri)
-        .add_attribute("request.price", request.price)
-        .add_attribute("request.currency", request.currency)
-        .add_attribute("request.validity_start_timestamp", request.validity_start_timestamp)
-        .add_attribute("request.validity_end_timestamp", request.validity_end_timestamp)
-        .add_attribute("request.collection", request.collection)
-        .add_attribute("request.uuid", request.uuid)
+        .add_attribute("action", "remove_signer")
+        .add_attribute("sender", sender.clone())
+        .add_attribute("label", label)
+        .add_attribute("admin_action_id", pending_action.id.to_string())
+        .add_attribute("executable_after", pending_action.executable_after)
     )
 }
 
-#[cfg(test)]
-mod tests {
-    #[allow(unused_imports)]
-    use super::*;
-    use cosmwasm_std::{BankMsg, Coin, CosmosMsg, StdError, Timestamp, to_json_binary, Uint128, WasmMsg};
-    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use k256::ecdsa::SigningKey;
-    use k256::elliptic_curve::rand_core::OsRng;
-    use dega_inj::minter::{AdminsResponse, QueryMsg, UpdateAdminCommand, UpdateDegaMinterConfigSettingsMsg};
-    use dega_inj::test_helpers::{add_load_error_item, add_save_error_item, add_save_error_map, clear_load_error_items, clear_save_error_items, set_binary_for_json_error};
-    use crate::error::ContractError;
-    use crate::execute::{execute_mint, execute_update_admin, execute_update_settings};
-    use crate::query::{query_admins, query_config};
-    use crate::state::TOKEN_INDEX;
-    use crate::test_helpers::{BUYER_ADDR, COLLECTION_CONTRACT_ADDR, get_inj_wei_from_kilo_inj, get_signer_pub_key, INJ_DENOM, INVALID_ADDR, MINT_URI, NEW_ADMIN_ADDR, NORMAL_USER_ADDR, PRIMARY_SALE_RECIPIENT_ADDR, query_typed, sign_mint_request, template_mint_msg, template_minter, USER_ADMIN_ADDR};
-    #[test]
-    fn access_restriction() {
+pub(crate) fn execute_admin_action(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
 
-        let signing_key = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key);
+    let pending_action = load_pending_admin_action(deps.storage, id)?;
 
-        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[]);
+    if let AdminAction::AddAdmin { .. } = pending_action.action {
+        return Err(ContractError::Generic("Admin nominations must be accepted by the nominee".to_string()));
+    }
 
-        let new_settings_pause = UpdateDegaMinterConfigSettingsMsg {
-            signer_pub_key: None,
-            minting_paused: Some(true),
-        };
-
-        // Try to update settings as a regular user (should error)
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        let unauthed_update_settings_err = execute_update_settings(&mut deps.as_mut(), &mock_env(), &normal_user_msg_info, &new_settings_pause).unwrap_err();
-        assert_eq!(unauthed_update_settings_err, ContractError::Unauthorized("Only admins can update settings".to_string()));
-        assert!(!query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
-
-        // Try to update admins as a regular user (should error)
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        let unauthed_update_admin_err = execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &normal_user_msg_info, NORMAL_USER_ADDR.to_string(), UpdateAdminCommand::Add).unwrap_err();
-        assert_eq!(unauthed_update_admin_err, ContractError::Unauthorized("Only admins can update admins".to_string()));
-        assert_eq!(query_admins(deps.as_ref(), mock_env()).unwrap().admins, vec![USER_ADMIN_ADDR.to_string()]);
-    }
+    assert_role(deps.storage, &info.sender, admin_action_role(&pending_action.action), "execute this admin action")?;
 
-    #[test]
-    fn updating_admin() {
+    carry_out_admin_action(deps, env, &pending_action)?;
 
-        let signing_key = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key);
+    Ok(Response::new()
+        .add_attribute("action", "execute_admin_action")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("admin_action_id", id.to_string())
+    )
+}
 
+pub(crate) fn execute_accept_admin_nomination(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
 
-        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+    let pending_action = load_pending_admin_action(deps.storage, id)?;
 
-        // Ensure we canot remove ourself as the only admin
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        let remove_only_admin_err = run_execute(deps.as_mut(), mock_env(), admin_msg_info.clone(),
-            ExecuteMsg::UpdateAdmin {
-                address: USER_ADMIN_ADDR.to_string(),
-                command: UpdateAdminCommand::Remove,
+    match &pending_action.action {
+        AdminAction::AddAdmin { address } => {
+            if address != info.sender.as_str() {
+                return Err(ContractError::Unauthorized("Only the nominee can accept an admin nomination".to_string()));
             }
-        ).unwrap_err();
+        },
+        _ => return Err(ContractError::InvalidInput("Admin action is not an admin nomination".to_string(), id.to_string())),
+    }
 
-        assert_eq!(remove_only_admin_err, ContractError::Generic("Cannot remove admin when one or none exists".to_string()));
-        assert_eq!(query_typed::<AdminsResponse>(deps.as_ref(), QueryMsg::Admins {}).unwrap().admins,
-                   vec![USER_ADMIN_ADDR.to_string()]);
-        assert!(query_typed::<bool>(deps.as_ref(), QueryMsg::IsAdmin { address: USER_ADMIN_ADDR.to_string() }).unwrap());
+    carry_out_admin_action(deps, env, &pending_action)?;
 
-        // Reset DB and minter after error (clean up to simulate rollback)
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+    Ok(Response::new()
+        .add_attribute("action", "accept_admin_nomination")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("admin_action_id", id.to_string())
+    )
+}
 
-        // Update admins as an admin, should succeed
-        execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &admin_msg_info, NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
-        assert_eq!(query_admins(deps.as_ref(), mock_env()).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+pub(crate) fn execute_cancel_admin_action(
+    deps: &mut DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
 
-        // Ensure proper error when removing non admin address
-        let remove_non_admin_err = execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &admin_msg_info, NORMAL_USER_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap_err();
-        assert_eq!(remove_non_admin_err, ContractError::Generic("Address to remove as admin is not an admin".to_string()));
-        assert_eq!(query_admins(deps.as_ref(), mock_env()).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+    let pending_action = load_pending_admin_action(deps.storage, id)?;
 
-        // Reset DB and minter after error (clean up to simulate rollback)
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+    let sender_is_admin = has_role(deps.storage, &info.sender, &Role::SuperAdmin)
+        .map_err(|e| ContractError::Std("Error checking sender role".to_string(), e))?;
 
-        // Update admins as an admin, should succeed
-        execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &admin_msg_info, NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
-        assert_eq!(query_admins(deps.as_ref(), mock_env()).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+    if !sender_is_admin && pending_action.proposer != info.sender.as_str() {
+        return Err(ContractError::Unauthorized("Only admins and the proposer can cancel admin actions".to_string()));
+    }
 
-        // Updating admins as the new admin should succeed
-        let new_admin_msg_info = mock_info(NEW_ADMIN_ADDR, &[]);
-        execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &new_admin_msg_info, NORMAL_USER_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
-        assert_eq!(query_admins(deps.as_ref(), mock_env()).unwrap().admins,
-                   vec![NEW_ADMIN_ADDR.to_string(), NORMAL_USER_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+    ADMIN_ACTIONS.remove(deps.storage, id);
 
-        // Remove myself as an admin, should succeed
-        execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &admin_msg_info, USER_ADMIN_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap();
-        assert_eq!(query_admins(deps.as_ref(), mock_env()).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), NORMAL_USER_ADDR.to_string()]);
+    Ok(Response::new()
+        .add_attribute("action", "cancel_admin_action")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("admin_action_id", id.to_string())
+    )
+}
 
-        // Updating admins as the old admin should fail now
-        let removed_update_admin_err = execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &admin_msg_info, USER_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap_err();
-        assert_eq!(removed_update_admin_err, ContractError::Unauthorized("Only admins can update admins".to_string()));
-        assert_eq!(query_admins(deps.as_ref(), mock_env()).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), NORMAL_USER_ADDR.to_string()]);
+pub(crate) fn execute_update_royalty_settings(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    royalty_settings: Option<RoyaltySettingsResponse>,
+) -> Result<Response, ContractError> {
+
+    if let Some(response) = propose_if_multisig(deps, env, info, ProposalAction::UpdateRoyaltySettings {
+        royalty_settings: royalty_settings.clone(),
+    })? {
+        return Ok(response);
     }
 
-    #[test]
-    fn updating_settings() {
+    assert_role(deps.storage, &info.sender, Role::CollectionEditor, "update royalty settings")?;
 
-        let signing_key = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key);
+    update_royalty_settings(deps, &info.sender, royalty_settings)
+}
 
-        // Initialize fresh DB and minter
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+// Royalty settings are validated by the collection contract, which accepts updates from the minter
+fn update_royalty_settings(
+    deps: &mut DepsMut,
+    sender: &Addr,
+    royalty_settings: Option<RoyaltySettingsResponse>,
+) -> Result<Response, ContractError> {
 
-        // Should be starting unpaused
-        assert!(!query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
+    let collection_address = load_item_wrapped(deps.storage, &COLLECTION_ADDRESS)
+        .map_err(|e| ContractError::Std("Error while loading collection address".to_string(), e))?;
 
-        let new_settings_pause = UpdateDegaMinterConfigSettingsMsg {
-            signer_pub_key: None,
-            minting_paused: Some(true),
-        };
+    let mut response = Response::new()
+        .add_attribute("action", "update_royalty_settings")
+        .add_attribute("sender", sender.clone());
+
+    response = match &royalty_settings {
+        Some(royalty_settings) => response
+            .add_attribute("royalty_settings.payment_address", royalty_settings.payment_address.clone())
+            .add_attribute("royalty_settings.share", royalty_settings.share.to_string()),
+        None => response.add_attribute("royalty_settings", "None"),
+    };
 
-        let new_settings_unpause = UpdateDegaMinterConfigSettingsMsg {
-            signer_pub_key: None,
-            minting_paused: Some(false),
-        };
+    let update_collection_info_msg = dega_inj::cw721::ExecuteMsg::UpdateCollectionInfo {
+        collection_info: UpdateCollectionInfoMsg {
+            description: None,
+            image: None,
+            external_link: None,
+            royalty_settings: Some(royalty_settings),
+        },
+    };
 
-        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+    Ok(response.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: collection_address.to_string(),
+        msg: to_json_binary_wrapped(&update_collection_info_msg)
+            .map_err(|e| ContractError::Std("Error during conversion of update collection info message to binary".to_string(), e))?,
+        funds: vec![],
+    })))
+}
 
-        // Update settings as an admin, should succeed
-        execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_pause).unwrap();
-        assert!(query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
+pub(crate) fn execute_approve_proposal(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
 
-        // Change the signing key
-        let second_signing_key = SigningKey::random(&mut OsRng);
-        let second_signer_pub_key = get_signer_pub_key(&second_signing_key);
-        let new_settings_signer = UpdateDegaMinterConfigSettingsMsg {
-            signer_pub_key: Some(second_signer_pub_key.clone()),
-            minting_paused: None,
-        };
-        execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_signer).unwrap();
-        assert_eq!(query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.signer_pub_key, second_signer_pub_key);
+    if !ADMIN_LIST.has(deps.storage, info.sender.clone()) {
+        return Err(ContractError::Unauthorized("Only admins can approve proposals".to_string()));
+    }
 
+    let response = Response::new()
+        .add_attribute("action", "approve_proposal")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("proposal_id", id.to_string());
 
-        // Add new admin
-        execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &admin_msg_info, NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
+    approve_proposal(deps, env, &info.sender, id, response)
+}
 
-        // New admin should be able to pause
-        let new_admin_msg_info = mock_info(NEW_ADMIN_ADDR, &[]);
-        execute_update_settings(&mut deps.as_mut(), &mock_env(), &new_admin_msg_info, &new_settings_unpause).unwrap();
-        assert!(!query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
+// While multisig approval is enabled the operation is proposed by an admin rather than executed, with the
+// proposer's approval recorded right away
+fn propose_if_multisig(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    action: ProposalAction,
+) -> Result<Option<Response>, ContractError> {
 
-        // Remove myself as an admin
-        execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &admin_msg_info, USER_ADMIN_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap();
+    let dega_minter_settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+        .map_err(|e| ContractError::Std("Error loading dega minter settings".to_string(), e))?;
 
-        // Updating settings as the old admin should fail now
-        let removed_update_settings_err = execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_unpause).unwrap_err();
-        assert_eq!(removed_update_settings_err, ContractError::Unauthorized("Only admins can update settings".to_string()));
-        assert!(!query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
+    let multisig = match dega_minter_settings.multisig {
+        Some(multisig) => multisig,
+        None => return Ok(None),
+    };
+
+    if !ADMIN_LIST.has(deps.storage, info.sender.clone()) {
+        return Err(ContractError::Unauthorized("Only admins can make proposals while multisig approval is enabled".to_string()));
     }
 
-    #[test]
-    fn updating_settings_errors() {
-        let signing_key = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key);
-        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+    let id = increment_proposal_count(deps.storage)
+        .map_err(|e| ContractError::Std("Error while incrementing proposal count".to_string(), e))?;
 
-        let new_settings_pause = UpdateDegaMinterConfigSettingsMsg {
-            signer_pub_key: None,
-            minting_paused: Some(true),
-        };
+    let expiration = Uint128::from(env.block.time.seconds().saturating_add(multisig.proposal_duration));
 
-        // Error due to being unable to load minter settings
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        add_load_error_item(&DEGA_MINTER_SETTINGS);
-        let err_msg = execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_pause)
-            .unwrap_err().to_string();
-        assert!(err_msg.contains("Error loading dega minter settings"));
-        clear_load_error_items();
+    let proposal = Proposal {
+        action,
+        proposer: info.sender.clone(),
+        approvals: vec![],
+        expiration,
+        executed: false,
+    };
 
-        // Error due to bad signer public key
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        let mut invalid_key_settings = new_settings_pause.clone();
-        invalid_key_settings.signer_pub_key = Some("Invalid Pub Key".to_string());
-        let err_msg = execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &invalid_key_settings)
-            .unwrap_err().to_string();
-        assert!(err_msg.contains("Invalid signer public key"));
+    save_map_item_wrapped(deps.storage, &PROPOSALS, id, &proposal)
+        .map_err(|e| ContractError::Std("Error while saving proposal".to_string(), e))?;
 
-        // Error due to being unable to save minter settings
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        add_save_error_item(&DEGA_MINTER_SETTINGS);
-        let err_msg = execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_pause)
-            .unwrap_err().to_string();
-        assert!(err_msg.contains("Error while saving dega minter settings"));
-        clear_save_error_items();
+    let response = Response::new()
+        .add_attribute("action", "propose")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("proposal_id", id.to_string())
+        .add_event(Event::new("multisig-proposal")
+            .add_attribute("proposal_id", id.to_string())
+            .add_attribute("proposer", info.sender.clone())
+            .add_attribute("expiration", expiration));
+
+    approve_proposal(deps, env, &info.sender, id, response).map(Some)
+}
+
+// Executes the proposal once the approvals of current admins reach the threshold. Executed proposals are carried
+// out on behalf of their proposer, without checking the proposer's roles again.
+fn approve_proposal(
+    deps: &mut DepsMut,
+    env: &Env,
+    approver: &Addr,
+    id: u64,
+    mut response: Response,
+) -> Result<Response, ContractError> {
+
+    let mut proposal = PROPOSALS.may_load(deps.storage, id)
+        .map_err(|e| ContractError::Std("Error loading proposal".to_string(), e))?
+        .ok_or(ContractError::InvalidInput("No proposal with id".to_string(), id.to_string()))?;
+
+    if proposal.executed {
+        return Err(ContractError::InvalidInput("Proposal has already been executed".to_string(), id.to_string()));
     }
 
-    #[test]
-    fn updating_admin_errors() {
-        let signing_key = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key);
-        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+    let epoch_time_128 = Uint128::from(env.block.time.seconds());
 
-        // Add address that is already admin
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        let add_existing_admin_err = execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &admin_msg_info, USER_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add)
-            .unwrap_err().to_string();
-        assert!(add_existing_admin_err.contains("Address to add as admin is already an admin"));
+    if epoch_time_128 >= proposal.expiration {
+        return Err(ContractError::Generic(
+            format!("Proposal has expired | Execution time: {} | Expiration: {}",
+                    epoch_time_128, proposal.expiration)
+        ));
+    }
 
-        // Unable to save address to admin list
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        let new_admin_addr = "new_admin_addr".to_string();
-        add_save_error_map(&ADMIN_LIST);
-        let add_existing_admin_err = execute_update_admin(
-            &mut deps.as_mut(), &mock_env(), &admin_msg_info, new_admin_addr, UpdateAdminCommand::Add)
-            .unwrap_err().to_string();
-        assert!(add_existing_admin_err.contains("Error while saving new admin"));
-        clear_save_error_items()
+    if proposal.approvals.contains(approver) {
+        return Err(ContractError::Generic("Proposal has already been approved by sender".to_string()));
     }
 
-    #[test]
-    fn valid_mint() {
+    proposal.approvals.push(approver.clone());
 
-        let price_wei = get_inj_wei_from_kilo_inj(100);
+    let dega_minter_settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+        .map_err(|e| ContractError::Std("Error loading dega minter settings".to_string(), e))?;
 
-        // Create a first keypair and to store in the minter
-        let signing_key_one = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+    // Proposals left open when multisig approval is disabled only need a single approval
+    let threshold = dega_minter_settings.multisig.map_or(1, |multisig| multisig.threshold) as usize;
+
+    let approval_count = proposal.approvals.iter()
+        .filter(|address| ADMIN_LIST.has(deps.storage, (*address).clone()))
+        .count();
+
+    response = response.add_event(Event::new("multisig-approval")
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("approver", approver.clone())
+        .add_attribute("approvals", approval_count.to_string())
+        .add_attribute("threshold", threshold.to_string()));
+
+    if approval_count >= threshold {
+        let executed_response = match &proposal.action {
+            ProposalAction::UpdateSettings { settings } =>
+                update_settings(deps, env, &proposal.proposer, settings)?,
+            ProposalAction::UpdateAdmin { address, command } =>
+                update_admin(deps, env, &proposal.proposer, address.clone(), command.clone())?,
+            ProposalAction::UpdateRoyaltySettings { royalty_settings } =>
+                update_royalty_settings(deps, &proposal.proposer, royalty_settings.clone())?,
+            ProposalAction::GrantRole { address, role } =>
+                grant_role(deps, env, &proposal.proposer, address.clone(), role.clone())?,
+            ProposalAction::RevokeRole { address, role } =>
+                revoke_role(deps, env, &proposal.proposer, address.clone(), role.clone())?,
+            ProposalAction::AddSigner { label, signer } =>
+                add_signer(deps, env, &proposal.proposer, label.clone(), signer.clone())?,
+            ProposalAction::RemoveSigner { label } =>
+                remove_signer(deps, env, &proposal.proposer, label.clone())?,
+            ProposalAction::UpdatePhases { phases } =>
+                update_phases(deps, &proposal.proposer, phases.clone())?,
+        };
 
-        // Initialize fresh DB and minter
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        proposal.executed = true;
 
-        let mock_env = mock_env();
+        response = response
+            .add_attributes(executed_response.attributes)
+            .add_submessages(executed_response.messages)
+            .add_events(executed_response.events)
+            .add_event(Event::new("multisig-execution")
+                .add_attribute("proposal_id", id.to_string()));
+    }
 
-        let mint_msg = template_mint_msg(&mock_env, price_wei);
+    save_map_item_wrapped(deps.storage, &PROPOSALS, id, &proposal)
+        .map_err(|e| ContractError::Std("Error while saving proposal".to_string(), e))?;
 
-        let mint_sig = sign_mint_request(signing_key_one, mint_msg.clone());
+    Ok(response)
+}
 
-        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[
-            Coin {
-                denom: INJ_DENOM.into(),
-                amount: price_wei,
-            }
-        ]);
+// Payment made for a mint, either attached native funds or CW20 tokens sent through the receive hook
+enum MintPayment {
+    Native(Coin),
+    Cw20 {
+        token: Addr,
+        amount: Uint128,
+    },
+}
 
-        let mint_response = run_execute(deps.as_mut(), mock_env, normal_user_msg_info, ExecuteMsg::Mint {
-            request: mint_msg,
-            signature: mint_sig,
-        }).unwrap();
+impl MintPayment {
+    fn currency(&self) -> String {
+        match self {
+            MintPayment::Native(coin) => coin.denom.clone(),
+            MintPayment::Cw20 { token, .. } => token.to_string(),
+        }
+    }
 
-        assert_eq!(mint_response.messages.len(), 2);
+    fn amount(&self) -> Uint128 {
+        match self {
+            MintPayment::Native(coin) => coin.amount,
+            MintPayment::Cw20 { amount, .. } => *amount,
+        }
+    }
 
-        assert_eq!(mint_response.messages[0].msg, CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: COLLECTION_CONTRACT_ADDR.to_string(),
-            msg: to_json_binary(&dega_inj::cw721::ExecuteMsg::Mint {
-                token_id: Uint128::one().to_string(),
+    fn with_amount(&self, amount: Uint128) -> MintPayment {
+        match self {
+            MintPayment::Native(coin) => MintPayment::Native(Coin {
+                denom: coin.denom.clone(),
+                amount,
+            }),
+            MintPayment::Cw20 { token, .. } => MintPayment::Cw20 {
+                token: token.clone(),
+                amount,
+            },
+        }
+    }
+
+    fn transfer_msg(&self, recipient: &Addr, amount: Uint128) -> Result<CosmosMsg, ContractError> {
+        match self {
+            MintPayment::Native(coin) => Ok(CosmosMsg::Bank(BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![Coin {
+                    denom: coin.denom.clone(),
+                    amount,
+                }],
+            })),
+            MintPayment::Cw20 { token, .. } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: token.to_string(),
+                msg: to_json_binary_wrapped(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount,
+                }).map_err(|e| ContractError::Std("Error during conversion of CW20 transfer message to binary".to_string(), e))?,
+                funds: vec![],
+            })),
+        }
+    }
+}
+
+pub(crate) fn execute_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    request: MintRequest,
+    signature: String,
+) -> Result<Response, ContractError> {
+
+    if info.funds.len() > 1 {
+        return Err(ContractError::Generic("Must only provide one payment currency".to_string()));
+    }
+
+    let payment = info.funds.first().map(|funds| MintPayment::Native(funds.clone()));
+
+    mint_with_payment(deps, env, info.sender, request, signature, payment)
+}
+
+pub(crate) fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+
+    let ReceiveMsg::Mint { request, signature } = from_json(&cw20_msg.msg)
+        .map_err(|e| ContractError::Std("Invalid CW20 receive message".to_string(), e))?;
+
+    let sender = deps.api.addr_validate(cw20_msg.sender.as_str())
+        .map_err(|e| ContractError::Std("Invalid CW20 sender address".to_string(), e))?;
+
+    // The CW20 contract calling the hook is the token which was paid
+    let payment = MintPayment::Cw20 {
+        token: info.sender,
+        amount: cw20_msg.amount,
+    };
+
+    mint_with_payment(deps, env, sender, request, signature, Some(payment))
+}
+
+fn mint_with_payment(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    request: MintRequest,
+    signature: String,
+    payment: Option<MintPayment>,
+) -> Result<Response, ContractError> {
+
+    let dega_minter_settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+        .map_err(|e| ContractError::Std("Error during dega minter settings query".to_string(), e))?;
+
+    if dega_minter_settings.minting_paused {
+        return Err(ContractError::MintingPaused);
+    }
+
+    let check_sig_result = query_check_sig(
+        deps.as_ref(),
+        env.clone(),
+        VerifiableMsg::MintRequest(request.clone()),
+        signature.clone(),
+        SignerSourceType::ActiveSigners
+    ).map_err(|e| ContractError::Std("Error during signature verification".to_string(), e))?;
+
+    if !check_sig_result.is_valid {
+        return Err(ContractError::Generic("Signature is invalid".to_string()));
+    }
+
+    if request.chain_id != env.block.chain_id {
+        return Err(ContractError::DomainMismatch("chain ID".to_string(), env.block.chain_id.clone(), request.chain_id));
+    }
+
+    if request.minter != env.contract.address.as_str() {
+        return Err(ContractError::DomainMismatch("minter address".to_string(), env.contract.address.to_string(), request.minter));
+    }
+
+    if request.quantity == 0 {
+        return Err(ContractError::InvalidInput("Mint quantity must be at least one".to_string(), request.quantity.to_string()));
+    }
+
+    if !request.uris.is_empty() && request.uris.len() != request.quantity as usize {
+        return Err(ContractError::InvalidInput(
+            format!("Mint request must have one uri per token | Quantity: {}", request.quantity),
+            request.uris.len().to_string(),
+        ));
+    }
+
+    assert_supply_available(deps.storage, dega_minter_settings.max_supply, request.quantity as u64)?;
+
+    let to_addr = deps.api.addr_validate(request.to.as_str())
+        .map_err(|e| ContractError::Std("Invalid purchaser address".to_string(), e))?;
+
+    record_wallet_mints(deps.storage, dega_minter_settings.max_mints_per_wallet, &to_addr, request.quantity)?;
+
+    let epoch_time_128 = Uint128::from(env.block.time.seconds());
+
+    if epoch_time_128 < request.validity_start_timestamp {
+        return Err(ContractError::Generic(
+            format!("Request is not valid yet | Execution time: {} | Validity start: {}",
+                    epoch_time_128,
+                    request.validity_start_timestamp
+            ).to_string()
+        ));
+    }
+
+    if epoch_time_128 > request.validity_end_timestamp {
+        return Err(ContractError::Generic(
+            format!("Request is no longer valid | Execution time: {} | Validity end: {}",
+                    epoch_time_128,
+                    request.validity_end_timestamp
+            ).to_string()));
+    }
+
+    let sale_phase = load_active_phase(deps.storage, &env)?;
+
+    if let Some(sale_phase) = &sale_phase {
+        if sale_phase.eligibility != PhaseEligibility::SignedRequest {
+            return Err(ContractError::Generic(format!("Sale phase ({}) does not accept mint requests", sale_phase.name)));
+        }
+
+        if request.currency != sale_phase.denom || request.price != Uint256::from(sale_phase.price) {
+            return Err(ContractError::Generic(format!(
+                "Mint request price does not match the sale phase ({}) | Phase price: {} {} | Requested: {} {}",
+                sale_phase.name,
+                sale_phase.price,
+                sale_phase.denom,
+                request.price,
+                request.currency,
+            )));
+        }
+
+        record_phase_mints(deps.storage, sale_phase, &to_addr, request.quantity)?;
+    }
+
+    let uuid_record = UUID_REGISTRY.may_load(deps.storage, request.uuid.clone())
+        .map_err(|e| ContractError::Std("Error loading UUID registry".to_string(), e))?;
+
+    match uuid_record {
+        Some(uuid_record) if uuid_record.revoked => {
+            return Err(ContractError::Generic("Mint request has been revoked".to_string()));
+        },
+        Some(_) => return Err(ContractError::Generic("UUID already registered".to_string())),
+        None => {},
+    }
+
+    let total_price = request.price.checked_mul(Uint256::from(request.quantity))
+        .map_err(|e| ContractError::Std("Error calculating total price".to_string(), e.into()))?;
+
+    let refund_amount = assert_payment(&payment, &request.currency, total_price, &dega_minter_settings.overpayment_mode)?;
+    let (payment, refund_msgs) = refund_excess(payment, &sender, refund_amount)?;
+
+    let this_collection_address = load_item_wrapped(deps.storage, &COLLECTION_ADDRESS)
+                                                    .map_err(|e| ContractError::Std("Error while loading collection address".to_string(), e))?;
+
+    let request_collection_address = deps.api.addr_validate(request.collection.as_str())
+                                         .map_err(|e| ContractError::Std("Invalid request collection address".to_string(), e))?;
+
+    if this_collection_address != request_collection_address {
+        return Err(ContractError::Generic(format!(
+            "Mint request authorized for collection ({}) sent to incorrect collection ({})",
+            request_collection_address.as_str(),
+            this_collection_address.as_str(),
+        )));
+    }
+
+    let mut token_ids: Vec<u64> = vec![];
+    let mut mint_wasm_msgs: Vec<CosmosMsg> = vec![];
+
+    // Create one mint msg per token, with its own uri when the request lists them
+    for index in 0..request.quantity as usize {
+        let token_uri = request.uris.get(index).unwrap_or(&request.uri);
+        let (token_id, mint_wasm_msg) = next_token_mint_msg(deps.storage, &this_collection_address, &request.to, token_uri)?;
+
+        mint_wasm_msgs.push(mint_wasm_msg);
+        token_ids.push(token_id);
+    }
+
+    let uuid_record = UuidRecord {
+        revoked: false,
+        token_ids: token_ids.clone(),
+        validity_end_timestamp: Some(request.validity_end_timestamp),
+    };
+
+    save_used_uuid(deps.storage, &request.uuid, &uuid_record)
+                 .map_err(|e| ContractError::Std("Error while registering UUID".to_string(), e))?;
+
+    increment_uuid_count(deps.storage)
+        .map_err(|e| ContractError::Std("Error while incrementing UUID count".to_string(), e))?;
+
+    // Create transfer proceeds msg
+    let sale_recipient_addr = deps.api.addr_validate(request.primary_sale_recipient.as_str())
+                                  .map_err(|e| ContractError::Std("Invalid primary sale recipient address".to_string(), e))?;
+
+    let (transfer_proceeds_msgs, payment_attributes) = match &payment {
+        Some(payment) => proceeds_transfer_msgs(&dega_minter_settings.platform_fee, &dega_minter_settings.revenue_splits, payment, &sale_recipient_addr)?,
+        None => (vec![], vec![]),
+    };
+
+    Ok(Response::new()
+        .add_messages(mint_wasm_msgs)
+        .add_messages(transfer_proceeds_msgs)
+        .add_messages(refund_msgs)
+        .add_attribute("action", "mint")
+        .add_attribute("sender", sender)
+        .add_attribute("signature", signature)
+        .add_attribute("signer_label", check_sig_result.signer_label.unwrap_or_default())
+        .add_attributes(token_ids.iter().map(|token_id| ("token_id", token_id.to_string())))
+        .add_attributes(payment_attributes)
+        .add_attributes(refund_attribute(refund_amount))
+        .add_attribute("collection_address", this_collection_address.to_string())
+        .add_attribute("request.to", request.to)
+        .add_attribute("request.primary_sale_recipient", request.primary_sale_recipient)
+        .add_attribute("request.uri", request.uri)
+        .add_attributes(request.uris.iter().map(|uri| ("request.uris", uri.clone())))
+        .add_attribute("request.quantity", request.quantity.to_string())
+        .add_attribute("request.price", request.price)
+        .add_attribute("request.currency", request.currency)
+        .add_attribute("request.validity_start_timestamp", request.validity_start_timestamp)
+        .add_attribute("request.validity_end_timestamp", request.validity_end_timestamp)
+        .add_attribute("request.collection", request.collection)
+        .add_attribute("request.chain_id", request.chain_id)
+        .add_attribute("request.minter", request.minter)
+        .add_attribute("request.uuid", request.uuid)
+        .add_attributes(sale_phase.map(|sale_phase| ("sale_phase", sale_phase.name)))
+    )
+}
+
+pub(crate) fn execute_public_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+
+    fixed_price_mint(deps, env, info, None)
+}
+
+pub(crate) fn execute_allowlist_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proof: AllowlistProof,
+) -> Result<Response, ContractError> {
+
+    fixed_price_mint(deps, env, info, Some(proof))
+}
+
+// Mint of the next token to the sender under the public sale settings, restricted to the allowlist when a proof is provided
+fn fixed_price_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    allowlist_proof: Option<AllowlistProof>,
+) -> Result<Response, ContractError> {
+
+    let dega_minter_settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+        .map_err(|e| ContractError::Std("Error during dega minter settings query".to_string(), e))?;
+
+    if dega_minter_settings.minting_paused {
+        return Err(ContractError::MintingPaused);
+    }
+
+    // The public sale settings also give the base uri and sale recipient of phase and allowlist mints
+    let public_sale = dega_minter_settings.public_sale.clone()
+        .ok_or_else(|| ContractError::Generic("Public sale is not enabled".to_string()))?;
+
+    assert_supply_available(deps.storage, dega_minter_settings.max_supply, 1)?;
+
+    let sale_phase = load_active_phase(deps.storage, &env)?;
+
+    if let Some(sale_phase) = &sale_phase {
+        match &sale_phase.eligibility {
+            PhaseEligibility::SignedRequest => {
+                return Err(ContractError::Generic(format!("Sale phase ({}) is not open to public mints", sale_phase.name)));
+            },
+            PhaseEligibility::Allowlist { .. } if allowlist_proof.is_none() => {
+                return Err(ContractError::Generic(format!("Sale phase ({}) requires an allowlist proof", sale_phase.name)));
+            },
+            _ => {},
+        }
+    }
+
+    // Proofs are not needed in public phases
+    let allowlist_applies = sale_phase.as_ref()
+        .map_or(true, |sale_phase| matches!(sale_phase.eligibility, PhaseEligibility::Allowlist { .. }));
+
+    let merkle_root = match allowlist_applies {
+        true => allowlist_root(&dega_minter_settings.allowlist_merkle_root, sale_phase.as_ref()),
+        false => None,
+    };
+
+    if merkle_root.is_some() && allowlist_proof.is_none() {
+        return Err(ContractError::Generic("An allowlist proof is required while an allowlist merkle root is set".to_string()));
+    }
+
+    // Phases have their own windows, outside of them allowlist mints are held to the public sale window as well
+    if sale_phase.is_none() {
+        let epoch_time_128 = Uint128::from(env.block.time.seconds());
+
+        if epoch_time_128 < public_sale.start_timestamp {
+            return Err(ContractError::Generic(
+                format!("Public sale has not started yet | Execution time: {} | Sale start: {}",
+                        epoch_time_128,
+                        public_sale.start_timestamp
+                )));
+        }
+
+        if let Some(end_timestamp) = public_sale.end_timestamp {
+            if epoch_time_128 > end_timestamp {
+                return Err(ContractError::Generic(
+                    format!("Public sale has ended | Execution time: {} | Sale end: {}",
+                            epoch_time_128,
+                            end_timestamp
+                    )));
+            }
+        }
+    }
+
+    let mut allowance = None;
+    if let (true, Some(allowlist_proof)) = (allowlist_applies, &allowlist_proof) {
+        let merkle_root = merkle_root
+            .ok_or_else(|| ContractError::Generic("No allowlist merkle root configured".to_string()))?;
+
+        let leaf = allowlist_leaf(info.sender.as_str(), allowlist_proof.allowance);
+
+        let is_valid = verify_merkle_proof(&merkle_root, leaf, &allowlist_proof.proof)
+            .map_err(|e| ContractError::Std("Error during allowlist proof verification".to_string(), e))?;
+
+        if !is_valid {
+            return Err(ContractError::Generic("Allowlist proof is invalid".to_string()));
+        }
+
+        allowance = Some(allowlist_proof.allowance);
+    }
+
+    let wallet_mint_count = record_wallet_mints(deps.storage, dega_minter_settings.max_mints_per_wallet, &info.sender, 1)?;
+
+    // The price of the active phase replaces the public sale price, outside of phases the dutch auction price does
+    let dutch_auction = match &sale_phase {
+        Some(_) => None,
+        None => dega_minter_settings.dutch_auction.clone(),
+    };
+
+    let public_sale_recipient = deps.api.addr_validate(public_sale.sale_recipient.as_str())
+        .map_err(|e| ContractError::Std("Invalid public sale recipient address".to_string(), e))?;
+
+    // The auction keeps selling in the denom and for the recipient of its first sale, which its held payments are in
+    let auction_sale = match &dutch_auction {
+        Some(_) => Some(AUCTION_SALE.may_load(deps.storage)
+            .map_err(|e| ContractError::Std("Error loading auction sale".to_string(), e))?
+            .unwrap_or(AuctionSale {
+                denom: public_sale.denom.clone(),
+                sale_recipient: public_sale_recipient.clone(),
+                platform_fee: dega_minter_settings.platform_fee.clone(),
+                revenue_splits: dega_minter_settings.revenue_splits.clone(),
+            })),
+        None => None,
+    };
+
+    let (price, denom, phase_mint_count) = match &sale_phase {
+        Some(sale_phase) => {
+            let phase_mint_count = record_phase_mints(deps.storage, sale_phase, &info.sender, 1)?;
+            (sale_phase.price, sale_phase.denom.clone(), Some(phase_mint_count))
+        },
+        None => {
+            let price = dutch_auction.as_ref()
+                .map_or(public_sale.price, |dutch_auction| dutch_auction_price(dutch_auction, &env.block.time));
+            let denom = auction_sale.as_ref()
+                .map_or(public_sale.denom.clone(), |auction_sale| auction_sale.denom.clone());
+            (price, denom, None)
+        },
+    };
+
+    // The allowance covers the mints of the phase, or all of the wallet's mints when no phases are scheduled
+    if let Some(allowance) = allowance {
+        let minted = phase_mint_count.unwrap_or(wallet_mint_count);
+        if minted > allowance {
+            return Err(ContractError::Generic(
+                format!("Allowlist allowance exceeded | Allowance: {} | Minted: {} | Requested: 1",
+                        allowance,
+                        minted - 1
+                )));
+        }
+    }
+
+    if info.funds.len() > 1 {
+        return Err(ContractError::Generic("Must only provide one payment currency".to_string()));
+    }
+
+    let payment = info.funds.first().map(|funds| MintPayment::Native(funds.clone()));
+
+    let refund_amount = assert_payment(&payment, &denom, Uint256::from(price), &dega_minter_settings.overpayment_mode)?;
+    let (payment, refund_msgs) = refund_excess(payment, &info.sender, refund_amount)?;
+
+    let this_collection_address = load_item_wrapped(deps.storage, &COLLECTION_ADDRESS)
+                                                    .map_err(|e| ContractError::Std("Error while loading collection address".to_string(), e))?;
+
+    // Tokens are minted in sequence, so the uri can be derived from the id the next mint will take
+    let next_token_id = TOKEN_INDEX.may_load(deps.storage)
+        .map_err(|e| ContractError::Std("Error loading token index".to_string(), e))?
+        .unwrap_or_default() + 1;
+    let token_uri = format!("{}{}", public_sale.base_uri, next_token_id);
+
+    let (token_id, mint_wasm_msg) = next_token_mint_msg(deps.storage, &this_collection_address, info.sender.as_str(), &token_uri)?;
+
+    let sale_recipient_addr = auction_sale.as_ref()
+        .map_or(public_sale_recipient, |auction_sale| auction_sale.sale_recipient.clone());
+
+    // Auction payments above the floor price are held by the minter until the buyer's rebate is claimed
+    let mut held_amount = Uint128::zero();
+    if let (Some(dutch_auction), Some(auction_sale)) = (&dutch_auction, &auction_sale) {
+        save_item_wrapped(deps.storage, &AUCTION_LAST_PRICE, &price)
+            .map_err(|e| ContractError::Std("Error while saving auction price".to_string(), e))?;
+
+        save_item_wrapped(deps.storage, &AUCTION_SALE, auction_sale)
+            .map_err(|e| ContractError::Std("Error while saving auction sale".to_string(), e))?;
+
+        if dutch_auction.rebates_enabled {
+            held_amount = price.saturating_sub(dutch_auction.floor_price);
+
+            let (quantity, paid) = AUCTION_PURCHASES.may_load(deps.storage, info.sender.clone())
+                .map_err(|e| ContractError::Std("Error loading auction purchases".to_string(), e))?
+                .unwrap_or_default();
+
+            let quantity = quantity.checked_add(1)
+                .ok_or_else(|| ContractError::Generic("Auction purchase count overflow".to_string()))?;
+            let paid = paid.checked_add(price)
+                .map_err(|e| ContractError::Std("Error calculating auction purchases total".to_string(), e.into()))?;
+
+            save_map_item_wrapped(deps.storage, &AUCTION_PURCHASES, info.sender.clone(), &(quantity, paid))
+                .map_err(|e| ContractError::Std("Error while saving auction purchases".to_string(), e))?;
+        }
+    }
+
+    let (transfer_proceeds_msgs, payment_attributes) = match &payment {
+        Some(payment) => {
+            let forwarded_payment = payment.with_amount(payment.amount() - held_amount);
+            match &auction_sale {
+                Some(auction_sale) => proceeds_transfer_msgs(
+                    &auction_sale.platform_fee, &auction_sale.revenue_splits, &forwarded_payment, &sale_recipient_addr)?,
+                None => proceeds_transfer_msgs(
+                    &dega_minter_settings.platform_fee, &dega_minter_settings.revenue_splits, &forwarded_payment, &sale_recipient_addr)?,
+            }
+        },
+        None => (vec![], vec![]),
+    };
+
+    let auction_attributes = match dutch_auction {
+        Some(dutch_auction) if dutch_auction.rebates_enabled => vec![("auction_held_amount", held_amount.to_string())],
+        _ => vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(mint_wasm_msg)
+        .add_messages(transfer_proceeds_msgs)
+        .add_messages(refund_msgs)
+        .add_attribute("action", if allowlist_proof.is_some() { "allowlist_mint" } else { "public_mint" })
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id.to_string())
+        .add_attribute("token_uri", token_uri)
+        .add_attributes(payment_attributes)
+        .add_attributes(refund_attribute(refund_amount))
+        .add_attribute("collection_address", this_collection_address.to_string())
+        .add_attribute("price", price)
+        .add_attribute("denom", denom)
+        .add_attributes(sale_phase.map(|sale_phase| ("sale_phase", sale_phase.name)))
+        .add_attributes(auction_attributes)
+    )
+}
+
+pub(crate) fn execute_claim_auction_rebate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Option<String>,
+) -> Result<Response, ContractError> {
+
+    let dega_minter_settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+        .map_err(|e| ContractError::Std("Error during dega minter settings query".to_string(), e))?;
+
+    let dutch_auction = dega_minter_settings.dutch_auction.clone()
+        .filter(|dutch_auction| dutch_auction.rebates_enabled)
+        .ok_or_else(|| ContractError::Generic("Dutch auction rebates are not enabled".to_string()))?;
+
+    // Held payments are in the denom and for the recipient the auction sold under, whatever the public sale is now
+    let auction_sale = AUCTION_SALE.may_load(deps.storage)
+        .map_err(|e| ContractError::Std("Error loading auction sale".to_string(), e))?
+        .ok_or_else(|| ContractError::Generic("Dutch auction has not sold any tokens".to_string()))?;
+
+    let buyer = match address {
+        Some(address) => deps.api.addr_validate(address.as_str())
+            .map_err(|e| ContractError::Std("Invalid rebate address".to_string(), e))?,
+        None => info.sender.clone(),
+    };
+
+    let clearing_price = auction_clearing_price(deps.storage, &dutch_auction, dega_minter_settings.max_supply, &env.block.time)
+        .map_err(|e| ContractError::Std("Error calculating auction clearing price".to_string(), e))?
+        .ok_or_else(|| ContractError::Generic("Dutch auction clearing price is not final yet".to_string()))?;
+
+    // Later claims settle at the same price, whatever happens to the supply or the time based price
+    save_item_wrapped(deps.storage, &AUCTION_CLEARING_PRICE, &clearing_price)
+        .map_err(|e| ContractError::Std("Error while saving auction clearing price".to_string(), e))?;
+
+    let (quantity, paid) = AUCTION_PURCHASES.may_load(deps.storage, buyer.clone())
+        .map_err(|e| ContractError::Std("Error loading auction purchases".to_string(), e))?
+        .ok_or_else(|| ContractError::Generic(format!("No auction purchases to settle for address: {}", buyer)))?;
+
+    AUCTION_PURCHASES.remove(deps.storage, buyer.clone());
+
+    // Of what was held above the floor price, the part above the clearing price goes back to the buyer
+    // and the rest is released to the sale recipients
+    let held_amount = paid.saturating_sub(dutch_auction.floor_price.saturating_mul(Uint128::from(quantity)));
+    let rebate_amount = paid.saturating_sub(clearing_price.saturating_mul(Uint128::from(quantity)));
+    let released_amount = held_amount.saturating_sub(rebate_amount);
+
+    let mut response = Response::new()
+        .add_attribute("action", "claim_auction_rebate")
+        .add_attribute("sender", info.sender)
+        .add_attribute("address", buyer.to_string())
+        .add_attribute("clearing_price", clearing_price)
+        .add_attribute("rebate_amount", rebate_amount)
+        .add_attribute("released_amount", released_amount);
+
+    if !rebate_amount.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: buyer.to_string(),
+            amount: vec![Coin {
+                denom: auction_sale.denom.clone(),
+                amount: rebate_amount,
+            }],
+        });
+    }
+
+    if !released_amount.is_zero() {
+        let released_payment = MintPayment::Native(Coin {
+            denom: auction_sale.denom,
+            amount: released_amount,
+        });
+
+        let (transfer_proceeds_msgs, payment_attributes) = proceeds_transfer_msgs(
+            &auction_sale.platform_fee, &auction_sale.revenue_splits, &released_payment, &auction_sale.sale_recipient)?;
+
+        response = response
+            .add_messages(transfer_proceeds_msgs)
+            .add_attributes(payment_attributes);
+    }
+
+    Ok(response)
+}
+
+pub(crate) fn execute_update_phases(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    phases: Vec<SalePhase>,
+) -> Result<Response, ContractError> {
+
+    validate_sale_phases(&phases)
+        .map_err(|e| ContractError::Std("Invalid sale phases".to_string(), e))?;
+
+    if let Some(response) = propose_if_multisig(deps, env, info, ProposalAction::UpdatePhases {
+        phases: phases.clone(),
+    })? {
+        return Ok(response);
+    }
+
+    assert_role(deps.storage, &info.sender, Role::SuperAdmin, "update sale phases")?;
+
+    update_phases(deps, &info.sender, phases)
+}
+
+fn update_phases(
+    deps: &mut DepsMut,
+    sender: &Addr,
+    phases: Vec<SalePhase>,
+) -> Result<Response, ContractError> {
+
+    save_item_wrapped(deps.storage, &SALE_PHASES, &phases)
+        .map_err(|e| ContractError::Std("Error while saving sale phases".to_string(), e))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_phases")
+        .add_attribute("sender", sender.clone())
+        .add_attribute("phases", phases.iter()
+            .map(|phase| phase.name.clone())
+            .collect::<Vec<String>>()
+            .join(","))
+    )
+}
+
+pub(crate) fn execute_revoke_mint_requests(
+    deps: &mut DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    uuids: Vec<String>,
+) -> Result<Response, ContractError> {
+
+    assert_role(deps.storage, &info.sender, Role::SignerManager, "revoke mint requests")?;
+
+    if uuids.is_empty() {
+        return Err(ContractError::InvalidInput("No UUIDs provided".to_string(), "[]".to_string()));
+    }
+
+    let mut response = Response::new()
+        .add_attribute("action", "revoke_mint_requests")
+        .add_attribute("sender", info.sender.clone());
+
+    for uuid in uuids {
+        let uuid_record = UUID_REGISTRY.may_load(deps.storage, uuid.clone())
+            .map_err(|e| ContractError::Std("Error loading UUID registry".to_string(), e))?;
+
+        // Revoking an already revoked request is a no-op, while used requests can no longer be revoked
+        match uuid_record {
+            Some(uuid_record) if uuid_record.revoked => continue,
+            Some(_) => return Err(ContractError::Generic(format!("Mint request has already been used: {}", uuid))),
+            None => {},
+        }
+
+        let uuid_record = UuidRecord {
+            revoked: true,
+            ..Default::default()
+        };
+
+        save_map_item_wrapped(deps.storage, &UUID_REGISTRY, uuid.clone(), &uuid_record)
+            .map_err(|e| ContractError::Std("Error while revoking UUID".to_string(), e))?;
+
+        response = response.add_attribute("uuid", uuid);
+    }
+
+    Ok(response)
+}
+
+pub(crate) fn execute_prune_uuids(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    limit: u32,
+) -> Result<Response, ContractError> {
+
+    if limit == 0 {
+        return Err(ContractError::InvalidInput("Prune limit must be at least one".to_string(), limit.to_string()));
+    }
+
+    // Requests remain valid up to and including their validity end
+    let expired_before = Bound::exclusive((env.block.time.seconds(), String::new()));
+
+    let expired_keys = map_keys_wrapped(deps.storage, &UUID_EXPIRATIONS, None, Some(expired_before), Order::Ascending)
+        .take(limit.min(MAX_UUID_PRUNE_LIMIT) as usize)
+        .collect::<StdResult<Vec<(u64, String)>>>()
+        .map_err(|e| ContractError::Std("Error loading expired UUIDs".to_string(), e))?;
+
+    for (expiration, uuid) in &expired_keys {
+        UUID_REGISTRY.remove(deps.storage, uuid.clone());
+        UUID_EXPIRATIONS.remove(deps.storage, (*expiration, uuid.clone()));
+    }
+
+    decrease_uuid_count(deps.storage, expired_keys.len() as u64)
+        .map_err(|e| ContractError::Std("Error while decreasing UUID count".to_string(), e))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "prune_uuids")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("pruned_count", expired_keys.len().to_string())
+    )
+}
+
+pub(crate) fn execute_airdrop(
+    deps: &mut DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    recipients: Vec<(String, String)>,
+) -> Result<Response, ContractError> {
+
+    assert_role(deps.storage, &info.sender, Role::Airdropper, "airdrop")?;
+
+    if recipients.is_empty() {
+        return Err(ContractError::InvalidInput("No airdrop recipients provided".to_string(), "[]".to_string()));
+    }
+
+    let dega_minter_settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+        .map_err(|e| ContractError::Std("Error loading dega minter settings".to_string(), e))?;
+
+    assert_supply_available(deps.storage, dega_minter_settings.max_supply, recipients.len() as u64)?;
+
+    let this_collection_address = load_item_wrapped(deps.storage, &COLLECTION_ADDRESS)
+                                                    .map_err(|e| ContractError::Std("Error while loading collection address".to_string(), e))?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "airdrop")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("collection_address", this_collection_address.to_string());
+
+    for (recipient, uri) in recipients {
+        deps.api.addr_validate(recipient.as_str())
+            .map_err(|e| ContractError::Std("Invalid airdrop recipient address".to_string(), e))?;
+
+        let (token_id, mint_wasm_msg) = next_token_mint_msg(deps.storage, &this_collection_address, &recipient, &uri)?;
+
+        response = response
+            .add_message(mint_wasm_msg)
+            .add_attribute("token_id", token_id.to_string())
+            .add_attribute("recipient", recipient);
+    }
+
+    Ok(response)
+}
+
+// Admins hold every role, so they are allowed along with the holders of the role
+fn assert_role(store: &dyn Storage, sender: &Addr, role: Role, action: &str) -> Result<(), ContractError> {
+    let sender_has_role = has_role(store, sender, &role)
+        .map_err(|e| ContractError::Std("Error checking sender role".to_string(), e))?;
+
+    if !sender_has_role {
+        let allowed = match role {
+            Role::SuperAdmin => "admins".to_string(),
+            role => format!("admins and {}s", role_name(&role)),
+        };
+
+        return Err(ContractError::Unauthorized(format!("Only {} can {}", allowed, action)));
+    }
+
+    Ok(())
+}
+
+// Role needed to propose and execute the action
+fn admin_action_role(action: &AdminAction) -> Role {
+    match action {
+        AdminAction::AddSigner { .. }
+        | AdminAction::RemoveSigner { .. }
+        | AdminAction::UpdateSignerPubKey { .. }
+        | AdminAction::UpdateSignerAddress { .. } => Role::SignerManager,
+        AdminAction::AddAdmin { .. }
+        | AdminAction::RemoveAdmin { .. }
+        | AdminAction::UpdateAdminActionDelay { .. } => Role::SuperAdmin,
+    }
+}
+
+fn validate_admin_address(api: &dyn Api, address: &str) -> Result<Addr, ContractError> {
+    api.addr_validate(address)
+        .map_err(|e| ContractError::Std("Invalid admin address".to_string(), e))
+}
+
+fn validate_role_address(api: &dyn Api, address: &str) -> Result<Addr, ContractError> {
+    api.addr_validate(address)
+        .map_err(|e| ContractError::Std("Invalid role address".to_string(), e))
+}
+
+// Checked when the action is proposed and again when it is carried out, as the state may have changed in between
+fn validate_admin_action(store: &dyn Storage, api: &dyn Api, action: &AdminAction) -> Result<(), ContractError> {
+    match action {
+        AdminAction::AddAdmin { address } => {
+            let address = validate_admin_address(api, address)?;
+
+            if ADMIN_LIST.has(store, address) {
+                return Err(ContractError::Generic("Address to add as admin is already an admin".to_string()));
+            }
+        },
+        AdminAction::RemoveAdmin { address } => {
+            let address = validate_admin_address(api, address)?;
+
+            if ADMIN_LIST.keys(store, None, None, Order::Ascending).count() < 2 {
+                return Err(ContractError::Generic("Cannot remove admin when one or none exists".to_string()));
+            }
+
+            if !ADMIN_LIST.has(store, address) {
+                return Err(ContractError::Generic("Address to remove as admin is not an admin".to_string()));
+            }
+
+            let dega_minter_settings = load_item_wrapped(store, &DEGA_MINTER_SETTINGS)
+                .map_err(|e| ContractError::Std("Error loading dega minter settings".to_string(), e))?;
+
+            if let Some(multisig) = dega_minter_settings.multisig {
+                let admin_count = ADMIN_LIST.keys(store, None, None, Order::Ascending).count();
+
+                if admin_count <= multisig.threshold as usize {
+                    return Err(ContractError::Generic(
+                        format!("Cannot remove admin below the multisig threshold | Threshold: {}", multisig.threshold)
+                    ));
+                }
+            }
+        },
+        AdminAction::AddSigner { label, signer } => {
+            if label.is_empty() || label == PRIMARY_SIGNER_LABEL || label == SIGNER_ADDRESS_LABEL {
+                return Err(ContractError::InvalidInput("Invalid signer label".to_string(), label.clone()));
+            }
+
+            if SIGNER_KEYS.has(store, label.clone()) {
+                return Err(ContractError::InvalidInput("Signer label already in use".to_string(), label.clone()));
+            }
+
+            verify_compressed_pub_key(signer.pub_key.clone())
+                .map_err(|e| ContractError::Std("Invalid signer public key".to_string(), e))?;
+
+            if let (Some(activation), Some(expiration)) = (signer.activation_timestamp, signer.expiration_timestamp) {
+                if expiration < activation {
+                    return Err(ContractError::InvalidInput(
+                        "Signer expiration must not be before activation".to_string(),
+                        format!("Activation: {} | Expiration: {}", activation, expiration),
+                    ));
+                }
+            }
+        },
+        AdminAction::RemoveSigner { label } => {
+            if ! SIGNER_KEYS.has(store, label.clone()) {
+                return Err(ContractError::InvalidInput("No signer found with label".to_string(), label.clone()));
+            }
+        },
+        AdminAction::UpdateSignerPubKey { signer_pub_key } => {
+            verify_compressed_pub_key(signer_pub_key.clone())
+                .map_err(|e| ContractError::Std("Invalid signer public key".to_string(), e))?;
+        },
+        AdminAction::UpdateSignerAddress { signer_address } => {
+            if let Some(signer_address) = signer_address {
+                api.addr_validate(signer_address)
+                    .map_err(|e| ContractError::Std("Invalid signer address".to_string(), e))?;
+            }
+        },
+        AdminAction::UpdateAdminActionDelay { .. } => {},
+    }
+
+    Ok(())
+}
+
+fn queue_admin_action(
+    deps: &mut DepsMut,
+    env: &Env,
+    proposer: &Addr,
+    action: AdminAction,
+) -> Result<PendingAdminAction, ContractError> {
+
+    validate_admin_action(deps.storage, deps.api, &action)?;
+
+    let dega_minter_settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+        .map_err(|e| ContractError::Std("Error loading dega minter settings".to_string(), e))?;
+
+    let id = increment_admin_action_count(deps.storage)
+        .map_err(|e| ContractError::Std("Error while incrementing admin action count".to_string(), e))?;
+
+    let pending_action = PendingAdminAction {
+        id,
+        action,
+        proposer: proposer.to_string(),
+        executable_after: Uint128::from(env.block.time.seconds().saturating_add(dega_minter_settings.admin_action_delay)),
+    };
+
+    save_map_item_wrapped(deps.storage, &ADMIN_ACTIONS, id, &pending_action)
+        .map_err(|e| ContractError::Std("Error while saving admin action".to_string(), e))?;
+
+    Ok(pending_action)
+}
+
+fn load_pending_admin_action(store: &dyn Storage, id: u64) -> Result<PendingAdminAction, ContractError> {
+    ADMIN_ACTIONS.may_load(store, id)
+        .map_err(|e| ContractError::Std("Error loading admin action".to_string(), e))?
+        .ok_or(ContractError::InvalidInput("No pending admin action with id".to_string(), id.to_string()))
+}
+
+fn carry_out_admin_action(deps: &mut DepsMut, env: &Env, pending_action: &PendingAdminAction) -> Result<(), ContractError> {
+    let epoch_time_128 = Uint128::from(env.block.time.seconds());
+
+    if epoch_time_128 < pending_action.executable_after {
+        return Err(ContractError::Generic(
+            format!("Admin action is not executable yet | Execution time: {} | Executable after: {}",
+                    epoch_time_128, pending_action.executable_after)
+        ));
+    }
+
+    validate_admin_action(deps.storage, deps.api, &pending_action.action)?;
+
+    match &pending_action.action {
+        AdminAction::AddAdmin { address } => {
+            save_map_item_wrapped(deps.storage, &ADMIN_LIST, validate_admin_address(deps.api, address)?, &Empty {})
+                .map_err(|e| ContractError::Std("Error while saving new admin".to_string(), e))?;
+        },
+        AdminAction::RemoveAdmin { address } => {
+            ADMIN_LIST.remove(deps.storage, validate_admin_address(deps.api, address)?);
+        },
+        AdminAction::AddSigner { label, signer } => {
+            save_map_item_wrapped(deps.storage, &SIGNER_KEYS, label.clone(), signer)
+                .map_err(|e| ContractError::Std("Error while saving signer key".to_string(), e))?;
+        },
+        AdminAction::RemoveSigner { label } => {
+            SIGNER_KEYS.remove(deps.storage, label.clone());
+        },
+        AdminAction::UpdateSignerPubKey { signer_pub_key } => {
+            update_stored_settings(deps.storage, |settings| settings.signer_pub_key.clone_from(signer_pub_key))?;
+        },
+        AdminAction::UpdateSignerAddress { signer_address } => {
+            update_stored_settings(deps.storage, |settings| settings.signer_address.clone_from(signer_address))?;
+        },
+        AdminAction::UpdateAdminActionDelay { admin_action_delay } => {
+            update_stored_settings(deps.storage, |settings| settings.admin_action_delay = *admin_action_delay)?;
+        },
+    }
+
+    ADMIN_ACTIONS.remove(deps.storage, pending_action.id);
+
+    Ok(())
+}
+
+fn update_stored_settings(
+    store: &mut dyn Storage,
+    update: impl FnOnce(&mut DegaMinterConfigSettings),
+) -> Result<(), ContractError> {
+
+    let mut dega_minter_settings = load_item_wrapped(store, &DEGA_MINTER_SETTINGS)
+        .map_err(|e| ContractError::Std("Error loading dega minter settings".to_string(), e))?;
+
+    update(&mut dega_minter_settings);
+
+    save_item_wrapped(store, &DEGA_MINTER_SETTINGS, &dega_minter_settings)
+        .map_err(|e| ContractError::Std("Error while saving dega minter settings".to_string(), e))
+}
+
+// Free mints are made without providing any payment. Returns the excess to refund, as overpayments are only
+// accepted in refund mode.
+fn assert_payment(
+    payment: &Option<MintPayment>,
+    currency: &str,
+    total_price: Uint256,
+    overpayment_mode: &OverpaymentMode,
+) -> Result<Uint128, ContractError> {
+    match payment {
+        Some(payment) => {
+            if payment.currency() != currency {
+                return Err(ContractError::Generic(
+                    format!("Payment currency does not match requested currency | Payment: {} | Requested: {}",
+                            payment.currency(),
+                            currency
+                    ).to_string()
+                ));
+            }
+
+            if Uint256::from(payment.amount()) < total_price {
+                return Err(ContractError::Generic(format!("Insufficient payment | Price: {} | Paid: {}", total_price, payment.amount())));
+            }
+
+            if Uint256::from(payment.amount()) > total_price {
+                if *overpayment_mode == OverpaymentMode::Strict {
+                    return Err(ContractError::Generic(format!("Overpayment | Price: {} | Paid: {}", total_price, payment.amount())));
+                }
+
+                // The payment fits in a Uint128, so does the excess
+                let refund_amount = Uint128::try_from(Uint256::from(payment.amount()) - total_price)
+                    .map_err(|e| ContractError::Std("Error calculating refund amount".to_string(), StdError::generic_err(e.to_string())))?;
+
+                return Ok(refund_amount);
+            }
+        },
+        None if total_price.is_zero() => {},
+        None => return Err(ContractError::Generic("No payment provided".to_string())),
+    }
+
+    Ok(Uint128::zero())
+}
+
+// Splits the refunded excess off the payment into a transfer back to the payer
+fn refund_excess(
+    payment: Option<MintPayment>,
+    payer: &Addr,
+    refund_amount: Uint128,
+) -> Result<(Option<MintPayment>, Vec<CosmosMsg>), ContractError> {
+
+    match payment {
+        Some(payment) if !refund_amount.is_zero() => {
+            let refund_msg = payment.transfer_msg(payer, refund_amount)?;
+            Ok((Some(payment.with_amount(payment.amount() - refund_amount)), vec![refund_msg]))
+        },
+        payment => Ok((payment, vec![])),
+    }
+}
+
+fn refund_attribute(refund_amount: Uint128) -> Option<(&'static str, String)> {
+    (!refund_amount.is_zero()).then(|| ("refund_amount", refund_amount.to_string()))
+}
+
+// Transfer messages along with the attributes describing them
+type ProceedsTransfers = (Vec<CosmosMsg>, Vec<(&'static str, String)>);
+
+// One transfer per recipient of the proceeds, starting with the platform fee
+fn proceeds_transfer_msgs(
+    platform_fee: &Option<PlatformFee>,
+    revenue_splits: &[RevenueSplit],
+    payment: &MintPayment,
+    sale_recipient_addr: &Addr,
+) -> Result<ProceedsTransfers, ContractError> {
+
+    let mut transfer_proceeds_msgs: Vec<CosmosMsg> = vec![];
+    let mut payment_attributes: Vec<(&str, String)> = vec![];
+    let mut sale_proceeds = payment.amount();
+
+    if let Some(platform_fee) = platform_fee {
+        let fee_amount = sale_proceeds.checked_mul_floor(platform_fee.share)
+            .map_err(|e| ContractError::Std("Error calculating platform fee".to_string(), StdError::generic_err(e.to_string())))?;
+        sale_proceeds = sale_proceeds.checked_sub(fee_amount)
+            .map_err(|e| ContractError::Std("Error calculating platform fee".to_string(), e.into()))?;
+
+        if !fee_amount.is_zero() {
+            transfer_proceeds_msgs.push(payment.transfer_msg(&Addr::unchecked(platform_fee.fee_address.clone()), fee_amount)?);
+        }
+
+        payment_attributes.push(("platform_fee_address", platform_fee.fee_address.clone()));
+        payment_attributes.push(("platform_fee_amount", fee_amount.to_string()));
+    }
+
+    let payouts = split_proceeds(sale_proceeds, revenue_splits, sale_recipient_addr)
+        .map_err(|e| ContractError::Std("Error splitting proceeds".to_string(), e))?;
+
+    for (recipient, amount) in payouts {
+        transfer_proceeds_msgs.push(payment.transfer_msg(&recipient, amount)?);
+    }
+
+    payment_attributes.push(("sale_proceeds_amount", sale_proceeds.to_string()));
+
+    Ok((transfer_proceeds_msgs, payment_attributes))
+}
+
+// Adds the quantity to the recipient's mint count, failing if the count would go over the per wallet limit.
+// Returns the updated count.
+fn record_wallet_mints(
+    store: &mut dyn Storage,
+    max_mints_per_wallet: Option<u32>,
+    recipient: &Addr,
+    quantity: u32,
+) -> Result<u32, ContractError> {
+
+    let minted = MINT_COUNTS.may_load(store, recipient.clone())
+        .map_err(|e| ContractError::Std("Error loading wallet mint count".to_string(), e))?
+        .unwrap_or_default();
+
+    if let Some(max_mints_per_wallet) = max_mints_per_wallet {
+        if minted.saturating_add(quantity) > max_mints_per_wallet {
+            return Err(ContractError::Generic(
+                format!("Wallet mint limit exceeded | Limit: {} | Minted: {} | Requested: {}",
+                        max_mints_per_wallet,
+                        minted,
+                        quantity
+                )));
+        }
+    }
+
+    let minted = minted.saturating_add(quantity);
+
+    save_map_item_wrapped(store, &MINT_COUNTS, recipient.clone(), &minted)
+        .map_err(|e| ContractError::Std("Error while saving wallet mint count".to_string(), e))?;
+
+    Ok(minted)
+}
+
+// Phase active at the block time, or None when the minter has no phases scheduled
+fn load_active_phase(store: &dyn Storage, env: &Env) -> Result<Option<SalePhase>, ContractError> {
+    let phases = SALE_PHASES.may_load(store)
+        .map_err(|e| ContractError::Std("Error loading sale phases".to_string(), e))?
+        .unwrap_or_default();
+
+    if phases.is_empty() {
+        return Ok(None);
+    }
+
+    match active_phase(&phases, &env.block.time) {
+        Some(phase) => Ok(Some(phase.clone())),
+        None => Err(ContractError::Generic(format!("No sale phase is active | Execution time: {}", env.block.time.seconds()))),
+    }
+}
+
+// Adds the quantity to the recipient's mint count for the phase, failing if the count would go over the phase limit.
+// Returns the updated count.
+fn record_phase_mints(
+    store: &mut dyn Storage,
+    sale_phase: &SalePhase,
+    recipient: &Addr,
+    quantity: u32,
+) -> Result<u32, ContractError> {
+
+    let key = (sale_phase.name.clone(), recipient.clone());
+
+    let minted = PHASE_MINT_COUNTS.may_load(store, key.clone())
+        .map_err(|e| ContractError::Std("Error loading phase mint count".to_string(), e))?
+        .unwrap_or_default();
+
+    if let Some(max_mints_per_wallet) = sale_phase.max_mints_per_wallet {
+        if minted.saturating_add(quantity) > max_mints_per_wallet {
+            return Err(ContractError::Generic(
+                format!("Phase mint limit exceeded | Phase: {} | Limit: {} | Minted: {} | Requested: {}",
+                        sale_phase.name,
+                        max_mints_per_wallet,
+                        minted,
+                        quantity
+                )));
+        }
+    }
+
+    let minted = minted.saturating_add(quantity);
+
+    save_map_item_wrapped(store, &PHASE_MINT_COUNTS, key, &minted)
+        .map_err(|e| ContractError::Std("Error while saving phase mint count".to_string(), e))?;
+
+    Ok(minted)
+}
+
+fn assert_supply_available(store: &dyn Storage, max_supply: Option<u64>, quantity: u64) -> Result<(), ContractError> {
+    if let Some(max_supply) = max_supply {
+        let minted = TOKEN_INDEX.may_load(store)
+            .map_err(|e| ContractError::Std("Error loading token index".to_string(), e))?
+            .unwrap_or_default();
+
+        if minted.saturating_add(quantity) > max_supply {
+            return Err(ContractError::Generic(format!(
+                "Max supply exceeded | Max supply: {} | Minted: {} | Requested: {}",
+                max_supply,
+                minted,
+                quantity,
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+// Assigns the next token id and creates the message minting it through the collection
+fn next_token_mint_msg(
+    store: &mut dyn Storage,
+    collection_address: &Addr,
+    owner: &str,
+    uri: &str,
+) -> Result<(u64, CosmosMsg), ContractError> {
+
+    let token_id = increment_token_index(store)
+        .map_err(|e| ContractError::Std("Error while incrementing token index".to_string(), e))?;
+
+    let mint_exec_msg = dega_inj::cw721::ExecuteMsg::Mint {
+        token_id: token_id.to_string(),
+        owner: owner.to_string(),
+        token_uri: Some(uri.to_string()),
+        extension: None,
+    };
+
+    let mint_wasm_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: collection_address.to_string(),
+        msg: to_json_binary_wrapped(&mint_exec_msg)
+            .map_err(|e| ContractError::Std("Error during conversion of mint exec message to binary".to_string(), e))?,
+        funds: vec![],
+    });
+
+    Ok((token_id, mint_wasm_msg))
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use cosmwasm_std::{Attribute, BankMsg, Coin, CosmosMsg, Decimal, Deps, StdError, Timestamp, to_json_binary, Uint128, WasmMsg};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use k256::ecdsa::SigningKey;
+    use k256::elliptic_curve::rand_core::OsRng;
+    use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+    use cw_storage_plus::Map;
+    use dega_inj::minter::{AdminsResponse, MintRequestEncoding, ReceiveMsg, PlatformFee, QueryMsg, MintCountResponse, CurrentPhaseResponse, CurrentPriceResponse, DutchAuction, MultisigSettings, OverpaymentMode, PendingAdminActionsResponse, PhaseEligibility, PhasesResponse, ProposalResponse, ProposalStatus, PublicSaleSettings, RevenueSplit, RolesResponse, SalePhase, SignatureScheme, SupplyResponse, UpdateAdminCommand, UpdateDegaMinterConfigSettingsMsg, UuidCountResponse, UuidsResponse, UuidStatus, UuidStatusResponse};
+    use dega_inj::test_helpers::{add_load_error_item, add_save_error_item, add_save_error_map, clear_load_error_items, clear_save_error_items, set_binary_for_json_error};
+    use crate::error::ContractError;
+    use crate::execute::{execute_accept_admin_nomination, execute_add_signer, execute_admin_action, execute_airdrop, execute_approve_proposal, execute_allowlist_mint, execute_cancel_admin_action, execute_claim_auction_rebate, execute_mint, execute_prune_uuids, execute_public_mint, execute_update_phases, execute_receive, execute_remove_signer, execute_update_admin, execute_update_royalty_settings, execute_update_settings};
+    use crate::query::{query_admins, query_config, query_current_phase, query_current_price, query_pending_admin_actions, query_proposal, query_proposals};
+    use crate::state::{MINT_COUNTS, TOKEN_INDEX};
+    use crate::lookup::SECP256K1_PUB_KEY_TYPE;
+    use crate::test_helpers::{build_allowlist, mock_dependencies_with_accounts, SIGNER_ACCOUNT_ADDR, BUYER_ADDR, COLLECTION_CONTRACT_ADDR, get_inj_wei_from_kilo_inj, get_signer_inj_address, get_signer_pub_key, INJ_DENOM, INVALID_ADDR, MINT_URI, NEW_ADMIN_ADDR, NORMAL_USER_ADDR, PRIMARY_SALE_RECIPIENT_ADDR, query_typed, run_pending_admin_actions, sign_mint_request, sign_mint_request_with_scheme, template_instantiate_msg, template_mint_msg, template_minter, template_minter_via_msg, template_reply_msg, USER_ADMIN_ADDR};
+    #[test]
+    fn access_restriction() {
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[]);
+
+        let new_settings_pause = UpdateDegaMinterConfigSettingsMsg {
+            signer_pub_key: None,
+            minting_paused: Some(true),
+            ..Default::default()
+        };
+
+        // Try to update settings as a regular user (should error)
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let unauthed_update_settings_err = execute_update_settings(&mut deps.as_mut(), &mock_env(), &normal_user_msg_info, &new_settings_pause).unwrap_err();
+        assert_eq!(unauthed_update_settings_err, ContractError::Unauthorized("Only admins and pausers can pause or unpause minting".to_string()));
+        assert!(!query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
+
+        // Try to update admins as a regular user (should error)
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let unauthed_update_admin_err = execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &normal_user_msg_info, NORMAL_USER_ADDR.to_string(), UpdateAdminCommand::Add).unwrap_err();
+        assert_eq!(unauthed_update_admin_err, ContractError::Unauthorized("Only admins can update admins".to_string()));
+        assert_eq!(query_admins(deps.as_ref(), mock_env(), None, None).unwrap().admins, vec![USER_ADMIN_ADDR.to_string()]);
+    }
+
+    #[test]
+    fn roles() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[]);
+        let grant = |deps: &mut DepsMut, address: &str, role: Role| execute_grant_role(deps, &mock_env(), &admin_msg_info, address.to_string(), role);
+        let roles = |deps: Deps, address: &str| query_typed::<RolesResponse>(deps, QueryMsg::Roles { address: address.to_string() }).unwrap().roles;
+        let pause_msg = UpdateDegaMinterConfigSettingsMsg {
+            minting_paused: Some(true),
+            ..Default::default()
+        };
+
+        let mut err_msg;
+
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &normal_user_msg_info, &pause_msg).unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins and pausers can pause or unpause minting"));
+
+        // Pausers can pause, but not change other settings
+        let response = grant(&mut deps.as_mut(), NORMAL_USER_ADDR, Role::Pauser).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("role", "Pauser")));
+        execute_update_settings(&mut deps.as_mut(), &env, &normal_user_msg_info, &pause_msg).unwrap();
+        assert!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.minting_paused);
+
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &normal_user_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            max_supply: Some(10),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins can update settings"));
+
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &normal_user_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            minting_paused: Some(false),
+            signature_scheme: Some(SignatureScheme::Eip191),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins and signer managers can update signer settings"));
+
+        // Signer managers manage signers and signing settings
+        grant(&mut deps.as_mut(), NORMAL_USER_ADDR, Role::SignerManager).unwrap();
+        execute_add_signer(&mut deps.as_mut(), &env, &normal_user_msg_info, "backend".to_string(), SignerKeyInfo {
+            pub_key: signer_pub_key.clone(),
+            activation_timestamp: None,
+            expiration_timestamp: None,
+        }).unwrap();
+        execute_update_settings(&mut deps.as_mut(), &env, &normal_user_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            minting_paused: Some(false),
+            signature_scheme: Some(SignatureScheme::Eip191),
+            ..Default::default()
+        }).unwrap();
+
+        err_msg = execute_airdrop(&mut deps.as_mut(), &env, &normal_user_msg_info, vec![(BUYER_ADDR.to_string(), MINT_URI.to_string())])
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins and airdroppers can airdrop"));
+
+        assert_eq!(roles(deps.as_ref(), NORMAL_USER_ADDR), vec![Role::Pauser, Role::SignerManager]);
+        assert_eq!(roles(deps.as_ref(), USER_ADMIN_ADDR), vec![Role::SuperAdmin]);
+        assert!(query_typed::<bool>(deps.as_ref(), QueryMsg::HasRole { address: USER_ADMIN_ADDR.to_string(), role: Role::Airdropper }).unwrap());
+        assert!(!query_typed::<bool>(deps.as_ref(), QueryMsg::HasRole { address: NORMAL_USER_ADDR.to_string(), role: Role::Airdropper }).unwrap());
+
+        // Only super admins manage roles
+        err_msg = execute_grant_role(&mut deps.as_mut(), &env, &normal_user_msg_info, NORMAL_USER_ADDR.to_string(), Role::Airdropper)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins can grant roles"));
+
+        err_msg = run_execute(deps.as_mut(), env.clone(), normal_user_msg_info.clone(), ExecuteMsg::RevokeRole {
+            address: NORMAL_USER_ADDR.to_string(),
+            role: Role::Pauser,
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins can revoke roles"));
+
+        err_msg = grant(&mut deps.as_mut(), NORMAL_USER_ADDR, Role::Pauser).unwrap_err().to_string();
+        assert!(err_msg.contains("Address already has the pauser role"));
+
+        err_msg = grant(&mut deps.as_mut(), INVALID_ADDR, Role::Pauser).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid role address"));
+
+        err_msg = execute_revoke_role(&mut deps.as_mut(), &env, &admin_msg_info, INVALID_ADDR.to_string(), Role::Pauser)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid role address"));
+
+        err_msg = execute_revoke_role(&mut deps.as_mut(), &env, &admin_msg_info, NORMAL_USER_ADDR.to_uppercase(), Role::Pauser)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid role address"));
+        assert_eq!(roles(deps.as_ref(), NORMAL_USER_ADDR), vec![Role::Pauser, Role::SignerManager]);
+
+        execute_revoke_role(&mut deps.as_mut(), &env, &admin_msg_info, NORMAL_USER_ADDR.to_string(), Role::Pauser).unwrap();
+        assert_eq!(roles(deps.as_ref(), NORMAL_USER_ADDR), vec![Role::SignerManager]);
+
+        err_msg = execute_revoke_role(&mut deps.as_mut(), &env, &admin_msg_info, NORMAL_USER_ADDR.to_string(), Role::Pauser)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Address does not have the pauser role"));
+
+        execute_revoke_role(&mut deps.as_mut(), &env, &admin_msg_info, NORMAL_USER_ADDR.to_string(), Role::SignerManager).unwrap();
+        assert!(roles(deps.as_ref(), NORMAL_USER_ADDR).is_empty());
+
+        // The super admin role is membership of the admin list, changed through admin actions
+        grant(&mut deps.as_mut(), NEW_ADMIN_ADDR, Role::SuperAdmin).unwrap();
+        assert!(!query_admins(deps.as_ref(), env.clone(), None, None).unwrap().admins.contains(&NEW_ADMIN_ADDR.to_string()));
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+        assert!(query_admins(deps.as_ref(), env.clone(), None, None).unwrap().admins.contains(&NEW_ADMIN_ADDR.to_string()));
+
+        execute_revoke_role(&mut deps.as_mut(), &env, &admin_msg_info, NEW_ADMIN_ADDR.to_string(), Role::SuperAdmin).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+        assert_eq!(query_admins(deps.as_ref(), env.clone(), None, None).unwrap().admins, vec![USER_ADMIN_ADDR.to_string()]);
+
+        err_msg = execute_revoke_role(&mut deps.as_mut(), &env, &admin_msg_info, USER_ADMIN_ADDR.to_string(), Role::SuperAdmin)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Cannot remove admin when one or none exists"));
+    }
+
+    #[test]
+    fn updating_admin() {
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+
+        // Ensure we canot remove ourself as the only admin
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let remove_only_admin_err = run_execute(deps.as_mut(), mock_env(), admin_msg_info.clone(),
+            ExecuteMsg::UpdateAdmin {
+                address: USER_ADMIN_ADDR.to_string(),
+                command: UpdateAdminCommand::Remove,
+            }
+        ).unwrap_err();
+
+        assert_eq!(remove_only_admin_err, ContractError::Generic("Cannot remove admin when one or none exists".to_string()));
+        assert_eq!(query_typed::<AdminsResponse>(deps.as_ref(), QueryMsg::Admins { start_after: None, limit: None }).unwrap().admins,
+                   vec![USER_ADMIN_ADDR.to_string()]);
+        assert!(query_typed::<bool>(deps.as_ref(), QueryMsg::IsAdmin { address: USER_ADMIN_ADDR.to_string() }).unwrap());
+
+        // Reset DB and minter after error (clean up to simulate rollback)
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Update admins as an admin, should succeed
+        execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &admin_msg_info, NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &admin_msg_info).unwrap();
+        assert_eq!(query_admins(deps.as_ref(), mock_env(), None, None).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+
+        // Ensure proper error when removing non admin address
+        let remove_non_admin_err = execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &admin_msg_info, NORMAL_USER_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap_err();
+        assert_eq!(remove_non_admin_err, ContractError::Generic("Address to remove as admin is not an admin".to_string()));
+        assert_eq!(query_admins(deps.as_ref(), mock_env(), None, None).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+
+        // Reset DB and minter after error (clean up to simulate rollback)
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Update admins as an admin, should succeed
+        execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &admin_msg_info, NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &admin_msg_info).unwrap();
+        assert_eq!(query_admins(deps.as_ref(), mock_env(), None, None).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+
+        // Updating admins as the new admin should succeed
+        let new_admin_msg_info = mock_info(NEW_ADMIN_ADDR, &[]);
+        execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &new_admin_msg_info, NORMAL_USER_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &new_admin_msg_info).unwrap();
+        assert_eq!(query_admins(deps.as_ref(), mock_env(), None, None).unwrap().admins,
+                   vec![NEW_ADMIN_ADDR.to_string(), NORMAL_USER_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+
+        // Admins can be listed a page at a time
+        assert_eq!(query_typed::<AdminsResponse>(deps.as_ref(), QueryMsg::Admins {
+            start_after: None,
+            limit: Some(2),
+        }).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), NORMAL_USER_ADDR.to_string()]);
+        assert_eq!(query_admins(deps.as_ref(), mock_env(), Some(NORMAL_USER_ADDR.to_string()), Some(2)).unwrap().admins,
+                   vec![USER_ADMIN_ADDR.to_string()]);
+        assert!(query_admins(deps.as_ref(), mock_env(), Some(USER_ADMIN_ADDR.to_string()), None).unwrap().admins.is_empty());
+
+        // Remove myself as an admin, should succeed
+        execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &admin_msg_info, USER_ADMIN_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &admin_msg_info).unwrap();
+        assert_eq!(query_admins(deps.as_ref(), mock_env(), None, None).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), NORMAL_USER_ADDR.to_string()]);
+
+        // Updating admins as the old admin should fail now
+        let removed_update_admin_err = execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &admin_msg_info, USER_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap_err();
+        assert_eq!(removed_update_admin_err, ContractError::Unauthorized("Only admins can update admins".to_string()));
+        assert_eq!(query_admins(deps.as_ref(), mock_env(), None, None).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), NORMAL_USER_ADDR.to_string()]);
+    }
+
+    #[test]
+    fn updating_settings() {
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        // Initialize fresh DB and minter
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Should be starting unpaused
+        assert!(!query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
+
+        let new_settings_pause = UpdateDegaMinterConfigSettingsMsg {
+            signer_pub_key: None,
+            minting_paused: Some(true),
+            ..Default::default()
+        };
+
+        let new_settings_unpause = UpdateDegaMinterConfigSettingsMsg {
+            signer_pub_key: None,
+            minting_paused: Some(false),
+            ..Default::default()
+        };
+
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+
+        // Update settings as an admin, should succeed
+        execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_pause).unwrap();
+        assert!(query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
+
+        // Change the signing key
+        let second_signing_key = SigningKey::random(&mut OsRng);
+        let second_signer_pub_key = get_signer_pub_key(&second_signing_key);
+        let new_settings_signer = UpdateDegaMinterConfigSettingsMsg {
+            signer_pub_key: Some(second_signer_pub_key.clone()),
+            minting_paused: None,
+            ..Default::default()
+        };
+        execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_signer).unwrap();
+        assert_eq!(query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.signer_pub_key, signer_pub_key);
+        run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &admin_msg_info).unwrap();
+        assert_eq!(query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.signer_pub_key, second_signer_pub_key);
+
+
+        // Add new admin
+        execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &admin_msg_info, NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &admin_msg_info).unwrap();
+
+        // New admin should be able to pause
+        let new_admin_msg_info = mock_info(NEW_ADMIN_ADDR, &[]);
+        execute_update_settings(&mut deps.as_mut(), &mock_env(), &new_admin_msg_info, &new_settings_unpause).unwrap();
+        assert!(!query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
+
+        // Remove myself as an admin
+        execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &admin_msg_info, USER_ADMIN_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &admin_msg_info).unwrap();
+
+        // Updating settings as the old admin should fail now
+        let removed_update_settings_err = execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_unpause).unwrap_err();
+        assert_eq!(removed_update_settings_err, ContractError::Unauthorized("Only admins and pausers can pause or unpause minting".to_string()));
+        assert!(!query_config(deps.as_ref(), mock_env()).unwrap().dega_minter_settings.minting_paused);
+    }
+
+    #[test]
+    fn updating_settings_errors() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+
+        let new_settings_pause = UpdateDegaMinterConfigSettingsMsg {
+            signer_pub_key: None,
+            minting_paused: Some(true),
+            ..Default::default()
+        };
+
+        // Error due to being unable to load minter settings
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        add_load_error_item(&DEGA_MINTER_SETTINGS);
+        let err_msg = execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_pause)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error loading dega minter settings"));
+        clear_load_error_items();
+
+        // Error due to bad signer public key
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let mut invalid_key_settings = new_settings_pause.clone();
+        invalid_key_settings.signer_pub_key = Some("Invalid Pub Key".to_string());
+        let err_msg = execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &invalid_key_settings)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid signer public key"));
+
+        // Error due to being unable to save minter settings
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        add_save_error_item(&DEGA_MINTER_SETTINGS);
+        let err_msg = execute_update_settings(&mut deps.as_mut(), &mock_env(), &admin_msg_info, &new_settings_pause)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error while saving dega minter settings"));
+        clear_save_error_items();
+    }
+
+    #[test]
+    fn updating_admin_errors() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+
+        // Add address that is already admin
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let add_existing_admin_err = execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &admin_msg_info, USER_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add)
+            .unwrap_err().to_string();
+        assert!(add_existing_admin_err.contains("Address to add as admin is already an admin"));
+
+        // Addresses that aren't valid or normalized can't be added or removed
+        for address in [INVALID_ADDR, "New_Admin_Addr"] {
+            for command in [UpdateAdminCommand::Add, UpdateAdminCommand::Remove] {
+                let invalid_admin_err = execute_update_admin(
+                    &mut deps.as_mut(), &mock_env(), &admin_msg_info, address.to_string(), command)
+                    .unwrap_err().to_string();
+                assert!(invalid_admin_err.contains("Invalid admin address"));
+            }
+        }
+
+        // Unable to save address to admin list
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let new_admin_addr = "new_admin_addr".to_string();
+        execute_update_admin(
+            &mut deps.as_mut(), &mock_env(), &admin_msg_info, new_admin_addr, UpdateAdminCommand::Add).unwrap();
+        add_save_error_map(&ADMIN_LIST);
+        let add_existing_admin_err = run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &admin_msg_info)
+            .unwrap_err().to_string();
+        assert!(add_existing_admin_err.contains("Error while saving new admin"));
+        clear_save_error_items()
+    }
+
+    #[test]
+    fn admin_actions() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
+        let now = env.block.time.seconds();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let new_admin_msg_info = mock_info(NEW_ADMIN_ADDR, &[]);
+        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[]);
+
+        let mut later_env = env.clone();
+        later_env.block.time = Timestamp::from_seconds(now + 100);
+
+        let pending_actions = |deps: Deps| {
+            query_typed::<PendingAdminActionsResponse>(deps, QueryMsg::PendingAdminActions {}).unwrap().actions
+        };
+
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // The delay itself is changed through an admin action, taking effect once carried out
+        let response = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            admin_action_delay: Some(100),
+            ..Default::default()
+        }).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("admin_action_delay.admin_action_id", "1")));
+        assert_eq!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.admin_action_delay, 0);
+        execute_admin_action(&mut deps.as_mut(), &env, &admin_msg_info, 1).unwrap();
+        assert_eq!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.admin_action_delay, 100);
+        assert!(pending_actions(deps.as_ref()).is_empty());
+
+        // Nominate a new admin, queued until the delay has passed
+        let response = execute_update_admin(&mut deps.as_mut(), &env, &admin_msg_info,
+                                            NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("admin_action_id", "2")));
+        assert_eq!(pending_actions(deps.as_ref()), vec![PendingAdminAction {
+            id: 2,
+            action: AdminAction::AddAdmin { address: NEW_ADMIN_ADDR.to_string() },
+            proposer: USER_ADMIN_ADDR.to_string(),
+            executable_after: Uint128::from(now + 100),
+        }]);
+        assert_eq!(query_admins(deps.as_ref(), env.clone(), None, None).unwrap().admins, vec![USER_ADMIN_ADDR.to_string()]);
+
+        let mut err_msg;
+
+        err_msg = execute_admin_action(&mut deps.as_mut(), &later_env, &admin_msg_info, 2).unwrap_err().to_string();
+        assert!(err_msg.contains("Admin nominations must be accepted by the nominee"));
+
+        err_msg = execute_accept_admin_nomination(&mut deps.as_mut(), &env, &new_admin_msg_info, 2).unwrap_err().to_string();
+        assert!(err_msg.contains("Admin action is not executable yet"));
+
+        err_msg = execute_accept_admin_nomination(&mut deps.as_mut(), &later_env, &normal_user_msg_info, 2).unwrap_err().to_string();
+        assert!(err_msg.contains("Only the nominee can accept an admin nomination"));
+
+        run_execute(deps.as_mut(), later_env.clone(), new_admin_msg_info.clone(), ExecuteMsg::AcceptAdminNomination { id: 2 }).unwrap();
+        assert_eq!(query_admins(deps.as_ref(), env.clone(), None, None).unwrap().admins, vec![NEW_ADMIN_ADDR.to_string(), USER_ADMIN_ADDR.to_string()]);
+        assert!(pending_actions(deps.as_ref()).is_empty());
+
+        err_msg = execute_accept_admin_nomination(&mut deps.as_mut(), &later_env, &new_admin_msg_info, 2).unwrap_err().to_string();
+        assert!(err_msg.contains("No pending admin action with id"));
+
+        // Other admins can cancel a pending removal, users without a role can't
+        execute_update_admin(&mut deps.as_mut(), &env, &admin_msg_info,
+                             NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap();
+
+        err_msg = execute_cancel_admin_action(&mut deps.as_mut(), &env, &normal_user_msg_info, 3).unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins and the proposer can cancel admin actions"));
+
+        run_execute(deps.as_mut(), env.clone(), new_admin_msg_info.clone(), ExecuteMsg::CancelAdminAction { id: 3 }).unwrap();
+        assert!(pending_actions(deps.as_ref()).is_empty());
+
+        err_msg = execute_admin_action(&mut deps.as_mut(), &later_env, &admin_msg_info, 3).unwrap_err().to_string();
+        assert!(err_msg.contains("No pending admin action with id"));
+
+        // Signer managers propose signer changes and can cancel their own proposals
+        execute_grant_role(&mut deps.as_mut(), &env, &admin_msg_info, NORMAL_USER_ADDR.to_string(), Role::SignerManager).unwrap();
+        let signer = SignerKeyInfo {
+            pub_key: signer_pub_key.clone(),
+            activation_timestamp: None,
+            expiration_timestamp: None,
+        };
+        execute_add_signer(&mut deps.as_mut(), &env, &normal_user_msg_info, "backend".to_string(), signer.clone()).unwrap();
+
+        err_msg = execute_accept_admin_nomination(&mut deps.as_mut(), &later_env, &normal_user_msg_info, 4).unwrap_err().to_string();
+        assert!(err_msg.contains("Admin action is not an admin nomination"));
+
+        execute_cancel_admin_action(&mut deps.as_mut(), &env, &normal_user_msg_info, 4).unwrap();
+        assert!(pending_actions(deps.as_ref()).is_empty());
+
+        execute_add_signer(&mut deps.as_mut(), &env, &normal_user_msg_info, "backend".to_string(), signer.clone()).unwrap();
+        execute_admin_action(&mut deps.as_mut(), &later_env, &normal_user_msg_info, 5).unwrap();
+        assert!(SIGNER_KEYS.has(deps.as_ref().storage, "backend".to_string()));
+
+        // Signer managers can't carry out admin changes
+        execute_update_admin(&mut deps.as_mut(), &env, &admin_msg_info,
+                             NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap();
+        err_msg = execute_admin_action(&mut deps.as_mut(), &later_env, &normal_user_msg_info, 6).unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins can execute this admin action"));
+
+        // Actions are checked again when carried out
+        execute_update_admin(&mut deps.as_mut(), &env, &admin_msg_info,
+                             USER_ADMIN_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap();
+        execute_admin_action(&mut deps.as_mut(), &later_env, &admin_msg_info, 6).unwrap();
+        err_msg = execute_admin_action(&mut deps.as_mut(), &later_env, &admin_msg_info, 7).unwrap_err().to_string();
+        assert!(err_msg.contains("Cannot remove admin when one or none exists"));
+        assert_eq!(query_admins(deps.as_ref(), env.clone(), None, None).unwrap().admins, vec![USER_ADMIN_ADDR.to_string()]);
+    }
+
+    #[test]
+    fn multisig_proposals() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
+        let now = env.block.time.seconds();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let new_admin_msg_info = mock_info(NEW_ADMIN_ADDR, &[]);
+        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[]);
+
+        let mut later_env = env.clone();
+        later_env.block.time = Timestamp::from_seconds(now + 100);
+
+        let royalty_settings = RoyaltySettingsResponse {
+            payment_address: NORMAL_USER_ADDR.to_string(),
+            share: Decimal::percent(5),
+        };
+
+        let proposal = |deps: Deps, env: &Env, id: u64| {
+            query_proposal(deps, env.clone(), id).unwrap()
+        };
+
+        let has_event = |response: &Response, ty: &str| {
+            response.events.iter().any(|event| event.ty == ty)
+        };
+
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        execute_update_admin(&mut deps.as_mut(), &env, &admin_msg_info, NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Add).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+
+        // Without multisig approval royalty changes are forwarded to the collection right away
+        let mut err_msg = execute_update_royalty_settings(&mut deps.as_mut(), &env, &normal_user_msg_info, None)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins and collection editors can update royalty settings"));
+
+        let response = execute_update_royalty_settings(&mut deps.as_mut(), &env, &admin_msg_info, Some(royalty_settings.clone())).unwrap();
+        assert_eq!(response.messages[0].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: COLLECTION_CONTRACT_ADDR.to_string(),
+            msg: to_json_binary(&dega_inj::cw721::ExecuteMsg::UpdateCollectionInfo {
+                collection_info: UpdateCollectionInfoMsg {
+                    description: None,
+                    image: None,
+                    external_link: None,
+                    royalty_settings: Some(Some(royalty_settings.clone())),
+                },
+            }).unwrap(),
+            funds: vec![],
+        }));
+
+        // The threshold must be reachable by the admins
+        for (threshold, proposal_duration, error) in [
+            (0, 100, "Multisig threshold must be at least one"),
+            (3, 100, "Multisig threshold cannot be above the number of admins"),
+            (2, 0, "Multisig proposal duration must be positive"),
+        ] {
+            err_msg = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+                multisig: Some(Some(MultisigSettings { threshold, proposal_duration })),
+                ..Default::default()
+            }).unwrap_err().to_string();
+            assert!(err_msg.contains(error));
+        }
+
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            multisig: Some(Some(MultisigSettings { threshold: 2, proposal_duration: 100 })),
+            ..Default::default()
+        }).unwrap();
+
+        // Settings updates become proposals, approved by their proposer
+        let max_supply_update = UpdateDegaMinterConfigSettingsMsg {
+            max_supply: Some(10),
+            ..Default::default()
+        };
+        let response = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &max_supply_update).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("proposal_id", "1")));
+        assert!(has_event(&response, "multisig-proposal"));
+        assert!(!has_event(&response, "multisig-execution"));
+        assert!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.max_supply.is_none());
+        assert_eq!(proposal(deps.as_ref(), &env, 1), ProposalResponse {
+            id: 1,
+            action: ProposalAction::UpdateSettings { settings: max_supply_update.clone() },
+            proposer: USER_ADMIN_ADDR.to_string(),
+            approvals: vec![USER_ADMIN_ADDR.to_string()],
+            expiration: Uint128::from(now + 100),
+            status: ProposalStatus::Open,
+        });
+        assert_eq!(query_proposals(deps.as_ref(), env.clone()).unwrap().proposals.len(), 1);
+
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &normal_user_msg_info, &max_supply_update)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins can make proposals while multisig approval is enabled"));
+
+        err_msg = execute_approve_proposal(&mut deps.as_mut(), &env, &normal_user_msg_info, 1).unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins can approve proposals"));
+
+        err_msg = execute_approve_proposal(&mut deps.as_mut(), &env, &admin_msg_info, 1).unwrap_err().to_string();
+        assert!(err_msg.contains("Proposal has already been approved by sender"));
+
+        err_msg = execute_approve_proposal(&mut deps.as_mut(), &env, &admin_msg_info, 10).unwrap_err().to_string();
+        assert!(err_msg.contains("No proposal with id"));
+
+        // Reaching the threshold executes the proposal
+        let response = run_execute(deps.as_mut(), env.clone(), new_admin_msg_info.clone(), ExecuteMsg::ApproveProposal { id: 1 }).unwrap();
+        assert!(has_event(&response, "multisig-approval"));
+        assert!(has_event(&response, "multisig-execution"));
+        assert!(response.attributes.contains(&Attribute::new("max_supply", "10")));
+        assert_eq!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.max_supply, Some(10));
+        assert_eq!(proposal(deps.as_ref(), &env, 1).status, ProposalStatus::Executed);
+        assert_eq!(proposal(deps.as_ref(), &env, 1).approvals, vec![USER_ADMIN_ADDR.to_string(), NEW_ADMIN_ADDR.to_string()]);
+        assert!(query_proposals(deps.as_ref(), env.clone()).unwrap().proposals.is_empty());
+
+        err_msg = execute_approve_proposal(&mut deps.as_mut(), &env, &admin_msg_info, 1).unwrap_err().to_string();
+        assert!(err_msg.contains("Proposal has already been executed"));
+
+        // Royalty changes are proposed as well, and can no longer be approved once expired
+        let response = execute_update_royalty_settings(&mut deps.as_mut(), &env, &admin_msg_info, None).unwrap();
+        assert!(response.messages.is_empty());
+        err_msg = execute_approve_proposal(&mut deps.as_mut(), &later_env, &new_admin_msg_info, 2).unwrap_err().to_string();
+        assert!(err_msg.contains("Proposal has expired"));
+        assert_eq!(proposal(deps.as_ref(), &later_env, 2).status, ProposalStatus::Expired);
+
+        execute_update_royalty_settings(&mut deps.as_mut(), &env, &admin_msg_info, None).unwrap();
+        let response = execute_approve_proposal(&mut deps.as_mut(), &env, &new_admin_msg_info, 3).unwrap();
+        assert_eq!(response.messages.len(), 1);
+        assert!(response.attributes.contains(&Attribute::new("royalty_settings", "None")));
+
+        // Admin changes and the super admin role go through proposals, and can't leave fewer admins than the threshold
+        execute_update_admin(&mut deps.as_mut(), &env, &admin_msg_info, NEW_ADMIN_ADDR.to_string(), UpdateAdminCommand::Remove).unwrap();
+        err_msg = execute_approve_proposal(&mut deps.as_mut(), &env, &new_admin_msg_info, 4).unwrap_err().to_string();
+        assert!(err_msg.contains("Cannot remove admin below the multisig threshold"));
+
+        let response = execute_grant_role(&mut deps.as_mut(), &env, &admin_msg_info, NORMAL_USER_ADDR.to_string(), Role::SuperAdmin).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("proposal_id", "5")));
+        execute_approve_proposal(&mut deps.as_mut(), &env, &new_admin_msg_info, 5).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+        assert!(query_admins(deps.as_ref(), env.clone(), None, None).unwrap().admins.contains(&NORMAL_USER_ADDR.to_string()));
+
+        // Settings changes proposed by the new admin need a second approval as well
+        execute_update_settings(&mut deps.as_mut(), &env, &normal_user_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            max_supply: Some(5),
+            ..Default::default()
+        }).unwrap();
+        assert_eq!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.max_supply, Some(10));
+        execute_approve_proposal(&mut deps.as_mut(), &env, &admin_msg_info, 6).unwrap();
+        assert_eq!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.max_supply, Some(5));
+        assert_eq!(query_proposals(deps.as_ref(), env.clone()).unwrap().proposals.iter().map(|p| p.id).collect::<Vec<u64>>(), vec![2, 4]);
+
+        // Other roles and signers are proposed as well, rather than applied by a single admin
+        let response = execute_grant_role(&mut deps.as_mut(), &env, &admin_msg_info, BUYER_ADDR.to_string(), Role::Pauser).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("proposal_id", "7")));
+        assert!(ROLES.may_load(deps.as_ref().storage, Addr::unchecked(BUYER_ADDR)).unwrap().is_none());
+        execute_approve_proposal(&mut deps.as_mut(), &env, &new_admin_msg_info, 7).unwrap();
+        assert_eq!(ROLES.load(deps.as_ref().storage, Addr::unchecked(BUYER_ADDR)).unwrap(), vec![Role::Pauser]);
+
+        execute_revoke_role(&mut deps.as_mut(), &env, &admin_msg_info, BUYER_ADDR.to_string(), Role::Pauser).unwrap();
+        assert!(ROLES.has(deps.as_ref().storage, Addr::unchecked(BUYER_ADDR)));
+        execute_approve_proposal(&mut deps.as_mut(), &env, &new_admin_msg_info, 8).unwrap();
+        assert!(!ROLES.has(deps.as_ref().storage, Addr::unchecked(BUYER_ADDR)));
+
+        let signer = SignerKeyInfo {
+            pub_key: signer_pub_key.clone(),
+            activation_timestamp: None,
+            expiration_timestamp: None,
+        };
+        let response = execute_add_signer(&mut deps.as_mut(), &env, &admin_msg_info, "second".to_string(), signer.clone()).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("proposal_id", "9")));
+        assert!(query_pending_admin_actions(deps.as_ref(), env.clone()).unwrap().actions.is_empty());
+        execute_approve_proposal(&mut deps.as_mut(), &env, &new_admin_msg_info, 9).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+        assert_eq!(SIGNER_KEYS.load(deps.as_ref().storage, "second".to_string()).unwrap(), signer);
+
+        execute_remove_signer(&mut deps.as_mut(), &env, &admin_msg_info, "second".to_string()).unwrap();
+        assert!(query_pending_admin_actions(deps.as_ref(), env.clone()).unwrap().actions.is_empty());
+        execute_approve_proposal(&mut deps.as_mut(), &env, &new_admin_msg_info, 10).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+        assert!(!SIGNER_KEYS.has(deps.as_ref().storage, "second".to_string()));
+
+        // Pausing alone is not proposed, so pausers can stop minting right away
+        execute_grant_role(&mut deps.as_mut(), &env, &admin_msg_info, BUYER_ADDR.to_string(), Role::Pauser).unwrap();
+        execute_approve_proposal(&mut deps.as_mut(), &env, &new_admin_msg_info, 11).unwrap();
+        let pause_update = UpdateDegaMinterConfigSettingsMsg {
+            minting_paused: Some(true),
+            ..Default::default()
+        };
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &mock_info(BUYER_ADDR, &[]), &UpdateDegaMinterConfigSettingsMsg {
+            max_supply: Some(30),
+            ..pause_update.clone()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins can make proposals while multisig approval is enabled"));
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &mock_info(INVALID_ADDR, &[]), &pause_update)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("pause or unpause minting"));
+        let response = execute_update_settings(&mut deps.as_mut(), &env, &mock_info(BUYER_ADDR, &[]), &pause_update).unwrap();
+        assert!(!has_event(&response, "multisig-proposal"));
+        assert!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.minting_paused);
+
+        // Sale phases set prices as well, so a lone admin can only propose them
+        let public_phase = SalePhase {
+            name: "public".to_string(),
+            start_timestamp: Uint128::from(now),
+            end_timestamp: Uint128::from(now + 100),
+            price: Uint128::one(),
+            denom: INJ_DENOM.to_string(),
+            max_mints_per_wallet: None,
+            eligibility: PhaseEligibility::Public,
+        };
+        let response = execute_update_phases(&mut deps.as_mut(), &env, &admin_msg_info, vec![public_phase.clone()]).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("proposal_id", "12")));
+        assert!(query_typed::<PhasesResponse>(deps.as_ref(), QueryMsg::Phases {}).unwrap().phases.is_empty());
+        assert_eq!(proposal(deps.as_ref(), &env, 12).action, ProposalAction::UpdatePhases { phases: vec![public_phase.clone()] });
+
+        execute_approve_proposal(&mut deps.as_mut(), &env, &new_admin_msg_info, 12).unwrap();
+        assert_eq!(query_typed::<PhasesResponse>(deps.as_ref(), QueryMsg::Phases {}).unwrap().phases, vec![public_phase]);
+
+        let err = query_proposal(deps.as_ref(), env.clone(), 20).unwrap_err().to_string();
+        assert!(err.contains("No proposal with id"));
+    }
+
+    #[test]
+    fn valid_mint() {
+
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+
+        // Create a first keypair and to store in the minter
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        // Initialize fresh DB and minter
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        let mock_env = mock_env();
+
+        let mint_msg = template_mint_msg(&mock_env, price_wei);
+
+        let mint_sig = sign_mint_request(signing_key_one, mint_msg.clone());
+
+        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[
+            Coin {
+                denom: INJ_DENOM.into(),
+                amount: price_wei,
+            }
+        ]);
+
+        let mint_response = run_execute(deps.as_mut(), mock_env, normal_user_msg_info, ExecuteMsg::Mint {
+            request: mint_msg,
+            signature: mint_sig,
+        }).unwrap();
+
+        assert_eq!(mint_response.messages.len(), 2);
+
+        assert_eq!(mint_response.messages[0].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: COLLECTION_CONTRACT_ADDR.to_string(),
+            msg: to_json_binary(&dega_inj::cw721::ExecuteMsg::Mint {
+                token_id: Uint128::one().to_string(),
                 owner: BUYER_ADDR.to_string(),
                 token_uri: Some(MINT_URI.to_string()),
                 extension: None,
@@ -531,18 +2854,484 @@ mod tests {
             funds: vec![],
         }));
 
-        assert_eq!(mint_response.messages[1].msg, CosmosMsg::Bank(BankMsg::Send {
-            to_address: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
-            amount: vec![Coin {
+        assert_eq!(mint_response.messages[1].msg, CosmosMsg::Bank(BankMsg::Send {
+            to_address: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+            amount: vec![Coin {
+                denom: INJ_DENOM.into(),
+                amount: price_wei,
+            }],
+        }));
+    }
+
+    #[test]
+    fn valid_batch_mint() {
+
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+        let total_price_wei = price_wei.checked_mul(Uint128::new(3)).unwrap();
+
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        let mock_env = mock_env();
+
+        let mut mint_msg = template_mint_msg(&mock_env, price_wei);
+        mint_msg.quantity = 3;
+
+        let mint_sig = sign_mint_request(signing_key_one.clone(), mint_msg.clone());
+
+        // Paying for a single token is not enough
+        let err = execute_mint(deps.as_mut(), mock_env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: price_wei, }
+        ]), mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Generic(
+            format!("Insufficient payment | Price: {} | Paid: {}", total_price_wei, price_wei)));
+
+        // Fresh minter since the failed execution above registered the UUID
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        let mint_response = execute_mint(deps.as_mut(), mock_env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: total_price_wei, }
+        ]), mint_msg.clone(), mint_sig.clone()).unwrap();
+
+        // One mint per token followed by a single proceeds transfer
+        assert_eq!(mint_response.messages.len(), 4);
+
+        for token_id in 1..=3u64 {
+            assert_eq!(mint_response.messages[token_id as usize - 1].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: COLLECTION_CONTRACT_ADDR.to_string(),
+                msg: to_json_binary(&dega_inj::cw721::ExecuteMsg::Mint {
+                    token_id: token_id.to_string(),
+                    owner: BUYER_ADDR.to_string(),
+                    token_uri: Some(MINT_URI.to_string()),
+                    extension: None,
+                }).unwrap(),
+                funds: vec![],
+            }));
+        }
+
+        assert_eq!(mint_response.messages[3].msg, CosmosMsg::Bank(BankMsg::Send {
+            to_address: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+            amount: vec![Coin {
+                denom: INJ_DENOM.into(),
+                amount: total_price_wei,
+            }],
+        }));
+
+        let token_id_attrs: Vec<String> = mint_response.attributes.iter()
+            .filter(|attr| attr.key == "token_id")
+            .map(|attr| attr.value.clone())
+            .collect();
+        assert_eq!(token_id_attrs, vec!["1", "2", "3"]);
+        assert_eq!(TOKEN_INDEX.load(&deps.storage).unwrap(), 3);
+
+        // Zero quantity
+        mint_msg.quantity = 0;
+        mint_msg.uuid = "UUID_ZERO".to_string();
+        let err = execute_mint(deps.as_mut(), mock_env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                               mint_msg.clone(), sign_mint_request(signing_key_one.clone(), mint_msg.clone())).unwrap_err();
+        assert_eq!(err, ContractError::InvalidInput("Mint quantity must be at least one".to_string(), "0".to_string()));
+
+        // Total price overflow
+        mint_msg.quantity = 2;
+        mint_msg.price = Uint256::MAX;
+        mint_msg.uuid = "UUID_OVERFLOW".to_string();
+        let err_msg = execute_mint(deps.as_mut(), mock_env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: price_wei, }
+        ]), mint_msg.clone(), sign_mint_request(signing_key_one.clone(), mint_msg.clone())).unwrap_err().to_string();
+        assert!(err_msg.contains("Error calculating total price"));
+
+        // Bundles listing their uris mint each token with its own uri
+        mint_msg.price = Uint256::from(price_wei);
+        mint_msg.uris = vec!["https://example.com/a".to_string(), "https://example.com/b".to_string()];
+        mint_msg.uuid = "UUID_URIS".to_string();
+        let mint_response = execute_mint(deps.as_mut(), mock_env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: price_wei * Uint128::new(2), }
+        ]), mint_msg.clone(), sign_mint_request(signing_key_one.clone(), mint_msg.clone())).unwrap();
+
+        for (index, token_uri) in mint_msg.uris.iter().enumerate() {
+            assert_eq!(mint_response.messages[index].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: COLLECTION_CONTRACT_ADDR.to_string(),
+                msg: to_json_binary(&dega_inj::cw721::ExecuteMsg::Mint {
+                    token_id: (index + 4).to_string(),
+                    owner: BUYER_ADDR.to_string(),
+                    token_uri: Some(token_uri.clone()),
+                    extension: None,
+                }).unwrap(),
+                funds: vec![],
+            }));
+        }
+
+        // The uris signed for must match the quantity
+        mint_msg.quantity = 3;
+        mint_msg.uuid = "UUID_URIS_SHORT".to_string();
+        let err = execute_mint(deps.as_mut(), mock_env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                               mint_msg.clone(), sign_mint_request(signing_key_one.clone(), mint_msg.clone())).unwrap_err();
+        assert_eq!(err, ContractError::InvalidInput(
+            "Mint request must have one uri per token | Quantity: 3".to_string(), "2".to_string()));
+
+        // The uris are covered by the signature
+        let mut altered_msg = mint_msg.clone();
+        altered_msg.quantity = 2;
+        let altered_sig = sign_mint_request(signing_key_one.clone(), altered_msg.clone());
+        altered_msg.uris = vec!["https://example.com/c".to_string(), "https://example.com/d".to_string()];
+        let err = execute_mint(deps.as_mut(), mock_env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: price_wei * Uint128::new(2), }
+        ]), altered_msg, altered_sig).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Signature is invalid".to_string()));
+    }
+
+    #[test]
+    fn mint_pausing() {
+
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+
+        // Create a first keypair and store the pubkey in the minter
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        let mock_env = mock_env();
+
+        let mint_msg = template_mint_msg(&mock_env, price_wei);
+
+        let mint_sig = sign_mint_request(signing_key_one.clone(), mint_msg.clone());
+
+        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[
+            Coin {
+                denom: INJ_DENOM.into(),
+                amount: price_wei,
+            }
+        ]);
+
+        // Initialize fresh DB and minter
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Confirm we are able to mint before pausing
+        execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(), mint_msg.clone(), mint_sig.clone()).unwrap();
+
+        // Pause minting
+        let new_settings_pause = UpdateDegaMinterConfigSettingsMsg {
+            signer_pub_key: None,
+            minting_paused: Some(true),
+            ..Default::default()
+        };
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        execute_update_settings(&mut deps.as_mut(), &cosmwasm_std::testing::mock_env(),
+                                &admin_msg_info, &new_settings_pause).unwrap();
+        assert!(query_config(deps.as_ref(), cosmwasm_std::testing::mock_env()).unwrap()
+                                                                              .dega_minter_settings.minting_paused);
+
+        // Generate a new mint message / signature with a different UUID
+        let mut mint_msg_two = template_mint_msg(&mock_env, price_wei);
+        mint_msg_two.uuid = "UUID2".to_string();
+        let mint_sig_two = sign_mint_request(signing_key_one, mint_msg_two.clone());
+
+        // Should get error when trying to mint while paused
+        let paused_err = execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(),
+                                      mint_msg_two.clone(), mint_sig_two.clone()).unwrap_err();
+
+        assert_eq!(paused_err, ContractError::MintingPaused);
+
+        // Reset DB and minter after error (clean up to simulate rollback)
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Pause minting again
+        execute_update_settings(&mut deps.as_mut(), &cosmwasm_std::testing::mock_env(),
+                                &admin_msg_info, &new_settings_pause).unwrap();
+        assert!(query_config(deps.as_ref(), cosmwasm_std::testing::mock_env()).unwrap()
+                                                                              .dega_minter_settings.minting_paused);
+
+        // Unpause minting
+        let new_settings_unpause = UpdateDegaMinterConfigSettingsMsg {
+            signer_pub_key: None,
+            minting_paused: Some(false),
+            ..Default::default()
+        };
+        execute_update_settings(&mut deps.as_mut(), &cosmwasm_std::testing::mock_env(),
+                                &admin_msg_info, &new_settings_unpause).unwrap();
+        assert!(!query_config(deps.as_ref(), cosmwasm_std::testing::mock_env()).unwrap()
+                                                                               .dega_minter_settings.minting_paused);
+
+        // Should be able to mint once more
+        execute_mint(deps.as_mut(), mock_env, normal_user_msg_info, mint_msg_two, mint_sig_two).unwrap();
+    }
+
+    #[test]
+    fn mint_timing() {
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+
+        // Create a first keypair and store the pubkey in the minter
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        let mut mock_env = mock_env();
+
+        let mut mint_msg = template_mint_msg(&mock_env, price_wei);
+
+        let now = mock_env.block.time.seconds();
+        let acceptable_start_time = now - 10;
+        let acceptable_end_time = now + 300;
+
+        mint_msg.validity_start_timestamp = Uint128::from(acceptable_start_time);
+        mint_msg.validity_end_timestamp = Uint128::from(acceptable_end_time);
+
+        let mint_sig = sign_mint_request(signing_key_one.clone(), mint_msg.clone());
+
+        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[
+            Coin {
                 denom: INJ_DENOM.into(),
                 amount: price_wei,
-            }],
-        }));
+            }
+        ]);
+
+        // Initialize fresh DB and minter
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Error due to minting too early
+        mock_env.block.time = Timestamp::from_seconds(acceptable_start_time - 30);
+        let early_err = execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(),
+                                     mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(early_err, ContractError::Generic(
+            format!("Request is not valid yet | Execution time: {} | Validity start: {}",
+                    mock_env.block.time.seconds(),
+                    mint_msg.validity_start_timestamp
+            ).to_string()
+        ));
+
+        // Reset DB and minter after error (clean up to simulate rollback)
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Error due to minting too late
+        mock_env.block.time = Timestamp::from_seconds(acceptable_end_time + 30);
+        let late_err = execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(),
+                                    mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(late_err, ContractError::Generic(
+            format!("Request is no longer valid | Execution time: {} | Validity end: {}",
+                    mock_env.block.time.seconds(),
+                    mint_msg.validity_end_timestamp
+            ).to_string()
+        ));
+
+        // Confirm we can mint when the time is right
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        mock_env.block.time = Timestamp::from_seconds(now);
+        execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(),
+                     mint_msg.clone(), mint_sig.clone()).unwrap();
+    }
+
+    #[test]
+    fn mint_payment() {
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+
+        // Create a first keypair and store the pubkey in the minter
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        let mock_env = mock_env();
+
+        let mint_msg = template_mint_msg(&mock_env, price_wei);
+
+        let mint_sig = sign_mint_request(signing_key_one.clone(), mint_msg.clone());
+
+        // Initialize fresh DB and minter
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Error due to no payment
+        let nopay_msg_info = mock_info(NORMAL_USER_ADDR, &[]);
+        let nopay_err = execute_mint(deps.as_mut(), mock_env.clone(), nopay_msg_info.clone(),
+                                     mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(nopay_err, ContractError::Generic("No payment provided".to_string()));
+
+        // Reset DB and minter after error (clean up to simulate rollback)
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Error due to multiple payment currencies
+        let usdc_denom = "usdc";
+        let multipay_msg_info = mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: price_wei, },
+            Coin { denom: usdc_denom.into(), amount: price_wei, },
+        ]);
+        let multipay_err = execute_mint(deps.as_mut(), mock_env.clone(), multipay_msg_info.clone(),
+                                        mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(multipay_err, ContractError::Generic("Must only provide one payment currency".to_string()));
+
+        // Reset DB and minter after error (clean up to simulate rollback)
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Error due to underpayment
+        let underpay_price = price_wei.checked_sub(Uint128::new(10000)).unwrap();
+        let underpay_msg_info = mock_info(NORMAL_USER_ADDR, &[
+            Coin {
+                denom: INJ_DENOM.into(),
+                amount: underpay_price,
+            }
+        ]);
+        let underpay_err = execute_mint(deps.as_mut(), mock_env.clone(), underpay_msg_info.clone(),
+                                        mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(underpay_err, ContractError::Generic(
+            format!("Insufficient payment | Price: {} | Paid: {}", price_wei, underpay_price)));
+
+        // Reset DB and minter after error (clean up to simulate rollback)
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Error due to overpayment
+        let overpay_price = price_wei.checked_add(Uint128::new(10000)).unwrap();
+        let overpay_msg_info = mock_info(NORMAL_USER_ADDR, &[
+            Coin {
+                denom: INJ_DENOM.into(),
+                amount: overpay_price,
+            }
+        ]);
+        let overpay_err = execute_mint(deps.as_mut(), mock_env.clone(), overpay_msg_info.clone(),
+                                       mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(overpay_err, ContractError::Generic(
+            format!("Overpayment | Price: {} | Paid: {}", price_wei, overpay_price)));
+
+        // Reset DB and minter after error (clean up to simulate rollback)
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Error due to wrong currency
+        let wrong_currency_msg_info = mock_info(NORMAL_USER_ADDR, &[
+            Coin {
+                denom: usdc_denom.into(),
+                amount: price_wei,
+            }
+        ]);
+        let wrong_currency_err = execute_mint(deps.as_mut(), mock_env.clone(), wrong_currency_msg_info.clone(),
+                                              mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(wrong_currency_err, ContractError::Generic(
+            format!("Payment currency does not match requested currency | Payment: {} | Requested: {}", usdc_denom, INJ_DENOM)));
+
+        // Reset DB and minter after error (clean up to simulate rollback)
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Correctly pay and process the transaction
+        let correct_msg_info = mock_info(NORMAL_USER_ADDR, &[
+            Coin {
+                denom: INJ_DENOM.into(),
+                amount: price_wei,
+            }
+        ]);
+        execute_mint(deps.as_mut(), mock_env.clone(), correct_msg_info.clone(),
+                     mint_msg.clone(), mint_sig.clone()).unwrap();
+    }
+
+    #[test]
+    fn mint_invalid_addresses() {
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+
+        // Create a first keypair and store the pubkey in the minter
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        let mock_env = mock_env();
+
+        let template_mint_msg = template_mint_msg(&mock_env, price_wei);
+
+        let msg_info = mock_info(NORMAL_USER_ADDR, &[
+            Coin {
+                denom: INJ_DENOM.into(),
+                amount: price_wei,
+            }
+        ]);
+
+        // Initialize fresh DB and minter
+        let mut deps = mock_dependencies();
+        let mut mint_request;
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Test for invalid buyer address
+        mint_request = template_mint_msg.clone();
+        mint_request.to = INVALID_ADDR.to_string();
+        let mut mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
+        let invalid_buyer_addr_err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
+                                                  mint_request.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(invalid_buyer_addr_err,
+                   ContractError::Std("Invalid purchaser address".to_string(),
+                                      StdError::generic_err( "Invalid input: address not normalized" )));
+
+        // Test for invalid primary sale recipient address
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        mint_request = template_mint_msg.clone();
+        mint_request.primary_sale_recipient = INVALID_ADDR.to_string();
+        mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
+        let invalid_sale_recipient_addr_err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
+                                                           mint_request.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(invalid_sale_recipient_addr_err,
+                   ContractError::Std("Invalid primary sale recipient address".to_string(),
+                                      StdError::generic_err( "Invalid input: address not normalized" )));
+
+        // Mint with invalid requested collection address
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        mint_request = template_mint_msg.clone();
+        mint_request.collection = INVALID_ADDR.to_string();
+        mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
+        let err_msg = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
+                                   mint_request.clone(), mint_sig.clone())
+                .unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid request collection address"));
+
+        // Mint with the wrong collection address (but still valid)
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        mint_request = template_mint_msg.clone();
+        mint_request.collection = "some_other_collection_addr".to_string();
+        mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
+        let err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
+                               mint_request.clone(), mint_sig.clone())
+            .unwrap_err();
+        assert_eq!(err, ContractError::Generic(format!(
+		            "Mint request authorized for collection ({}) sent to incorrect collection ({})",
+		            mint_request.collection.as_str(),
+		            COLLECTION_CONTRACT_ADDR,
+		        )));
+
+        // Mint with a request signed for another chain
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        mint_request = template_mint_msg.clone();
+        mint_request.chain_id = "injective-888".to_string();
+        mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
+        let err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
+                               mint_request.clone(), mint_sig.clone())
+            .unwrap_err();
+        assert_eq!(err, ContractError::DomainMismatch(
+            "chain ID".to_string(),
+            mock_env.block.chain_id.clone(),
+            "injective-888".to_string(),
+        ));
+
+        // Mint with a request signed for another minter
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        mint_request = template_mint_msg.clone();
+        mint_request.minter = "some_other_minter_addr".to_string();
+        mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
+        let err_msg = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
+                                   mint_request.clone(), mint_sig.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Mint request minter address does not match"));
+        assert!(err_msg.contains("some_other_minter_addr"));
     }
 
     #[test]
-    fn mint_pausing() {
-
+    fn mint_already_used_uuid() {
         let price_wei = get_inj_wei_from_kilo_inj(100);
 
         // Create a first keypair and store the pubkey in the minter
@@ -551,11 +3340,7 @@ mod tests {
 
         let mock_env = mock_env();
 
-        let mint_msg = template_mint_msg(&mock_env, price_wei);
-
-        let mint_sig = sign_mint_request(signing_key_one.clone(), mint_msg.clone());
-
-        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[
+        let msg_info = mock_info(NORMAL_USER_ADDR, &[
             Coin {
                 denom: INJ_DENOM.into(),
                 amount: price_wei,
@@ -566,441 +3351,1615 @@ mod tests {
         let mut deps = mock_dependencies();
         template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
 
-        // Confirm we are able to mint before pausing
-        execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(), mint_msg.clone(), mint_sig.clone()).unwrap();
+        // Mint to use the UUID
+        let mint_msg = template_mint_msg(&mock_env, price_wei);
+        let mint_sig = sign_mint_request(signing_key_one.clone(), mint_msg.clone());
+        execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
+                     mint_msg.clone(), mint_sig.clone()).unwrap();
 
-        // Pause minting
-        let new_settings_pause = UpdateDegaMinterConfigSettingsMsg {
-            signer_pub_key: None,
-            minting_paused: Some(true),
+        // Try again with the same UUID
+        let uuid_err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
+                                    mint_msg.clone(), mint_sig.clone()).unwrap_err();
+
+        assert_eq!(uuid_err, ContractError::Generic("UUID already registered".to_string()));
+    }
+
+
+    #[test]
+    fn mint_invalid_signature() {
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+
+        // Create a first keypair and store the pubkey in the minter
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        let mock_env = mock_env();
+
+        let msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price_wei, }]);
+
+        // Initialize fresh DB and minter
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+
+        let bad_signer = SigningKey::random(&mut OsRng);
+        let bad_signer_pub_key = get_signer_pub_key(&bad_signer);
+
+        assert_ne!(signer_pub_key, bad_signer_pub_key);
+
+        // Sign mint request with the bad signer
+        let mint_msg = template_mint_msg(&mock_env, price_wei);
+        let bad_signature = sign_mint_request(bad_signer.clone(), mint_msg.clone());
+
+        // Try again with the same UUID
+        let invalid_sig_err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
+                                           mint_msg.clone(), bad_signature.clone()).unwrap_err();
+
+        assert_eq!(invalid_sig_err, ContractError::Generic("Signature is invalid".to_string()));
+    }
+
+    #[test]
+    fn mint_errors() {
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+        let msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price_wei, }]);
+        let env = mock_env();
+        let mint_request = template_mint_msg(&env, price_wei);
+        let signature = sign_mint_request(signing_key, mint_request.clone());
+
+        // Unable to load minter settings
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        add_load_error_item(&DEGA_MINTER_SETTINGS);
+        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
+                                   mint_request.clone(), signature.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error during dega minter settings query"));
+        clear_load_error_items();
+
+        // Error due to invalid signature
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let invalid_signature = "^Invalid Signature^";
+        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
+                                   mint_request.clone(), invalid_signature.to_string())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error during decode signature from base64"));
+        assert!(err_msg.contains("Error during signature verification"));
+
+        // Error due to being unable to save to UUID registry
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        add_save_error_map(&UUID_REGISTRY);
+        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
+                                   mint_request.clone(), signature.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error while registering UUID"));
+        clear_save_error_items();
+
+        // Error due to not being able to load the address of the collection
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        add_load_error_item(&COLLECTION_ADDRESS);
+        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
+                                   mint_request.clone(), signature.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error while loading collection address"));
+        clear_load_error_items();
+
+        // Error incrementing token index
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        add_save_error_item(&TOKEN_INDEX);
+        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
+                                   mint_request.clone(), signature.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error while incrementing token index"));
+        clear_save_error_items();
+
+        // Error serializing the exec mint message for the collection contract
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let new_token_id = TOKEN_INDEX.may_load(&deps.storage).unwrap().unwrap_or_default() + 1;
+        let mint_exec_msg = dega_inj::cw721::ExecuteMsg::Mint {
+            token_id: new_token_id.to_string(),
+            owner: mint_request.to.clone(),
+            token_uri: Some(mint_request.uri.clone()),
+            extension: None,
         };
+        let binary_for_error = to_json_binary(&mint_exec_msg).unwrap();
+        set_binary_for_json_error(Some(binary_for_error));
+        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
+                                   mint_request.clone(), signature.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error during conversion of mint exec message to binary"));
+        set_binary_for_json_error(None);
+    }
+
+    #[test]
+    fn signer_rotation() {
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+
+        let primary_signing_key = SigningKey::random(&mut OsRng);
+        let primary_signer_pub_key = get_signer_pub_key(&primary_signing_key);
+
+        let rotated_signing_key = SigningKey::random(&mut OsRng);
+        let rotated_signer_pub_key = get_signer_pub_key(&rotated_signing_key);
+
+        let env = mock_env();
+        let now = env.block.time.seconds();
         let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
-        execute_update_settings(&mut deps.as_mut(), &cosmwasm_std::testing::mock_env(),
-                                &admin_msg_info, &new_settings_pause).unwrap();
-        assert!(query_config(deps.as_ref(), cosmwasm_std::testing::mock_env()).unwrap()
-                                                                              .dega_minter_settings.minting_paused);
+        let msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price_wei, }]);
 
-        // Generate a new mint message / signature with a different UUID
-        let mut mint_msg_two = template_mint_msg(&mock_env, price_wei);
-        mint_msg_two.uuid = "UUID2".to_string();
-        let mint_sig_two = sign_mint_request(signing_key_one, mint_msg_two.clone());
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), primary_signer_pub_key.clone(), false).unwrap();
+
+        // Sign a request with the old primary key before rotating
+        let old_mint_msg = template_mint_msg(&env, price_wei);
+        let old_mint_sig = sign_mint_request(primary_signing_key.clone(), old_mint_msg.clone());
+
+        // Keep the old key around as an additional signer until its outstanding requests expire
+        run_execute(deps.as_mut(), env.clone(), admin_msg_info.clone(), ExecuteMsg::AddSigner {
+            label: "previous".to_string(),
+            signer: SignerKeyInfo {
+                pub_key: primary_signer_pub_key.clone(),
+                activation_timestamp: None,
+                expiration_timestamp: Some(Uint128::from(now + 100)),
+            },
+        }).unwrap();
+
+        // Rotate the primary key
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            signer_pub_key: Some(rotated_signer_pub_key.clone()),
+            minting_paused: None,
+            ..Default::default()
+        }).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+
+        // Request signed by the old key is still accepted and reports the matching key
+        let response = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
+                                    old_mint_msg.clone(), old_mint_sig.clone()).unwrap();
+        assert!(response.attributes.iter().any(|attr| attr.key == "signer_label" && attr.value == "previous"));
+
+        // Request signed by the new key is accepted as the primary
+        let mut new_mint_msg = template_mint_msg(&env, price_wei);
+        new_mint_msg.uuid = "UUID2".to_string();
+        let new_mint_sig = sign_mint_request(rotated_signing_key.clone(), new_mint_msg.clone());
+        let response = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
+                                    new_mint_msg.clone(), new_mint_sig.clone()).unwrap();
+        assert!(response.attributes.iter().any(|attr| attr.key == "signer_label" && attr.value == "primary"));
+
+        // Once the previous key expires its signatures are rejected
+        let mut later_env = env.clone();
+        later_env.block.time = Timestamp::from_seconds(now + 101);
+        let mut expired_mint_msg = template_mint_msg(&later_env, price_wei);
+        expired_mint_msg.uuid = "UUID3".to_string();
+        let expired_mint_sig = sign_mint_request(primary_signing_key.clone(), expired_mint_msg.clone());
+        let err = execute_mint(deps.as_mut(), later_env.clone(), msg_info.clone(),
+                               expired_mint_msg.clone(), expired_mint_sig.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Signature is invalid".to_string()));
+
+        // A signer that is not active yet is rejected until its activation time
+        let future_signing_key = SigningKey::random(&mut OsRng);
+        run_execute(deps.as_mut(), env.clone(), admin_msg_info.clone(), ExecuteMsg::AddSigner {
+            label: "future".to_string(),
+            signer: SignerKeyInfo {
+                pub_key: get_signer_pub_key(&future_signing_key),
+                activation_timestamp: Some(Uint128::from(now + 20)),
+                expiration_timestamp: None,
+            },
+        }).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+        let mut future_mint_msg = template_mint_msg(&env, price_wei);
+        future_mint_msg.uuid = "UUID4".to_string();
+        let future_mint_sig = sign_mint_request(future_signing_key.clone(), future_mint_msg.clone());
+        let err = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
+                               future_mint_msg.clone(), future_mint_sig.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Signature is invalid".to_string()));
+        let mut active_env = env.clone();
+        active_env.block.time = Timestamp::from_seconds(now + 20);
+        execute_mint(deps.as_mut(), active_env.clone(), msg_info.clone(),
+                     future_mint_msg.clone(), future_mint_sig.clone()).unwrap();
+
+        // Removing a signer stops it from being accepted
+        run_execute(deps.as_mut(), env.clone(), admin_msg_info.clone(), ExecuteMsg::RemoveSigner {
+            label: "future".to_string(),
+        }).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+        let mut removed_mint_msg = template_mint_msg(&env, price_wei);
+        removed_mint_msg.uuid = "UUID5".to_string();
+        let removed_mint_sig = sign_mint_request(future_signing_key, removed_mint_msg.clone());
+        let err = execute_mint(deps.as_mut(), active_env, msg_info,
+                               removed_mint_msg, removed_mint_sig).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Signature is invalid".to_string()));
+    }
+
+    #[test]
+    fn signer_errors() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[]);
+
+        let signer = SignerKeyInfo {
+            pub_key: signer_pub_key.clone(),
+            activation_timestamp: None,
+            expiration_timestamp: None,
+        };
+
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Only admins can manage signers
+        let err = execute_add_signer(&mut deps.as_mut(), &mock_env(), &normal_user_msg_info,
+                                     "backend".to_string(), signer.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized("Only admins and signer managers can add signers".to_string()));
+        let err = execute_remove_signer(&mut deps.as_mut(), &mock_env(), &normal_user_msg_info,
+                                        "backend".to_string()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized("Only admins and signer managers can remove signers".to_string()));
+
+        // Reserved and empty labels
+        let err = execute_add_signer(&mut deps.as_mut(), &mock_env(), &admin_msg_info,
+                                     PRIMARY_SIGNER_LABEL.to_string(), signer.clone()).unwrap_err();
+        assert!(err.to_string().contains("Invalid signer label"));
+        let err = execute_add_signer(&mut deps.as_mut(), &mock_env(), &admin_msg_info,
+                                     "".to_string(), signer.clone()).unwrap_err();
+        assert!(err.to_string().contains("Invalid signer label"));
+
+        // Invalid public key
+        let mut invalid_signer = signer.clone();
+        invalid_signer.pub_key = "Invalid Pub Key".to_string();
+        let err = execute_add_signer(&mut deps.as_mut(), &mock_env(), &admin_msg_info,
+                                     "backend".to_string(), invalid_signer).unwrap_err();
+        assert!(err.to_string().contains("Invalid signer public key"));
+
+        // Expiration before activation
+        let mut backwards_signer = signer.clone();
+        backwards_signer.activation_timestamp = Some(Uint128::new(2000));
+        backwards_signer.expiration_timestamp = Some(Uint128::new(1000));
+        let err = execute_add_signer(&mut deps.as_mut(), &mock_env(), &admin_msg_info,
+                                     "backend".to_string(), backwards_signer).unwrap_err();
+        assert!(err.to_string().contains("Signer expiration must not be before activation"));
+
+        // Duplicate labels
+        execute_add_signer(&mut deps.as_mut(), &mock_env(), &admin_msg_info,
+                           "backend".to_string(), signer.clone()).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &admin_msg_info).unwrap();
+        let err = execute_add_signer(&mut deps.as_mut(), &mock_env(), &admin_msg_info,
+                                     "backend".to_string(), signer.clone()).unwrap_err();
+        assert!(err.to_string().contains("Signer label already in use"));
+
+        // Removing an unknown label
+        let err = execute_remove_signer(&mut deps.as_mut(), &mock_env(), &admin_msg_info,
+                                        "unknown".to_string()).unwrap_err();
+        assert!(err.to_string().contains("No signer found with label"));
+
+        // Unable to save the signer
+        execute_add_signer(&mut deps.as_mut(), &mock_env(), &admin_msg_info,
+                           "backend-2".to_string(), signer.clone()).unwrap();
+        add_save_error_map(&SIGNER_KEYS);
+        let err = run_pending_admin_actions(&mut deps.as_mut(), &mock_env(), &admin_msg_info).unwrap_err();
+        assert!(err.to_string().contains("Error while saving signer key"));
+        clear_save_error_items();
+    }
+
+    #[test]
+    fn mint_with_signer_address() {
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let address_signing_key = SigningKey::random(&mut OsRng);
+        let address_signer_pub_key = get_signer_pub_key(&address_signing_key);
+
+        let env = mock_env();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price_wei, }]);
+
+        let mut deps = mock_dependencies_with_accounts(vec![
+            (SIGNER_ACCOUNT_ADDR, Some((SECP256K1_PUB_KEY_TYPE, address_signer_pub_key))),
+        ]);
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        let mint_msg = template_mint_msg(&env, price_wei);
+        let mint_sig = sign_mint_request(address_signing_key, mint_msg.clone());
+
+        // Not accepted before the signer address is configured
+        let err = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(), mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Signature is invalid".to_string()));
+
+        // Invalid signer address rejected
+        let err_msg = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            signer_address: Some(Some(INVALID_ADDR.to_string())),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid signer address"));
+
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            signer_address: Some(Some(SIGNER_ACCOUNT_ADDR.to_string())),
+            ..Default::default()
+        }).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+        assert_eq!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.signer_address,
+                   Some(SIGNER_ACCOUNT_ADDR.to_string()));
+
+        let response = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(), mint_msg.clone(), mint_sig.clone()).unwrap();
+        assert!(response.attributes.iter().any(|attr| attr.key == "signer_label" && attr.value == "signer_address"));
+
+        // Unset the signer address
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            signer_address: Some(None),
+            ..Default::default()
+        }).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+        assert!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.signer_address.is_none());
+    }
+
+    #[test]
+    fn mint_with_eth_signature() {
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let wallet_signing_key = SigningKey::random(&mut OsRng);
+        let wallet_address = get_signer_inj_address(&wallet_signing_key);
+
+        let env = mock_env();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price_wei, }]);
+
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        let mut mint_msg = template_mint_msg(&env, price_wei);
+        let typed_sig = sign_mint_request_with_scheme(&wallet_signing_key, mint_msg.clone(), &SignatureScheme::Eip712, &MintRequestEncoding::Json);
+
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            signer_address: Some(Some(wallet_address.clone())),
+            ..Default::default()
+        }).unwrap();
+        run_pending_admin_actions(&mut deps.as_mut(), &env, &admin_msg_info).unwrap();
+
+        // Typed data signature not accepted while the minter expects SHA-256
+        let err = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(), mint_msg.clone(), typed_sig.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Signature is invalid".to_string()));
+
+        let response = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            signature_scheme: Some(SignatureScheme::Eip712),
+            ..Default::default()
+        }).unwrap();
+        assert!(response.attributes.iter().any(|attr| attr.key == "signature_scheme" && attr.value == "Eip712"));
+        assert_eq!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.signature_scheme,
+                   SignatureScheme::Eip712);
+
+        let response = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(), mint_msg.clone(), typed_sig.clone()).unwrap();
+        assert!(response.attributes.iter().any(|attr| attr.key == "signer_label" && attr.value == "signer_address"));
+
+        // Personal sign signature by the primary signer key
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            signature_scheme: Some(SignatureScheme::Eip191),
+            ..Default::default()
+        }).unwrap();
+
+        mint_msg.uuid = "UUID_2".to_string();
+        let personal_sig = sign_mint_request_with_scheme(&signing_key, mint_msg.clone(), &SignatureScheme::Eip191, &MintRequestEncoding::Json);
+        let response = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(), mint_msg.clone(), personal_sig).unwrap();
+        assert!(response.attributes.iter().any(|attr| attr.key == "signer_label" && attr.value == "primary"));
+    }
+
+    #[test]
+    fn free_mint() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        let mut mint_msg = template_mint_msg(&env, Uint128::zero());
+        mint_msg.quantity = 2;
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+
+        // Zero price mints only mint, with no proceeds transfer
+        let response = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                                    mint_msg.clone(), mint_sig.clone()).unwrap();
+        assert_eq!(response.messages.len(), 2);
+        assert!(response.messages.iter().all(|msg| matches!(msg.msg, CosmosMsg::Wasm(_))));
+
+        // Funds sent to a free mint are rejected
+        mint_msg.uuid = "UUID_2".to_string();
+        let err = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: Uint128::new(10) }
+        ]), mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Overpayment | Price: 0 | Paid: 10".to_string()));
+    }
+
+    #[test]
+    fn airdrop() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
 
-        // Should get error when trying to mint while paused
-        let paused_err = execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(),
-                                      mint_msg_two.clone(), mint_sig_two.clone()).unwrap_err();
+        let recipients = vec![
+            (BUYER_ADDR.to_string(), "https://example.com/1".to_string()),
+            (NORMAL_USER_ADDR.to_string(), "https://example.com/2".to_string()),
+        ];
 
-        assert_eq!(paused_err, ContractError::MintingPaused);
+        let response = run_execute(deps.as_mut(), env.clone(), admin_msg_info.clone(), ExecuteMsg::Airdrop {
+            recipients: recipients.clone(),
+        }).unwrap();
 
-        // Reset DB and minter after error (clean up to simulate rollback)
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        assert_eq!(response.messages.len(), 2);
+        for (index, (recipient, uri)) in recipients.iter().enumerate() {
+            assert_eq!(response.messages[index].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: COLLECTION_CONTRACT_ADDR.to_string(),
+                msg: to_json_binary(&dega_inj::cw721::ExecuteMsg::Mint {
+                    token_id: (index + 1).to_string(),
+                    owner: recipient.clone(),
+                    token_uri: Some(uri.clone()),
+                    extension: None,
+                }).unwrap(),
+                funds: vec![],
+            }));
+        }
+        assert_eq!(TOKEN_INDEX.load(&deps.storage).unwrap(), 2);
 
-        // Pause minting again
-        execute_update_settings(&mut deps.as_mut(), &cosmwasm_std::testing::mock_env(),
-                                &admin_msg_info, &new_settings_pause).unwrap();
-        assert!(query_config(deps.as_ref(), cosmwasm_std::testing::mock_env()).unwrap()
-                                                                              .dega_minter_settings.minting_paused);
+        let mut err_msg;
 
-        // Unpause minting
-        let new_settings_unpause = UpdateDegaMinterConfigSettingsMsg {
-            signer_pub_key: None,
-            minting_paused: Some(false),
-        };
-        execute_update_settings(&mut deps.as_mut(), &cosmwasm_std::testing::mock_env(),
-                                &admin_msg_info, &new_settings_unpause).unwrap();
-        assert!(!query_config(deps.as_ref(), cosmwasm_std::testing::mock_env()).unwrap()
-                                                                               .dega_minter_settings.minting_paused);
+        // Non admin
+        err_msg = execute_airdrop(&mut deps.as_mut(), &env, &mock_info(NORMAL_USER_ADDR, &[]), recipients.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins and airdroppers can airdrop"));
 
-        // Should be able to mint once more
-        execute_mint(deps.as_mut(), mock_env, normal_user_msg_info, mint_msg_two, mint_sig_two).unwrap();
+        // No recipients
+        err_msg = execute_airdrop(&mut deps.as_mut(), &env, &admin_msg_info, vec![])
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("No airdrop recipients provided"));
+
+        // Invalid recipient
+        err_msg = execute_airdrop(&mut deps.as_mut(), &env, &admin_msg_info,
+                                  vec![(INVALID_ADDR.to_string(), MINT_URI.to_string())])
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid airdrop recipient address"));
+
+        // Error loading the collection address
+        add_load_error_item(&COLLECTION_ADDRESS);
+        err_msg = execute_airdrop(&mut deps.as_mut(), &env, &admin_msg_info, recipients.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error while loading collection address"));
+        clear_load_error_items();
     }
 
     #[test]
-    fn mint_timing() {
-        let price_wei = get_inj_wei_from_kilo_inj(100);
+    fn max_supply() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
 
-        // Create a first keypair and store the pubkey in the minter
-        let signing_key_one = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+        let env = mock_env();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let mut deps = mock_dependencies();
+        let mut instantiate_msg = template_instantiate_msg(signer_pub_key.clone());
+        instantiate_msg.minter_params.dega_minter_settings.max_supply = Some(4);
+        template_minter_via_msg(&mut deps.as_mut(), &instantiate_msg, &template_reply_msg(), false).unwrap();
+
+        assert_eq!(query_typed::<SupplyResponse>(deps.as_ref(), QueryMsg::Supply {}).unwrap(), SupplyResponse {
+            max_supply: Some(4),
+            minted: 0,
+            remaining: Some(4),
+        });
+
+        let mut mint_msg = template_mint_msg(&env, Uint128::zero());
+        mint_msg.quantity = 2;
+        execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                     mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap();
+
+        // Airdrop beyond the remaining supply
+        let recipients = vec![
+            (BUYER_ADDR.to_string(), MINT_URI.to_string()),
+            (BUYER_ADDR.to_string(), MINT_URI.to_string()),
+            (BUYER_ADDR.to_string(), MINT_URI.to_string()),
+        ];
+        let err = execute_airdrop(&mut deps.as_mut(), &env, &admin_msg_info, recipients.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Max supply exceeded | Max supply: 4 | Minted: 2 | Requested: 3".to_string()));
+
+        execute_airdrop(&mut deps.as_mut(), &env, &admin_msg_info, recipients[..1].to_vec()).unwrap();
+        assert_eq!(query_typed::<SupplyResponse>(deps.as_ref(), QueryMsg::Supply {}).unwrap().remaining, Some(1));
+
+        // Mint beyond the remaining supply
+        mint_msg.uuid = "UUID_2".to_string();
+        let err = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                               mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Max supply exceeded | Max supply: 4 | Minted: 3 | Requested: 2".to_string()));
+
+        // Max supply can not be raised
+        let mut err_msg = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            max_supply: Some(5),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Max supply can only be lowered"));
+
+        // Nor lowered below the tokens already minted
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            max_supply: Some(2),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Max supply cannot be lower than the number of tokens minted"));
+
+        // Lowering to the minted count closes the supply
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            max_supply: Some(3),
+            ..Default::default()
+        }).unwrap();
+        assert_eq!(query_typed::<SupplyResponse>(deps.as_ref(), QueryMsg::Supply {}).unwrap(), SupplyResponse {
+            max_supply: Some(3),
+            minted: 3,
+            remaining: Some(0),
+        });
 
-        let mut mock_env = mock_env();
+        // A minter without a max supply can have one set later
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        assert_eq!(query_typed::<SupplyResponse>(deps.as_ref(), QueryMsg::Supply {}).unwrap(), SupplyResponse {
+            max_supply: None,
+            minted: 0,
+            remaining: None,
+        });
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            max_supply: Some(10),
+            ..Default::default()
+        }).unwrap();
+        assert_eq!(query_typed::<SupplyResponse>(deps.as_ref(), QueryMsg::Supply {}).unwrap().remaining, Some(10));
+    }
 
-        let mut mint_msg = template_mint_msg(&mock_env, price_wei);
+    #[test]
+    fn wallet_mint_limit() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
 
-        let now = mock_env.block.time.seconds();
-        let acceptable_start_time = now - 10;
-        let acceptable_end_time = now + 300;
+        let env = mock_env();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
 
-        mint_msg.validity_start_timestamp = Uint128::from(acceptable_start_time);
-        mint_msg.validity_end_timestamp = Uint128::from(acceptable_end_time);
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            max_mints_per_wallet: Some(Some(3)),
+            ..Default::default()
+        }).unwrap();
 
-        let mint_sig = sign_mint_request(signing_key_one.clone(), mint_msg.clone());
+        let mut mint_msg = template_mint_msg(&env, Uint128::zero());
+        mint_msg.quantity = 2;
+        execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                     mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap();
+
+        assert_eq!(query_typed::<MintCountResponse>(deps.as_ref(), QueryMsg::MintCount {
+            address: mint_msg.to.clone(),
+        }).unwrap(), MintCountResponse {
+            address: mint_msg.to.clone(),
+            count: 2,
+            limit: Some(3),
+        });
+
+        // Going over the limit for the same recipient
+        mint_msg.uuid = "UUID_2".to_string();
+        let err = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                               mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Wallet mint limit exceeded | Limit: 3 | Minted: 2 | Requested: 2".to_string()));
+
+        // Other recipients have their own count
+        mint_msg.to = NORMAL_USER_ADDR.to_string();
+        execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                     mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap();
+
+        // Airdrops are not counted against the limit
+        execute_airdrop(&mut deps.as_mut(), &env, &admin_msg_info, vec![(NORMAL_USER_ADDR.to_string(), MINT_URI.to_string())]).unwrap();
+        assert_eq!(query_typed::<MintCountResponse>(deps.as_ref(), QueryMsg::MintCount {
+            address: NORMAL_USER_ADDR.to_string(),
+        }).unwrap().count, 2);
+
+        // Mints are still counted after the limit is removed
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            max_mints_per_wallet: Some(None),
+            ..Default::default()
+        }).unwrap();
+        mint_msg.uuid = "UUID_3".to_string();
+        execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                     mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap();
+        assert_eq!(query_typed::<MintCountResponse>(deps.as_ref(), QueryMsg::MintCount {
+            address: NORMAL_USER_ADDR.to_string(),
+        }).unwrap(), MintCountResponse {
+            address: NORMAL_USER_ADDR.to_string(),
+            count: 4,
+            limit: None,
+        });
+
+        // Invalid query address
+        let err_msg = query_typed::<MintCountResponse>(deps.as_ref(), QueryMsg::MintCount {
+            address: INVALID_ADDR.to_string(),
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid address"));
+
+        // Error saving the mint count
+        mint_msg.uuid = "UUID_4".to_string();
+        add_save_error_map(&MINT_COUNTS);
+        let err_msg = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                                   mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone()))
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error while saving wallet mint count"));
+        clear_save_error_items();
+    }
 
-        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[
-            Coin {
-                denom: INJ_DENOM.into(),
-                amount: price_wei,
-            }
-        ]);
+    #[test]
+    fn public_mint() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
 
-        // Initialize fresh DB and minter
+        let env = mock_env();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
         let mut deps = mock_dependencies();
         template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
 
-        // Error due to minting too early
-        mock_env.block.time = Timestamp::from_seconds(acceptable_start_time - 30);
-        let early_err = execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(),
-                                     mint_msg.clone(), mint_sig.clone()).unwrap_err();
-        assert_eq!(early_err, ContractError::Generic(
-            format!("Request is not valid yet | Execution time: {} | Validity start: {}",
-                    mock_env.block.time.seconds(),
-                    mint_msg.validity_start_timestamp
-            ).to_string()
-        ));
+        let price = Uint128::new(100);
+        let paid_msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price }]);
 
-        // Reset DB and minter after error (clean up to simulate rollback)
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let mut err_msg = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Public sale is not enabled"));
+
+        let public_sale = PublicSaleSettings {
+            price,
+            denom: INJ_DENOM.to_string(),
+            start_timestamp: Uint128::from(env.block.time.seconds() - 10),
+            end_timestamp: Some(Uint128::from(env.block.time.seconds() + 50)),
+            base_uri: "https://example.com/".to_string(),
+            sale_recipient: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+        };
 
-        // Error due to minting too late
-        mock_env.block.time = Timestamp::from_seconds(acceptable_end_time + 30);
-        let late_err = execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(),
-                                    mint_msg.clone(), mint_sig.clone()).unwrap_err();
-        assert_eq!(late_err, ContractError::Generic(
-            format!("Request is no longer valid | Execution time: {} | Validity end: {}",
-                    mock_env.block.time.seconds(),
-                    mint_msg.validity_end_timestamp
-            ).to_string()
-        ));
+        let mut invalid_public_sale = public_sale.clone();
+        invalid_public_sale.sale_recipient = INVALID_ADDR.to_string();
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            public_sale: Some(Some(invalid_public_sale)),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid public sale settings"));
+
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            public_sale: Some(Some(public_sale.clone())),
+            ..Default::default()
+        }).unwrap();
 
-        // Confirm we can mint when the time is right
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        mock_env.block.time = Timestamp::from_seconds(now);
-        execute_mint(deps.as_mut(), mock_env.clone(), normal_user_msg_info.clone(),
-                     mint_msg.clone(), mint_sig.clone()).unwrap();
-    }
+        // Tokens are minted in sequence to the sender, with the uri derived from the token id
+        for token_id in 1..=2u64 {
+            let response = run_execute(deps.as_mut(), env.clone(), paid_msg_info.clone(), ExecuteMsg::PublicMint {}).unwrap();
+            assert_eq!(response.messages.len(), 2);
+            assert_eq!(response.messages[0].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: COLLECTION_CONTRACT_ADDR.to_string(),
+                msg: to_json_binary(&dega_inj::cw721::ExecuteMsg::Mint {
+                    token_id: token_id.to_string(),
+                    owner: NORMAL_USER_ADDR.to_string(),
+                    token_uri: Some(format!("https://example.com/{}", token_id)),
+                    extension: None,
+                }).unwrap(),
+                funds: vec![],
+            }));
+            assert_eq!(response.messages[1].msg, CosmosMsg::Bank(BankMsg::Send {
+                to_address: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+                amount: vec![Coin { denom: INJ_DENOM.into(), amount: price }],
+            }));
+        }
 
-    #[test]
-    fn mint_payment() {
-        let price_wei = get_inj_wei_from_kilo_inj(100);
+        // The signed mint path keeps working alongside the public sale
+        let mint_msg = template_mint_msg(&env, Uint128::zero());
+        let response = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]),
+                                    mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("token_id", "3")));
 
-        // Create a first keypair and store the pubkey in the minter
-        let signing_key_one = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+        // Incorrect payments
+        err_msg = execute_public_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]))
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("No payment provided"));
+
+        err_msg = execute_public_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: "other".into(), amount: price }
+        ])).unwrap_err().to_string();
+        assert!(err_msg.contains("Payment currency does not match requested currency"));
+
+        err_msg = execute_public_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: Uint128::new(99) }
+        ])).unwrap_err().to_string();
+        assert!(err_msg.contains("Insufficient payment"));
+
+        // Outside the sale window
+        let mut early_env = env.clone();
+        early_env.block.time = env.block.time.minus_seconds(20);
+        err_msg = execute_public_mint(deps.as_mut(), early_env, paid_msg_info.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Public sale has not started yet"));
 
-        let mock_env = mock_env();
+        let mut late_env = env.clone();
+        late_env.block.time = env.block.time.plus_seconds(60);
+        err_msg = execute_public_mint(deps.as_mut(), late_env, paid_msg_info.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Public sale has ended"));
 
-        let mint_msg = template_mint_msg(&mock_env, price_wei);
+        // Paused
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            minting_paused: Some(true),
+            ..Default::default()
+        }).unwrap();
+        let err = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info.clone()).unwrap_err();
+        assert_eq!(err, ContractError::MintingPaused);
 
-        let mint_sig = sign_mint_request(signing_key_one.clone(), mint_msg.clone());
+        // Disabling the public sale
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            minting_paused: Some(false),
+            public_sale: Some(None),
+            ..Default::default()
+        }).unwrap();
+        err_msg = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Public sale is not enabled"));
+    }
 
-        // Initialize fresh DB and minter
+    #[test]
+    fn sale_phases() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
+        let now = env.block.time.seconds() as u128;
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
         let mut deps = mock_dependencies();
         template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
 
-        // Error due to no payment
-        let nopay_msg_info = mock_info(NORMAL_USER_ADDR, &[]);
-        let nopay_err = execute_mint(deps.as_mut(), mock_env.clone(), nopay_msg_info.clone(),
-                                     mint_msg.clone(), mint_sig.clone()).unwrap_err();
-        assert_eq!(nopay_err, ContractError::Generic("No payment provided".to_string()));
+        let allowlist_phase = SalePhase {
+            name: "allowlist".to_string(),
+            start_timestamp: Uint128::new(now - 100),
+            end_timestamp: Uint128::new(now + 100),
+            price: Uint128::new(100),
+            denom: INJ_DENOM.to_string(),
+            max_mints_per_wallet: Some(1),
+            eligibility: PhaseEligibility::SignedRequest,
+        };
+        let public_phase = SalePhase {
+            name: "public".to_string(),
+            start_timestamp: Uint128::new(now + 101),
+            end_timestamp: Uint128::new(now + 300),
+            price: Uint128::new(200),
+            denom: INJ_DENOM.to_string(),
+            max_mints_per_wallet: None,
+            eligibility: PhaseEligibility::Public,
+        };
+        let phases = vec![allowlist_phase.clone(), public_phase.clone()];
 
-        // Reset DB and minter after error (clean up to simulate rollback)
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let mut err_msg = execute_update_phases(&mut deps.as_mut(), &env, &mock_info(NORMAL_USER_ADDR, &[]), phases.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins can update sale phases"));
 
-        // Error due to multiple payment currencies
-        let usdc_denom = "usdc";
-        let multipay_msg_info = mock_info(NORMAL_USER_ADDR, &[
-            Coin { denom: INJ_DENOM.into(), amount: price_wei, },
-            Coin { denom: usdc_denom.into(), amount: price_wei, },
-        ]);
-        let multipay_err = execute_mint(deps.as_mut(), mock_env.clone(), multipay_msg_info.clone(),
-                                        mint_msg.clone(), mint_sig.clone()).unwrap_err();
-        assert_eq!(multipay_err, ContractError::Generic("Must only provide one payment currency".to_string()));
+        err_msg = execute_update_phases(&mut deps.as_mut(), &env, &admin_msg_info, vec![public_phase.clone(), allowlist_phase.clone()])
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid sale phases"));
 
-        // Reset DB and minter after error (clean up to simulate rollback)
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        run_execute(deps.as_mut(), env.clone(), admin_msg_info.clone(), ExecuteMsg::UpdatePhases {
+            phases: phases.clone(),
+        }).unwrap();
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            public_sale: Some(Some(PublicSaleSettings {
+                price: Uint128::new(1),
+                denom: INJ_DENOM.to_string(),
+                start_timestamp: Uint128::new(now - 100),
+                end_timestamp: None,
+                base_uri: "https://example.com/".to_string(),
+                sale_recipient: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+            })),
+            ..Default::default()
+        }).unwrap();
 
-        // Error due to underpayment
-        let underpay_price = price_wei.checked_sub(Uint128::new(10000)).unwrap();
-        let underpay_msg_info = mock_info(NORMAL_USER_ADDR, &[
-            Coin {
-                denom: INJ_DENOM.into(),
-                amount: underpay_price,
-            }
-        ]);
-        let underpay_err = execute_mint(deps.as_mut(), mock_env.clone(), underpay_msg_info.clone(),
-                                        mint_msg.clone(), mint_sig.clone()).unwrap_err();
-        assert_eq!(underpay_err, ContractError::Generic(
-            format!("Insufficient payment | Price: {} | Paid: {}", price_wei, underpay_price)));
+        assert_eq!(query_typed::<PhasesResponse>(deps.as_ref(), QueryMsg::Phases {}).unwrap().phases, phases);
+        assert_eq!(query_typed::<CurrentPhaseResponse>(deps.as_ref(), QueryMsg::CurrentPhase {}).unwrap().phase,
+                   Some(allowlist_phase.clone()));
 
-        // Reset DB and minter after error (clean up to simulate rollback)
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        // Signed mints during the allowlist phase, at the phase price and within the phase limit
+        let mut mint_msg = template_mint_msg(&env, Uint128::new(100));
+        let paid_msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: Uint128::new(100) }]);
+        let response = execute_mint(deps.as_mut(), env.clone(), paid_msg_info.clone(),
+                                    mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("sale_phase", "allowlist")));
 
-        // Error due to overpayment
-        let overpay_price = price_wei.checked_add(Uint128::new(10000)).unwrap();
-        let overpay_msg_info = mock_info(NORMAL_USER_ADDR, &[
-            Coin {
-                denom: INJ_DENOM.into(),
-                amount: overpay_price,
-            }
-        ]);
-        let overpay_err = execute_mint(deps.as_mut(), mock_env.clone(), overpay_msg_info.clone(),
-                                       mint_msg.clone(), mint_sig.clone()).unwrap_err();
-        assert_eq!(overpay_err, ContractError::Generic(
-            format!("Overpayment | Price: {} | Paid: {}", price_wei, overpay_price)));
+        mint_msg.uuid = "UUID_2".to_string();
+        err_msg = execute_mint(deps.as_mut(), env.clone(), paid_msg_info.clone(),
+                               mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone()))
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Phase mint limit exceeded | Phase: allowlist | Limit: 1 | Minted: 1 | Requested: 1"));
 
-        // Reset DB and minter after error (clean up to simulate rollback)
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        let mut cheap_mint_msg = template_mint_msg(&env, Uint128::new(50));
+        cheap_mint_msg.to = NORMAL_USER_ADDR.to_string();
+        cheap_mint_msg.uuid = "UUID_3".to_string();
+        err_msg = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: Uint128::new(50) }]),
+                               cheap_mint_msg.clone(), sign_mint_request(signing_key.clone(), cheap_mint_msg.clone()))
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Mint request price does not match the sale phase (allowlist)"));
 
-        // Error due to wrong currency
-        let wrong_currency_msg_info = mock_info(NORMAL_USER_ADDR, &[
-            Coin {
-                denom: usdc_denom.into(),
-                amount: price_wei,
-            }
-        ]);
-        let wrong_currency_err = execute_mint(deps.as_mut(), mock_env.clone(), wrong_currency_msg_info.clone(),
-                                              mint_msg.clone(), mint_sig.clone()).unwrap_err();
-        assert_eq!(wrong_currency_err, ContractError::Generic(
-            format!("Payment currency does not match requested currency | Payment: {} | Requested: {}", usdc_denom, INJ_DENOM)));
+        err_msg = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Sale phase (allowlist) is not open to public mints"));
+
+        // Public mints during the public phase, at the phase price rather than the public sale price
+        let mut public_env = env.clone();
+        public_env.block.time = env.block.time.plus_seconds(150);
+        assert_eq!(query_current_phase(deps.as_ref(), public_env.clone()).unwrap().phase, Some(public_phase.clone()));
+
+        let response = execute_public_mint(deps.as_mut(), public_env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: Uint128::new(200) }
+        ])).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("sale_phase", "public")));
+        assert!(response.attributes.contains(&Attribute::new("price", "200")));
+
+        let mut public_mint_msg = template_mint_msg(&public_env, Uint128::new(200));
+        public_mint_msg.uuid = "UUID_4".to_string();
+        err_msg = execute_mint(deps.as_mut(), public_env.clone(), mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: Uint128::new(200) }]),
+                               public_mint_msg.clone(), sign_mint_request(signing_key.clone(), public_mint_msg.clone()))
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Sale phase (public) does not accept mint requests"));
 
-        // Reset DB and minter after error (clean up to simulate rollback)
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        // Outside of every phase
+        let mut late_env = env.clone();
+        late_env.block.time = env.block.time.plus_seconds(400);
+        assert_eq!(query_current_phase(deps.as_ref(), late_env.clone()).unwrap().phase, None);
+        err_msg = execute_public_mint(deps.as_mut(), late_env.clone(), paid_msg_info.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("No sale phase is active"));
 
-        // Correctly pay and process the transaction
-        let correct_msg_info = mock_info(NORMAL_USER_ADDR, &[
-            Coin {
-                denom: INJ_DENOM.into(),
-                amount: price_wei,
-            }
-        ]);
-        execute_mint(deps.as_mut(), mock_env.clone(), correct_msg_info.clone(),
-                     mint_msg.clone(), mint_sig.clone()).unwrap();
+        // Without phases the minter goes back to the unphased behaviour
+        execute_update_phases(&mut deps.as_mut(), &env, &admin_msg_info, vec![]).unwrap();
+        mint_msg.uuid = "UUID_5".to_string();
+        execute_mint(deps.as_mut(), env.clone(), paid_msg_info.clone(),
+                     mint_msg.clone(), sign_mint_request(signing_key.clone(), mint_msg.clone())).unwrap();
     }
 
     #[test]
-    fn mint_invalid_addresses() {
-        let price_wei = get_inj_wei_from_kilo_inj(100);
+    fn allowlist_mint() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
 
-        // Create a first keypair and store the pubkey in the minter
-        let signing_key_one = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+        let env = mock_env();
+        let now = env.block.time.seconds() as u128;
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
 
-        let mock_env = mock_env();
+        let price = Uint128::new(100);
+        let buyer_msg_info = mock_info(BUYER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price }]);
+        let user_msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price }]);
+
+        let (merkle_root, proofs) = build_allowlist(&[(BUYER_ADDR, 2), (NORMAL_USER_ADDR, 1), (NEW_ADMIN_ADDR, 1)]);
+
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            public_sale: Some(Some(PublicSaleSettings {
+                price,
+                denom: INJ_DENOM.to_string(),
+                start_timestamp: Uint128::new(now - 100),
+                end_timestamp: Some(Uint128::new(now + 100)),
+                base_uri: "https://example.com/".to_string(),
+                sale_recipient: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+            })),
+            ..Default::default()
+        }).unwrap();
 
-        let template_mint_msg = template_mint_msg(&mock_env, price_wei);
+        let mut err_msg = execute_allowlist_mint(deps.as_mut(), env.clone(), buyer_msg_info.clone(), proofs[0].clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("No allowlist merkle root configured"));
 
-        let msg_info = mock_info(NORMAL_USER_ADDR, &[
-            Coin {
-                denom: INJ_DENOM.into(),
-                amount: price_wei,
-            }
-        ]);
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            allowlist_merkle_root: Some(Some("not hex".to_string())),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid allowlist merkle root"));
+
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            allowlist_merkle_root: Some(Some(merkle_root.clone())),
+            ..Default::default()
+        }).unwrap();
+
+        // The minter's root gates public mints outside of phases as well
+        err_msg = execute_public_mint(deps.as_mut(), env.clone(), buyer_msg_info.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("An allowlist proof is required while an allowlist merkle root is set"));
+
+        // Outside of phases allowlist mints are held to the public sale window
+        let mut early_env = env.clone();
+        early_env.block.time = Timestamp::from_seconds(now as u64 - 200);
+        err_msg = execute_allowlist_mint(deps.as_mut(), early_env, buyer_msg_info.clone(), proofs[0].clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Public sale has not started yet"));
+
+        let mut late_env = env.clone();
+        late_env.block.time = Timestamp::from_seconds(now as u64 + 200);
+        err_msg = execute_allowlist_mint(deps.as_mut(), late_env, buyer_msg_info.clone(), proofs[0].clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Public sale has ended"));
+
+        // Without phases the allowance covers all of the wallet's mints
+        for _ in 0..2 {
+            let response = run_execute(deps.as_mut(), env.clone(), buyer_msg_info.clone(), ExecuteMsg::AllowlistMint {
+                proof: proofs[0].clone(),
+            }).unwrap();
+            assert!(response.attributes.contains(&Attribute::new("action", "allowlist_mint")));
+        }
+
+        err_msg = execute_allowlist_mint(deps.as_mut(), env.clone(), buyer_msg_info.clone(), proofs[0].clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Allowlist allowance exceeded | Allowance: 2 | Minted: 2 | Requested: 1"));
 
-        // Initialize fresh DB and minter
-        let mut deps = mock_dependencies();
-        let mut mint_request;
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        // Proofs are bound to the sender and the allowance
+        err_msg = execute_allowlist_mint(deps.as_mut(), env.clone(), user_msg_info.clone(), proofs[0].clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Allowlist proof is invalid"));
 
-        // Test for invalid buyer address
-        mint_request = template_mint_msg.clone();
-        mint_request.to = INVALID_ADDR.to_string();
-        let mut mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
-        let invalid_buyer_addr_err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
-                                                  mint_request.clone(), mint_sig.clone()).unwrap_err();
-        assert_eq!(invalid_buyer_addr_err,
-                   ContractError::Std("Invalid purchaser address".to_string(),
-                                      StdError::generic_err( "Invalid input: address not normalized" )));
+        let mut inflated_proof = proofs[1].clone();
+        inflated_proof.allowance = 5;
+        err_msg = execute_allowlist_mint(deps.as_mut(), env.clone(), user_msg_info.clone(), inflated_proof)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Allowlist proof is invalid"));
 
-        // Test for invalid primary sale recipient address
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        mint_request = template_mint_msg.clone();
-        mint_request.primary_sale_recipient = INVALID_ADDR.to_string();
-        mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
-        let invalid_sale_recipient_addr_err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
-                                                           mint_request.clone(), mint_sig.clone()).unwrap_err();
-        assert_eq!(invalid_sale_recipient_addr_err,
-                   ContractError::Std("Invalid primary sale recipient address".to_string(),
-                                      StdError::generic_err( "Invalid input: address not normalized" )));
+        let mut malformed_proof = proofs[1].clone();
+        malformed_proof.proof = vec!["not hex".to_string()];
+        err_msg = execute_allowlist_mint(deps.as_mut(), env.clone(), user_msg_info.clone(), malformed_proof)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error during allowlist proof verification"));
+
+        // An allowlist phase with its own root, which only lists the normal user
+        let (phase_root, phase_proofs) = build_allowlist(&[(NORMAL_USER_ADDR, 1)]);
+        execute_update_phases(&mut deps.as_mut(), &env, &admin_msg_info, vec![SalePhase {
+            name: "allowlist".to_string(),
+            start_timestamp: Uint128::new(now - 100),
+            end_timestamp: Uint128::new(now + 100),
+            price: Uint128::new(50),
+            denom: INJ_DENOM.to_string(),
+            max_mints_per_wallet: None,
+            eligibility: PhaseEligibility::Allowlist { merkle_root: Some(phase_root) },
+        }]).unwrap();
+
+        err_msg = execute_public_mint(deps.as_mut(), env.clone(), user_msg_info.clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Sale phase (allowlist) requires an allowlist proof"));
 
-        // Mint with invalid requested collection address
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        mint_request = template_mint_msg.clone();
-        mint_request.collection = INVALID_ADDR.to_string();
-        mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
-        let err_msg = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
-                                   mint_request.clone(), mint_sig.clone())
-                .unwrap_err().to_string();
-        assert!(err_msg.contains("Invalid request collection address"));
+        err_msg = execute_allowlist_mint(deps.as_mut(), env.clone(), user_msg_info.clone(), proofs[1].clone())
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Allowlist proof is invalid"));
 
-        // Mint with the wrong collection address (but still valid)
-        deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        mint_request = template_mint_msg.clone();
-        mint_request.collection = "some_other_collection_addr".to_string();
-        mint_sig = sign_mint_request(signing_key_one.clone(), mint_request.clone());
-        let err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
-                               mint_request.clone(), mint_sig.clone())
-            .unwrap_err();
-        assert_eq!(err, ContractError::Generic(format!(
-		            "Mint request authorized for collection ({}) sent to incorrect collection ({})",
-		            mint_request.collection.as_str(),
-		            COLLECTION_CONTRACT_ADDR,
-		        )));
+        let response = execute_allowlist_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: Uint128::new(50) }
+        ]), phase_proofs[0].clone()).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("sale_phase", "allowlist")));
     }
 
     #[test]
-    fn mint_already_used_uuid() {
-        let price_wei = get_inj_wei_from_kilo_inj(100);
-
-        // Create a first keypair and store the pubkey in the minter
-        let signing_key_one = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+    fn dutch_auction() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
 
-        let mock_env = mock_env();
+        let env = mock_env();
+        let now = env.block.time.seconds() as u128;
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let paid_msg_info = |sender: &str, amount: u128| mock_info(sender, &[Coin { denom: INJ_DENOM.into(), amount: Uint128::new(amount) }]);
+        let sale_proceeds_msg = |to_address: &str, amount: u128| CosmosMsg::Bank(BankMsg::Send {
+            to_address: to_address.to_string(),
+            amount: vec![Coin { denom: INJ_DENOM.into(), amount: Uint128::new(amount) }],
+        });
+
+        let dutch_auction = DutchAuction {
+            start_price: Uint128::new(1000),
+            floor_price: Uint128::new(100),
+            price_decrement: Uint128::new(300),
+            decrement_interval: 60,
+            start_timestamp: Uint128::new(now),
+            rebates_enabled: true,
+        };
 
-        let msg_info = mock_info(NORMAL_USER_ADDR, &[
-            Coin {
-                denom: INJ_DENOM.into(),
-                amount: price_wei,
-            }
-        ]);
+        let mut instantiate_msg = template_instantiate_msg(signer_pub_key.clone());
+        instantiate_msg.minter_params.dega_minter_settings.max_supply = Some(3);
+        instantiate_msg.minter_params.dega_minter_settings.dutch_auction = Some(dutch_auction.clone());
+        instantiate_msg.minter_params.dega_minter_settings.public_sale = Some(PublicSaleSettings {
+            price: Uint128::new(1),
+            denom: INJ_DENOM.to_string(),
+            start_timestamp: Uint128::new(now),
+            end_timestamp: None,
+            base_uri: "https://example.com/".to_string(),
+            sale_recipient: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+        });
 
-        // Initialize fresh DB and minter
         let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        template_minter_via_msg(&mut deps.as_mut(), &instantiate_msg, &template_reply_msg(), false).unwrap();
 
-        // Mint to use the UUID
-        let mint_msg = template_mint_msg(&mock_env, price_wei);
-        let mint_sig = sign_mint_request(signing_key_one.clone(), mint_msg.clone());
-        execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
-                     mint_msg.clone(), mint_sig.clone()).unwrap();
+        assert_eq!(query_typed::<CurrentPriceResponse>(deps.as_ref(), QueryMsg::CurrentPrice {}).unwrap(), CurrentPriceResponse {
+            price: Uint128::new(1000),
+            denom: INJ_DENOM.to_string(),
+            auction_clearing_price: None,
+        });
 
-        // Try again with the same UUID
-        let uuid_err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
-                                    mint_msg.clone(), mint_sig.clone()).unwrap_err();
+        // Only the floor price is forwarded to the sale recipient, the rest is held for rebates
+        let response = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info(BUYER_ADDR, 1000)).unwrap();
+        assert_eq!(response.messages[1].msg, sale_proceeds_msg(PRIMARY_SALE_RECIPIENT_ADDR, 100));
+        assert!(response.attributes.contains(&Attribute::new("auction_held_amount", "900")));
 
-        assert_eq!(uuid_err, ContractError::Generic("UUID already registered".to_string()));
-    }
+        let mut err_msg = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info(BUYER_ADDR, 700))
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Insufficient payment | Price: 1000 | Paid: 700"));
 
+        err_msg = execute_claim_auction_rebate(deps.as_mut(), env.clone(), mock_info(BUYER_ADDR, &[]), None)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Dutch auction clearing price is not final yet"));
+
+        err_msg = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            dutch_auction: Some(None),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Dutch auction cannot be changed while auction purchases are unsettled"));
+
+        // The auction keeps the payment terms it first sold under when the public sale, fee or splits change
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            public_sale: Some(Some(PublicSaleSettings {
+                denom: "other_denom".to_string(),
+                sale_recipient: NORMAL_USER_ADDR.to_string(),
+                ..instantiate_msg.minter_params.dega_minter_settings.public_sale.clone().unwrap()
+            })),
+            platform_fee: Some(Some(PlatformFee {
+                fee_address: NEW_ADMIN_ADDR.to_string(),
+                share: Decimal::percent(10),
+            })),
+            revenue_splits: Some(vec![RevenueSplit {
+                recipient: NORMAL_USER_ADDR.to_string(),
+                share: Decimal::percent(50),
+            }]),
+            ..Default::default()
+        }).unwrap();
 
-    #[test]
-    fn mint_invalid_signature() {
-        let price_wei = get_inj_wei_from_kilo_inj(100);
+        // The price declines after each interval, selling out the supply sets the clearing price
+        let mut later_env = env.clone();
+        later_env.block.time = env.block.time.plus_seconds(60);
+        execute_public_mint(deps.as_mut(), later_env.clone(), paid_msg_info(NORMAL_USER_ADDR, 700)).unwrap();
+        assert_eq!(query_current_price(deps.as_ref(), later_env.clone()).unwrap().auction_clearing_price, None);
 
-        // Create a first keypair and store the pubkey in the minter
-        let signing_key_one = SigningKey::random(&mut OsRng);
-        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+        let response = execute_public_mint(deps.as_mut(), later_env.clone(), paid_msg_info(BUYER_ADDR, 700)).unwrap();
+        assert_eq!(response.messages[1].msg, sale_proceeds_msg(PRIMARY_SALE_RECIPIENT_ADDR, 100));
+        assert_eq!(query_current_price(deps.as_ref(), later_env.clone()).unwrap().auction_clearing_price, Some(Uint128::new(700)));
 
-        let mock_env = mock_env();
+        // Buyer paid 1700 for two tokens, of which 1500 was held: 300 is rebated and 1200 released
+        let response = run_execute(deps.as_mut(), later_env.clone(), mock_info(NORMAL_USER_ADDR, &[]), ExecuteMsg::ClaimAuctionRebate {
+            address: Some(BUYER_ADDR.to_string()),
+        }).unwrap();
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(response.messages[0].msg, sale_proceeds_msg(BUYER_ADDR, 300));
+        assert_eq!(response.messages[1].msg, sale_proceeds_msg(PRIMARY_SALE_RECIPIENT_ADDR, 1200));
 
-        let msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price_wei, }]);
+        err_msg = execute_claim_auction_rebate(deps.as_mut(), later_env.clone(), mock_info(BUYER_ADDR, &[]), None)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("No auction purchases to settle for address"));
+
+        // Buyers at the clearing price have nothing rebated, even when claiming after the price reached the floor
+        let mut floor_env = env.clone();
+        floor_env.block.time = env.block.time.plus_seconds(600);
+        assert_eq!(query_current_price(deps.as_ref(), floor_env.clone()).unwrap().auction_clearing_price, Some(Uint128::new(700)));
+        assert_eq!(query_current_price(deps.as_ref(), floor_env.clone()).unwrap().denom, INJ_DENOM.to_string());
+
+        // Rebates don't depend on the public sale still being enabled
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            public_sale: Some(None),
+            ..Default::default()
+        }).unwrap();
 
-        // Initialize fresh DB and minter
+        let response = execute_claim_auction_rebate(deps.as_mut(), floor_env.clone(), mock_info(NORMAL_USER_ADDR, &[]), None).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("clearing_price", "700")));
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(response.messages[0].msg, sale_proceeds_msg(PRIMARY_SALE_RECIPIENT_ADDR, 600));
+
+        err_msg = execute_claim_auction_rebate(deps.as_mut(), later_env.clone(), mock_info(BUYER_ADDR, &[]), Some(INVALID_ADDR.to_string()))
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid rebate address"));
+
+        // Once every purchase is settled the auction can be changed, which starts a new one
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            dutch_auction: Some(Some(DutchAuction {
+                start_timestamp: Uint128::new(now + 1000),
+                ..dutch_auction.clone()
+            })),
+            ..Default::default()
+        }).unwrap();
+        assert!(AUCTION_LAST_PRICE.may_load(deps.as_ref().storage).unwrap().is_none());
+        assert!(AUCTION_CLEARING_PRICE.may_load(deps.as_ref().storage).unwrap().is_none());
+        assert!(AUCTION_SALE.may_load(deps.as_ref().storage).unwrap().is_none());
+
+        err_msg = execute_claim_auction_rebate(deps.as_mut(), later_env.clone(), mock_info(BUYER_ADDR, &[]), None)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Dutch auction has not sold any tokens"));
+
+        // Purchase totals which can't be recorded fail the mint
         let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        template_minter_via_msg(&mut deps.as_mut(), &instantiate_msg, &template_reply_msg(), false).unwrap();
+        AUCTION_PURCHASES.save(deps.as_mut().storage, Addr::unchecked(BUYER_ADDR), &(1, Uint128::MAX)).unwrap();
+        err_msg = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info(BUYER_ADDR, 1000))
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error calculating auction purchases total"));
 
+        AUCTION_PURCHASES.save(deps.as_mut().storage, Addr::unchecked(BUYER_ADDR), &(u32::MAX, Uint128::zero())).unwrap();
+        err_msg = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info(BUYER_ADDR, 1000))
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Auction purchase count overflow"));
 
-        let bad_signer = SigningKey::random(&mut OsRng);
-        let bad_signer_pub_key = get_signer_pub_key(&bad_signer);
+        // Without rebates the full price is forwarded, and the clearing price is final at the floor
+        let mut deps = mock_dependencies();
+        instantiate_msg.minter_params.dega_minter_settings.dutch_auction = Some(DutchAuction {
+            rebates_enabled: false,
+            ..dutch_auction
+        });
+        template_minter_via_msg(&mut deps.as_mut(), &instantiate_msg, &template_reply_msg(), false).unwrap();
+
+        let response = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info(BUYER_ADDR, 1000)).unwrap();
+        assert_eq!(response.messages[1].msg, sale_proceeds_msg(PRIMARY_SALE_RECIPIENT_ADDR, 1000));
+
+        assert_eq!(query_current_price(deps.as_ref(), floor_env.clone()).unwrap(), CurrentPriceResponse {
+            price: Uint128::new(100),
+            denom: INJ_DENOM.to_string(),
+            auction_clearing_price: Some(Uint128::new(100)),
+        });
+
+        err_msg = execute_claim_auction_rebate(deps.as_mut(), floor_env, mock_info(BUYER_ADDR, &[]), None)
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Dutch auction rebates are not enabled"));
+    }
 
-        assert_ne!(signer_pub_key, bad_signer_pub_key);
+    #[test]
+    fn overpayment_refund() {
+        let price = Uint128::new(1000);
+        let cw20_token_addr = "cw20_token_addr";
 
-        // Sign mint request with the bad signer
-        let mint_msg = template_mint_msg(&mock_env, price_wei);
-        let bad_signature = sign_mint_request(bad_signer.clone(), mint_msg.clone());
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
 
-        // Try again with the same UUID
-        let invalid_sig_err = execute_mint(deps.as_mut(), mock_env.clone(), msg_info.clone(),
-                                           mint_msg.clone(), bad_signature.clone()).unwrap_err();
+        let env = mock_env();
+        let paid_msg_info = |sender: &str, amount: u128| mock_info(sender, &[Coin { denom: INJ_DENOM.into(), amount: Uint128::new(amount) }]);
+        let bank_send_msg = |to_address: &str, amount: u128| CosmosMsg::Bank(BankMsg::Send {
+            to_address: to_address.to_string(),
+            amount: vec![Coin { denom: INJ_DENOM.into(), amount: Uint128::new(amount) }],
+        });
+
+        let mut instantiate_msg = template_instantiate_msg(signer_pub_key.clone());
+        instantiate_msg.minter_params.dega_minter_settings.overpayment_mode = OverpaymentMode::Refund;
+        instantiate_msg.minter_params.dega_minter_settings.public_sale = Some(PublicSaleSettings {
+            price,
+            denom: INJ_DENOM.to_string(),
+            start_timestamp: Uint128::new(env.block.time.seconds() as u128),
+            end_timestamp: None,
+            base_uri: "https://example.com/".to_string(),
+            sale_recipient: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+        });
 
-        assert_eq!(invalid_sig_err, ContractError::Generic("Signature is invalid".to_string()));
+        let mut deps = mock_dependencies();
+        template_minter_via_msg(&mut deps.as_mut(), &instantiate_msg, &template_reply_msg(), false).unwrap();
+
+        // The excess is returned to the sender in a separate transfer
+        let mut mint_msg = template_mint_msg(&env, price);
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        let response = execute_mint(deps.as_mut(), env.clone(), paid_msg_info(NORMAL_USER_ADDR, 1050), mint_msg.clone(), mint_sig).unwrap();
+        assert_eq!(response.messages.len(), 3);
+        assert_eq!(response.messages[1].msg, bank_send_msg(PRIMARY_SALE_RECIPIENT_ADDR, 1000));
+        assert_eq!(response.messages[2].msg, bank_send_msg(NORMAL_USER_ADDR, 50));
+        assert!(response.attributes.contains(&Attribute::new("refund_amount", "50")));
+
+        // Exact payments have nothing to refund
+        mint_msg.uuid = "UUID_2".to_string();
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        let response = execute_mint(deps.as_mut(), env.clone(), paid_msg_info(NORMAL_USER_ADDR, 1000), mint_msg.clone(), mint_sig).unwrap();
+        assert_eq!(response.messages.len(), 2);
+        assert!(!response.attributes.iter().any(|attr| attr.key == "refund_amount"));
+
+        // CW20 excess is transferred back to the sender of the tokens
+        mint_msg.uuid = "UUID_3".to_string();
+        mint_msg.currency = cw20_token_addr.to_string();
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        let response = execute_receive(deps.as_mut(), env.clone(), mock_info(cw20_token_addr, &[]), Cw20ReceiveMsg {
+            sender: NORMAL_USER_ADDR.to_string(),
+            amount: Uint128::new(1200),
+            msg: to_json_binary(&ReceiveMsg::Mint { request: mint_msg.clone(), signature: mint_sig }).unwrap(),
+        }).unwrap();
+        assert_eq!(response.messages.len(), 3);
+        assert_eq!(response.messages[2].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: cw20_token_addr.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: NORMAL_USER_ADDR.to_string(),
+                amount: Uint128::new(200),
+            }).unwrap(),
+            funds: vec![],
+        }));
+
+        // Public mints are refunded as well
+        let response = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info(BUYER_ADDR, 1001)).unwrap();
+        assert_eq!(response.messages[1].msg, bank_send_msg(PRIMARY_SALE_RECIPIENT_ADDR, 1000));
+        assert_eq!(response.messages[2].msg, bank_send_msg(BUYER_ADDR, 1));
+        assert!(response.attributes.contains(&Attribute::new("refund_amount", "1")));
+
+        // Back to strict mode, overpayments are rejected
+        let response = execute_update_settings(&mut deps.as_mut(), &env, &mock_info(USER_ADMIN_ADDR, &[]), &UpdateDegaMinterConfigSettingsMsg {
+            overpayment_mode: Some(OverpaymentMode::Strict),
+            ..Default::default()
+        }).unwrap();
+        assert!(response.attributes.contains(&Attribute::new("overpayment_mode", "Strict")));
+
+        let err = execute_public_mint(deps.as_mut(), env.clone(), paid_msg_info(BUYER_ADDR, 1001)).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Overpayment | Price: 1000 | Paid: 1001".to_string()));
     }
 
     #[test]
-    fn mint_errors() {
-        let price_wei = get_inj_wei_from_kilo_inj(100);
+    fn revoke_mint_requests() {
         let signing_key = SigningKey::random(&mut OsRng);
         let signer_pub_key = get_signer_pub_key(&signing_key);
-        let msg_info = mock_info(NORMAL_USER_ADDR, &[Coin { denom: INJ_DENOM.into(), amount: price_wei, }]);
-        let env = mock_env();
-        let mint_request = template_mint_msg(&env, price_wei);
-        let signature = sign_mint_request(signing_key, mint_request.clone());
 
-        // Unable to load minter settings
+        let env = mock_env();
         let mut deps = mock_dependencies();
         template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        add_load_error_item(&DEGA_MINTER_SETTINGS);
-        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
-                                   mint_request.clone(), signature.clone())
+
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let uuid_status = |deps: Deps, uuid: &str| query_typed::<UuidStatusResponse>(deps, QueryMsg::UuidStatus {
+            uuid: uuid.to_string(),
+        }).unwrap();
+
+        let mut mint_msg = template_mint_msg(&env, Uint128::zero());
+        mint_msg.quantity = 2;
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+
+        assert_eq!(uuid_status(deps.as_ref(), "UUID").status, UuidStatus::Unused);
+
+        execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]), mint_msg.clone(), mint_sig).unwrap();
+        assert_eq!(uuid_status(deps.as_ref(), "UUID"), UuidStatusResponse {
+            uuid: "UUID".to_string(),
+            status: UuidStatus::Used,
+            token_ids: vec![1, 2],
+        });
+
+        let response = execute_revoke_mint_requests(&mut deps.as_mut(), &env, &admin_msg_info,
+                                                    vec!["UUID_2".to_string(), "UUID_3".to_string()]).unwrap();
+        assert_eq!(response.attributes.iter().filter(|attr| attr.key == "uuid").count(), 2);
+        assert_eq!(uuid_status(deps.as_ref(), "UUID_2").status, UuidStatus::Revoked);
+
+        // Used and revoked requests are listed and counted
+        let uuids = query_typed::<UuidsResponse>(deps.as_ref(), QueryMsg::Uuids {
+            start_after: None,
+            limit: None,
+        }).unwrap().uuids;
+        assert_eq!(uuids.iter().map(|uuid| uuid.uuid.as_str()).collect::<Vec<&str>>(), vec!["UUID", "UUID_2", "UUID_3"]);
+        assert_eq!(uuids[0].token_ids, vec![1, 2]);
+        assert_eq!(uuids[1].status, UuidStatus::Revoked);
+
+        let uuids = query_typed::<UuidsResponse>(deps.as_ref(), QueryMsg::Uuids {
+            start_after: Some("UUID".to_string()),
+            limit: Some(1),
+        }).unwrap().uuids;
+        assert_eq!(uuids, vec![uuid_status(deps.as_ref(), "UUID_2")]);
+
+        // Revoked requests are listed but not counted, as they stay in the registry for good
+        assert_eq!(query_typed::<UuidCountResponse>(deps.as_ref(), QueryMsg::UuidCount {}).unwrap().count, 1);
+
+        // Revoked requests can no longer be used, even with a valid signature
+        mint_msg.uuid = "UUID_2".to_string();
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        let err = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]), mint_msg.clone(), mint_sig).unwrap_err();
+        assert_eq!(err, ContractError::Generic("Mint request has been revoked".to_string()));
+
+        // Revoking again is a no-op
+        let response = execute_revoke_mint_requests(&mut deps.as_mut(), &env, &admin_msg_info, vec!["UUID_2".to_string()]).unwrap();
+        assert!(!response.attributes.iter().any(|attr| attr.key == "uuid"));
+        assert_eq!(query_typed::<UuidCountResponse>(deps.as_ref(), QueryMsg::UuidCount {}).unwrap().count, 1);
+
+        let mut err_msg;
+
+        err_msg = execute_revoke_mint_requests(&mut deps.as_mut(), &env, &admin_msg_info, vec!["UUID".to_string()])
             .unwrap_err().to_string();
-        assert!(err_msg.contains("Error during dega minter settings query"));
-        clear_load_error_items();
+        assert!(err_msg.contains("Mint request has already been used: UUID"));
 
-        // Error due to invalid signature
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        let invalid_signature = "^Invalid Signature^";
-        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
-                                   mint_request.clone(), invalid_signature.to_string())
+        err_msg = execute_revoke_mint_requests(&mut deps.as_mut(), &env, &admin_msg_info, vec![])
             .unwrap_err().to_string();
-        assert!(err_msg.contains("Error during decode signature from base64"));
-        assert!(err_msg.contains("Error during signature verification"));
+        assert!(err_msg.contains("No UUIDs provided"));
+
+        err_msg = run_execute(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[]), ExecuteMsg::RevokeMintRequests {
+            uuids: vec!["UUID_4".to_string()],
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Only admins and signer managers can revoke mint requests"));
 
-        // Error due to being unable to save to UUID registry
-        let mut deps = mock_dependencies();
-        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
         add_save_error_map(&UUID_REGISTRY);
-        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
-                                   mint_request.clone(), signature.clone())
+        err_msg = execute_revoke_mint_requests(&mut deps.as_mut(), &env, &admin_msg_info, vec!["UUID_4".to_string()])
             .unwrap_err().to_string();
-        assert!(err_msg.contains("Error while registering UUID"));
+        assert!(err_msg.contains("Error while revoking UUID"));
         clear_save_error_items();
 
-        // Error due to not being able to load the address of the collection
+        // Entries registered before token ids were recorded are empty objects
+        let legacy_registry: Map<String, Empty> = Map::new("uuid_registry");
+        legacy_registry.save(deps.as_mut().storage, "LEGACY_UUID".to_string(), &Empty {}).unwrap();
+        assert_eq!(uuid_status(deps.as_ref(), "LEGACY_UUID").status, UuidStatus::Used);
+        assert!(uuid_status(deps.as_ref(), "LEGACY_UUID").token_ids.is_empty());
+    }
+
+    #[test]
+    fn prune_uuids() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
         let mut deps = mock_dependencies();
         template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        add_load_error_item(&COLLECTION_ADDRESS);
-        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
-                                   mint_request.clone(), signature.clone())
+
+        let normal_user_msg_info = mock_info(NORMAL_USER_ADDR, &[]);
+        let uuid_status = |deps: Deps, uuid: &str| query_typed::<UuidStatusResponse>(deps, QueryMsg::UuidStatus {
+            uuid: uuid.to_string(),
+        }).unwrap().status;
+        let pruned_count = |response: &Response| response.attributes.iter()
+            .find(|attr| attr.key == "pruned_count").unwrap().value.clone();
+        let uuid_count = |deps: Deps| query_typed::<UuidCountResponse>(deps, QueryMsg::UuidCount {}).unwrap().count;
+
+        // Requests valid until 50 and 100 seconds from now
+        let mut mint_msg = template_mint_msg(&env, Uint128::zero());
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        execute_mint(deps.as_mut(), env.clone(), normal_user_msg_info.clone(), mint_msg.clone(), mint_sig).unwrap();
+
+        mint_msg.uuid = "UUID_2".to_string();
+        mint_msg.validity_end_timestamp += Uint128::new(50);
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        execute_mint(deps.as_mut(), env.clone(), normal_user_msg_info.clone(), mint_msg.clone(), mint_sig).unwrap();
+
+        execute_revoke_mint_requests(&mut deps.as_mut(), &env, &mock_info(USER_ADMIN_ADDR, &[]), vec!["UUID_3".to_string()]).unwrap();
+        assert_eq!(uuid_count(deps.as_ref()), 2);
+
+        // Requests are still valid at their validity end
+        let mut later_env = env.clone();
+        later_env.block.time = env.block.time.plus_seconds(50);
+        let response = execute_prune_uuids(&mut deps.as_mut(), &later_env, &normal_user_msg_info, 10).unwrap();
+        assert_eq!(pruned_count(&response), "0");
+        assert_eq!(uuid_status(deps.as_ref(), "UUID"), UuidStatus::Used);
+
+        later_env.block.time = env.block.time.plus_seconds(1000);
+        let response = run_execute(deps.as_mut(), later_env.clone(), normal_user_msg_info.clone(), ExecuteMsg::PruneUuids {
+            limit: 1,
+        }).unwrap();
+        assert_eq!(pruned_count(&response), "1");
+        assert_eq!(uuid_status(deps.as_ref(), "UUID"), UuidStatus::Unused);
+        assert_eq!(uuid_status(deps.as_ref(), "UUID_2"), UuidStatus::Used);
+
+        let response = execute_prune_uuids(&mut deps.as_mut(), &later_env, &normal_user_msg_info, 10).unwrap();
+        assert_eq!(pruned_count(&response), "1");
+        assert_eq!(uuid_status(deps.as_ref(), "UUID_2"), UuidStatus::Unused);
+        assert_eq!(uuid_count(deps.as_ref()), 0);
+
+        // Revoked requests have no known validity end and are kept
+        assert_eq!(uuid_status(deps.as_ref(), "UUID_3"), UuidStatus::Revoked);
+
+        let err_msg = execute_prune_uuids(&mut deps.as_mut(), &later_env, &normal_user_msg_info, 0)
             .unwrap_err().to_string();
-        assert!(err_msg.contains("Error while loading collection address"));
-        clear_load_error_items();
+        assert!(err_msg.contains("Prune limit must be at least one"));
+    }
 
-        // Error incrementing token index
+    #[test]
+    fn cw20_mint() {
+        let price = Uint128::new(1_000_000);
+        let cw20_token_addr = "cw20_token_addr";
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
         let mut deps = mock_dependencies();
         template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        add_save_error_item(&TOKEN_INDEX);
-        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
-                                   mint_request.clone(), signature.clone())
-            .unwrap_err().to_string();
-        assert!(err_msg.contains("Error while incrementing token index"));
-        clear_save_error_items();
 
-        // Error serializing the exec mint message for the collection contract
+        let mut mint_msg = template_mint_msg(&env, price);
+        mint_msg.currency = cw20_token_addr.to_string();
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+
+        let receive_msg = |request: &MintRequest, signature: &String, amount: Uint128| ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: NORMAL_USER_ADDR.to_string(),
+            amount,
+            msg: to_json_binary(&ReceiveMsg::Mint {
+                request: request.clone(),
+                signature: signature.clone(),
+            }).unwrap(),
+        });
+
+        let response = run_execute(deps.as_mut(), env.clone(), mock_info(cw20_token_addr, &[]),
+                                   receive_msg(&mint_msg, &mint_sig, price)).unwrap();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(response.messages[1].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: cw20_token_addr.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+                amount: price,
+            }).unwrap(),
+            funds: vec![],
+        }));
+        assert!(response.attributes.iter().any(|attr| attr.key == "sender" && attr.value == NORMAL_USER_ADDR));
+
+        let mut err_msg;
+
+        // Tokens sent from a different CW20 contract than requested
+        mint_msg.uuid = "UUID_2".to_string();
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        let err = run_execute(deps.as_mut(), env.clone(), mock_info("other_cw20_addr", &[]),
+                              receive_msg(&mint_msg, &mint_sig, price)).unwrap_err();
+        assert_eq!(err, ContractError::Generic(format!(
+            "Payment currency does not match requested currency | Payment: other_cw20_addr | Requested: {}", cw20_token_addr)));
+
+        // Wrong amount
+        mint_msg.uuid = "UUID_3".to_string();
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        err_msg = run_execute(deps.as_mut(), env.clone(), mock_info(cw20_token_addr, &[]),
+                              receive_msg(&mint_msg, &mint_sig, price - Uint128::one())).unwrap_err().to_string();
+        assert!(err_msg.contains("Insufficient payment"));
+
+        // Invalid hook message
+        err_msg = execute_receive(deps.as_mut(), env.clone(), mock_info(cw20_token_addr, &[]), Cw20ReceiveMsg {
+            sender: NORMAL_USER_ADDR.to_string(),
+            amount: price,
+            msg: to_json_binary(&"not a mint").unwrap(),
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid CW20 receive message"));
+
+        // Invalid sender
+        err_msg = run_execute(deps.as_mut(), env.clone(), mock_info(cw20_token_addr, &[]), ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: INVALID_ADDR.to_string(),
+            amount: price,
+            msg: to_json_binary(&ReceiveMsg::Mint { request: mint_msg.clone(), signature: mint_sig.clone() }).unwrap(),
+        })).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid CW20 sender address"));
+
+        // Error serializing the transfer message
+        mint_msg.uuid = "UUID_4".to_string();
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        set_binary_for_json_error(Some(to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+            amount: price,
+        }).unwrap()));
+        err_msg = run_execute(deps.as_mut(), env.clone(), mock_info(cw20_token_addr, &[]),
+                              receive_msg(&mint_msg, &mint_sig, price)).unwrap_err().to_string();
+        assert!(err_msg.contains("Error during conversion of CW20 transfer message to binary"));
+        set_binary_for_json_error(None);
+    }
+
+    #[test]
+    fn mint_with_revenue_splits() {
+        let price_wei = Uint128::new(1001);
+        let artist_addr = "artist_addr";
+        let studio_addr = "studio_addr";
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
         let mut deps = mock_dependencies();
         template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
-        let new_token_id = TOKEN_INDEX.may_load(&deps.storage).unwrap().unwrap_or_default() + 1;
-        let mint_exec_msg = dega_inj::cw721::ExecuteMsg::Mint {
-            token_id: new_token_id.to_string(),
-            owner: mint_request.to.clone(),
-            token_uri: Some(mint_request.uri.clone()),
-            extension: None,
+
+        let splits = vec![
+            RevenueSplit { recipient: artist_addr.to_string(), share: Decimal::percent(50) },
+            RevenueSplit { recipient: studio_addr.to_string(), share: Decimal::percent(30) },
+        ];
+
+        let response = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            revenue_splits: Some(splits.clone()),
+            ..Default::default()
+        }).unwrap();
+        assert!(response.attributes.iter().any(|attr| attr.key == "revenue_splits" && attr.value == "artist_addr:0.5,studio_addr:0.3"));
+        assert_eq!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.revenue_splits, splits);
+
+        let mint_msg = template_mint_msg(&env, price_wei);
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        let response = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: price_wei }
+        ]), mint_msg.clone(), mint_sig.clone()).unwrap();
+
+        // One transfer per recipient, with the rounding dust going to the primary sale recipient
+        let transfers: Vec<CosmosMsg> = response.messages.iter().skip(1).map(|msg| msg.msg.clone()).collect();
+        assert_eq!(transfers, vec![
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: artist_addr.to_string(),
+                amount: vec![Coin { denom: INJ_DENOM.into(), amount: Uint128::new(500) }],
+            }),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: studio_addr.to_string(),
+                amount: vec![Coin { denom: INJ_DENOM.into(), amount: Uint128::new(300) }],
+            }),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+                amount: vec![Coin { denom: INJ_DENOM.into(), amount: Uint128::new(201) }],
+            }),
+        ]);
+
+        // Invalid splits are rejected
+        let err_msg = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            revenue_splits: Some(vec![RevenueSplit { recipient: INVALID_ADDR.to_string(), share: Decimal::percent(10) }]),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid revenue splits"));
+
+        // Clearing the splits
+        execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            revenue_splits: Some(vec![]),
+            ..Default::default()
+        }).unwrap();
+        assert!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.revenue_splits.is_empty());
+    }
+
+    #[test]
+    fn mint_with_platform_fee() {
+        let price_wei = Uint128::new(1000);
+        let platform_addr = "platform_addr";
+        let artist_addr = "artist_addr";
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
+        let admin_msg_info = mock_info(USER_ADMIN_ADDR, &[]);
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        let platform_fee = PlatformFee {
+            fee_address: platform_addr.to_string(),
+            share: Decimal::permille(25),
         };
-        let binary_for_error = to_json_binary(&mint_exec_msg).unwrap();
-        set_binary_for_json_error(Some(binary_for_error));
-        let err_msg = execute_mint(deps.as_mut(), env.clone(), msg_info.clone(),
-                                   mint_request.clone(), signature.clone())
-            .unwrap_err().to_string();
-        assert!(err_msg.contains("Error during conversion of mint exec message to binary"));
-        set_binary_for_json_error(None);
+
+        let response = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            platform_fee: Some(Some(platform_fee.clone())),
+            revenue_splits: Some(vec![RevenueSplit { recipient: artist_addr.to_string(), share: Decimal::percent(50) }]),
+            ..Default::default()
+        }).unwrap();
+        assert!(response.attributes.iter().any(|attr| attr.key == "platform_fee.share" && attr.value == "0.025"));
+        assert_eq!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.platform_fee, Some(platform_fee));
+
+        let mint_msg = template_mint_msg(&env, price_wei);
+        let mint_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        let response = execute_mint(deps.as_mut(), env.clone(), mock_info(NORMAL_USER_ADDR, &[
+            Coin { denom: INJ_DENOM.into(), amount: price_wei }
+        ]), mint_msg.clone(), mint_sig.clone()).unwrap();
+
+        // Fee is taken first, the splits apply to what remains
+        let transfers: Vec<CosmosMsg> = response.messages.iter().skip(1).map(|msg| msg.msg.clone()).collect();
+        assert_eq!(transfers, vec![
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: platform_addr.to_string(),
+                amount: vec![Coin { denom: INJ_DENOM.into(), amount: Uint128::new(25) }],
+            }),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: artist_addr.to_string(),
+                amount: vec![Coin { denom: INJ_DENOM.into(), amount: Uint128::new(487) }],
+            }),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: PRIMARY_SALE_RECIPIENT_ADDR.to_string(),
+                amount: vec![Coin { denom: INJ_DENOM.into(), amount: Uint128::new(488) }],
+            }),
+        ]);
+        assert!(response.attributes.iter().any(|attr| attr.key == "platform_fee_address" && attr.value == platform_addr));
+        assert!(response.attributes.iter().any(|attr| attr.key == "platform_fee_amount" && attr.value == "25"));
+        assert!(response.attributes.iter().any(|attr| attr.key == "sale_proceeds_amount" && attr.value == "975"));
+
+        // Invalid fee share
+        let err_msg = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            platform_fee: Some(Some(PlatformFee { fee_address: platform_addr.to_string(), share: Decimal::percent(101) })),
+            ..Default::default()
+        }).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid platform fee"));
+        assert!(err_msg.contains("Share cannot be greater than 100%"));
+
+        // Removing the fee
+        let response = execute_update_settings(&mut deps.as_mut(), &env, &admin_msg_info, &UpdateDegaMinterConfigSettingsMsg {
+            platform_fee: Some(None),
+            ..Default::default()
+        }).unwrap();
+        assert!(response.attributes.iter().any(|attr| attr.key == "platform_fee" && attr.value == "None"));
+        assert!(query_config(deps.as_ref(), env.clone()).unwrap().dega_minter_settings.platform_fee.is_none());
     }
-}
\ No newline at end of file
+}
+
@@ -1,6 +1,18 @@
-use cosmwasm_std::{Binary, StdError, StdResult, Storage};
-use dega_inj::helpers::save_item_wrapped;
-use crate::state::TOKEN_INDEX;
+use cosmwasm_std::{Addr, Api, Binary, Decimal, Empty, StdError, StdResult, Storage, Timestamp, Uint128};
+use dega_inj::helpers::{save_item_wrapped, save_map_item_wrapped, share_validate};
+use dega_inj::minter::{DutchAuction, MultisigSettings, PhaseEligibility, PlatformFee, PublicSaleSettings, RevenueSplit, Role, SalePhase, SignerKeyInfo};
+use crate::merkle::parse_merkle_hash;
+use crate::state::{ADMIN_ACTION_COUNT, ADMIN_LIST, AUCTION_CLEARING_PRICE, AUCTION_LAST_PRICE, PROPOSAL_COUNT, ROLES, TOKEN_INDEX, UUID_COUNT, UUID_EXPIRATIONS, UUID_REGISTRY, UuidRecord};
+
+// Label reported for the signer key stored in the minter settings
+pub(crate) const PRIMARY_SIGNER_LABEL: &str = "primary";
+// Label reported for the key looked up from the signer address in the minter settings
+pub(crate) const SIGNER_ADDRESS_LABEL: &str = "signer_address";
+// Most expired UUIDs removed from the registry by a single prune
+pub(crate) const MAX_UUID_PRUNE_LIMIT: u32 = 500;
+// Page size of list queries when no limit is given, and the largest page they return
+pub(crate) const DEFAULT_QUERY_LIMIT: u32 = 30;
+pub(crate) const MAX_QUERY_LIMIT: u32 = 100;
 
 
 pub(crate) fn verify_compressed_pub_key(pub_key_string: String) -> StdResult<Vec<u8>> {
@@ -14,13 +26,544 @@ pub(crate) fn verify_compressed_pub_key(pub_key_string: String) -> StdResult<Vec
     Ok(pub_key_bytes)
 }
 
+// Admins hold every role
+pub(crate) fn has_role(store: &dyn Storage, address: &Addr, role: &Role) -> StdResult<bool> {
+    if ADMIN_LIST.has(store, address.clone()) {
+        return Ok(true);
+    }
+
+    let roles = ROLES.may_load(store, address.clone())?.unwrap_or_default();
+
+    Ok(roles.contains(role))
+}
+
+pub(crate) fn role_name(role: &Role) -> &'static str {
+    match role {
+        Role::SuperAdmin => "super admin",
+        Role::Pauser => "pauser",
+        Role::SignerManager => "signer manager",
+        Role::CollectionEditor => "collection editor",
+        Role::Airdropper => "airdropper",
+    }
+}
+
+// Registers a used UUID along with its entry in the expiration index, so it can be pruned once the request expires
+pub(crate) fn save_used_uuid(store: &mut dyn Storage, uuid: &str, uuid_record: &UuidRecord) -> StdResult<()> {
+    save_map_item_wrapped(store, &UUID_REGISTRY, uuid.to_string(), uuid_record)?;
+
+    if let Some(validity_end_timestamp) = uuid_record.validity_end_timestamp {
+        let expiration = u64::try_from(validity_end_timestamp.u128()).unwrap_or(u64::MAX);
+        save_map_item_wrapped(store, &UUID_EXPIRATIONS, (expiration, uuid.to_string()), &Empty {})?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn is_signer_active(signer: &SignerKeyInfo, block_time: &Timestamp) -> bool {
+    let epoch_time_128 = Uint128::from(block_time.seconds());
+
+    if let Some(activation_timestamp) = signer.activation_timestamp {
+        if epoch_time_128 < activation_timestamp {
+            return false;
+        }
+    }
+
+    if let Some(expiration_timestamp) = signer.expiration_timestamp {
+        if epoch_time_128 > expiration_timestamp {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub(crate) fn validate_platform_fee(api: &dyn Api, platform_fee: &PlatformFee) -> StdResult<()> {
+    api.addr_validate(platform_fee.fee_address.as_str())
+        .map_err(|e| StdError::generic_err(format!("Invalid platform fee address ({}): {}", platform_fee.fee_address, e)))?;
+
+    share_validate(platform_fee.share)?;
+
+    Ok(())
+}
+
+pub(crate) fn validate_public_sale(api: &dyn Api, public_sale: &PublicSaleSettings) -> StdResult<()> {
+    api.addr_validate(public_sale.sale_recipient.as_str())
+        .map_err(|e| StdError::generic_err(format!("Invalid public sale recipient ({}): {}", public_sale.sale_recipient, e)))?;
+
+    if public_sale.denom.is_empty() {
+        return Err(StdError::generic_err("Public sale denom cannot be empty"));
+    }
+
+    if let Some(end_timestamp) = public_sale.end_timestamp {
+        if end_timestamp <= public_sale.start_timestamp {
+            return Err(StdError::generic_err(format!(
+                "Public sale must end after it starts | Start: {} | End: {}",
+                public_sale.start_timestamp,
+                end_timestamp,
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn validate_dutch_auction(auction: &DutchAuction) -> StdResult<()> {
+    if auction.floor_price > auction.start_price {
+        return Err(StdError::generic_err(format!(
+            "Dutch auction floor price cannot be above the start price | Start price: {} | Floor price: {}",
+            auction.start_price,
+            auction.floor_price,
+        )));
+    }
+
+    if auction.price_decrement.is_zero() {
+        return Err(StdError::generic_err("Dutch auction price decrement must be positive"));
+    }
+
+    if auction.decrement_interval == 0 {
+        return Err(StdError::generic_err("Dutch auction decrement interval must be positive"));
+    }
+
+    Ok(())
+}
+
+// The threshold must be reachable by the current admins
+pub(crate) fn validate_multisig(multisig: &MultisigSettings, admin_count: usize) -> StdResult<()> {
+    if multisig.threshold == 0 {
+        return Err(StdError::generic_err("Multisig threshold must be at least one"));
+    }
+
+    if multisig.threshold as usize > admin_count {
+        return Err(StdError::generic_err(format!(
+            "Multisig threshold cannot be above the number of admins | Threshold: {} | Admins: {}",
+            multisig.threshold,
+            admin_count,
+        )));
+    }
+
+    if multisig.proposal_duration == 0 {
+        return Err(StdError::generic_err("Multisig proposal duration must be positive"));
+    }
+
+    Ok(())
+}
+
+// Start price less one decrement per full interval since the start, down to the floor price
+pub(crate) fn dutch_auction_price(auction: &DutchAuction, time: &Timestamp) -> Uint128 {
+    let epoch_time_128 = Uint128::from(time.seconds());
+
+    if epoch_time_128 <= auction.start_timestamp {
+        return auction.start_price;
+    }
+
+    let intervals = (epoch_time_128 - auction.start_timestamp) / Uint128::from(auction.decrement_interval);
+    let price = auction.start_price.saturating_sub(auction.price_decrement.saturating_mul(intervals));
+
+    price.max(auction.floor_price)
+}
+
+// The clearing price is final once the max supply has sold out, at the price of the last sale, or once the price
+// has declined to the floor, as every later sale is at the floor price. Sell outs are checked first so a supply sold
+// out above the floor keeps its clearing price after the floor time, and a clearing price fixed by a claim is kept.
+pub(crate) fn auction_clearing_price(
+    store: &dyn Storage,
+    auction: &DutchAuction,
+    max_supply: Option<u64>,
+    time: &Timestamp,
+) -> StdResult<Option<Uint128>> {
+
+    if let Some(clearing_price) = AUCTION_CLEARING_PRICE.may_load(store)? {
+        return Ok(Some(clearing_price));
+    }
+
+    if let Some(max_supply) = max_supply {
+        let minted = TOKEN_INDEX.may_load(store)?.unwrap_or_default();
+
+        if minted >= max_supply {
+            if let Some(last_price) = AUCTION_LAST_PRICE.may_load(store)? {
+                return Ok(Some(last_price));
+            }
+        }
+    }
+
+    if dutch_auction_price(auction, time) == auction.floor_price {
+        return Ok(Some(auction.floor_price));
+    }
+
+    Ok(None)
+}
+
+// Phases must be named uniquely, in chronological order and without overlapping windows
+pub(crate) fn validate_sale_phases(phases: &[SalePhase]) -> StdResult<()> {
+    for (index, phase) in phases.iter().enumerate() {
+        if phase.name.is_empty() {
+            return Err(StdError::generic_err("Sale phase name cannot be empty"));
+        }
+
+        if phases[..index].iter().any(|other| other.name == phase.name) {
+            return Err(StdError::generic_err(format!("Duplicate sale phase name: {}", phase.name)));
+        }
+
+        if phase.denom.is_empty() {
+            return Err(StdError::generic_err(format!("Sale phase ({}) denom cannot be empty", phase.name)));
+        }
+
+        if phase.end_timestamp <= phase.start_timestamp {
+            return Err(StdError::generic_err(format!(
+                "Sale phase ({}) must end after it starts | Start: {} | End: {}",
+                phase.name,
+                phase.start_timestamp,
+                phase.end_timestamp,
+            )));
+        }
+
+        if let PhaseEligibility::Allowlist { merkle_root: Some(merkle_root) } = &phase.eligibility {
+            parse_merkle_hash(merkle_root)
+                .map_err(|e| StdError::generic_err(format!("Sale phase ({}) merkle root is invalid: {}", phase.name, e)))?;
+        }
+
+        if index > 0 && phase.start_timestamp <= phases[index - 1].end_timestamp {
+            return Err(StdError::generic_err(format!(
+                "Sale phase ({}) must start after the previous phase ({}) ends",
+                phase.name,
+                phases[index - 1].name,
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+// Phase whose window contains the time, both ends inclusive
+pub(crate) fn active_phase<'a>(phases: &'a [SalePhase], time: &Timestamp) -> Option<&'a SalePhase> {
+    let epoch_time_128 = Uint128::from(time.seconds());
+
+    phases.iter().find(|phase| phase.start_timestamp <= epoch_time_128 && epoch_time_128 <= phase.end_timestamp)
+}
+
+// Revenue split recipients must be valid and unique, with positive shares that total at most one
+pub(crate) fn validate_revenue_splits(api: &dyn Api, splits: &[RevenueSplit]) -> StdResult<()> {
+    let mut total_share = Decimal::zero();
+
+    for (index, split) in splits.iter().enumerate() {
+        api.addr_validate(split.recipient.as_str())
+            .map_err(|e| StdError::generic_err(format!("Invalid revenue split recipient ({}): {}", split.recipient, e)))?;
+
+        if splits[..index].iter().any(|other| other.recipient == split.recipient) {
+            return Err(StdError::generic_err(format!("Duplicate revenue split recipient: {}", split.recipient)));
+        }
+
+        if split.share.is_zero() {
+            return Err(StdError::generic_err(format!("Revenue split share must be positive: {}", split.recipient)));
+        }
+
+        total_share = total_share.checked_add(split.share)
+            .map_err(|e| StdError::generic_err(format!("Revenue split shares overflow: {}", e)))?;
+    }
+
+    if total_share > Decimal::one() {
+        return Err(StdError::generic_err(format!("Revenue split shares total more than one: {}", total_share)));
+    }
+
+    Ok(())
+}
+
+// Divides the proceeds between the revenue split recipients, in order, rounding each share down.
+// The primary sale recipient receives what remains, including any rounding dust.
+// Recipients due nothing are left out, as zero amount transfers are rejected.
+pub(crate) fn split_proceeds(
+    amount: Uint128,
+    splits: &[RevenueSplit],
+    primary_sale_recipient: &Addr,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let mut payouts: Vec<(Addr, Uint128)> = vec![];
+    let mut remainder = amount;
+
+    for split in splits {
+        let split_amount = amount.checked_mul_floor(split.share)
+            .map_err(|e| StdError::generic_err(format!("Error calculating revenue split: {}", e)))?;
+        remainder = remainder.checked_sub(split_amount)?;
+
+        if !split_amount.is_zero() {
+            payouts.push((Addr::unchecked(split.recipient.clone()), split_amount));
+        }
+    }
+
+    if !remainder.is_zero() {
+        payouts.push((primary_sale_recipient.clone(), remainder));
+    }
+
+    Ok(payouts)
+}
+
 pub(crate) fn increment_token_index(store: &mut dyn Storage) -> StdResult<u64> {
     let val = TOKEN_INDEX.may_load(store)?.unwrap_or_default() + 1;
     save_item_wrapped(store, &TOKEN_INDEX, &val)?;
     Ok(val)
 }
 
+pub(crate) fn increment_proposal_count(store: &mut dyn Storage) -> StdResult<u64> {
+    let val = PROPOSAL_COUNT.may_load(store)?.unwrap_or_default() + 1;
+    save_item_wrapped(store, &PROPOSAL_COUNT, &val)?;
+    Ok(val)
+}
+
+pub(crate) fn increment_uuid_count(store: &mut dyn Storage) -> StdResult<u64> {
+    let val = UUID_COUNT.may_load(store)?.unwrap_or_default() + 1;
+    save_item_wrapped(store, &UUID_COUNT, &val)?;
+    Ok(val)
+}
+
+pub(crate) fn decrease_uuid_count(store: &mut dyn Storage, amount: u64) -> StdResult<u64> {
+    let val = UUID_COUNT.may_load(store)?.unwrap_or_default().saturating_sub(amount);
+    save_item_wrapped(store, &UUID_COUNT, &val)?;
+    Ok(val)
+}
+
+pub(crate) fn query_limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize
+}
+
+pub(crate) fn increment_admin_action_count(store: &mut dyn Storage) -> StdResult<u64> {
+    let val = ADMIN_ACTION_COUNT.may_load(store)?.unwrap_or_default() + 1;
+    save_item_wrapped(store, &ADMIN_ACTION_COUNT, &val)?;
+    Ok(val)
+}
+
 #[cfg(test)]
 mod tests {
+    use cosmwasm_std::testing::MockApi;
+    use super::*;
+
+    #[test]
+    fn signer_activity_window() {
+        let mut signer = SignerKeyInfo {
+            pub_key: "pubkey".to_string(),
+            activation_timestamp: None,
+            expiration_timestamp: None,
+        };
+
+        assert!(is_signer_active(&signer, &Timestamp::from_seconds(1000)));
+
+        signer.activation_timestamp = Some(Uint128::new(1000));
+        signer.expiration_timestamp = Some(Uint128::new(2000));
+        assert!(!is_signer_active(&signer, &Timestamp::from_seconds(999)));
+        assert!(is_signer_active(&signer, &Timestamp::from_seconds(1000)));
+        assert!(is_signer_active(&signer, &Timestamp::from_seconds(2000)));
+        assert!(!is_signer_active(&signer, &Timestamp::from_seconds(2001)));
+    }
+
+    #[test]
+    fn revenue_splits() {
+        let api = MockApi::default();
+        let primary = Addr::unchecked("primary_addr");
+        let splits = vec![
+            RevenueSplit { recipient: "artist_addr".to_string(), share: Decimal::percent(50) },
+            RevenueSplit { recipient: "studio_addr".to_string(), share: Decimal::permille(333) },
+        ];
+
+        validate_revenue_splits(&api, &splits).unwrap();
+        validate_revenue_splits(&api, &[]).unwrap();
+
+        // Shares round down and the dust goes to the primary sale recipient
+        assert_eq!(split_proceeds(Uint128::new(1001), &splits, &primary).unwrap(), vec![
+            (Addr::unchecked("artist_addr"), Uint128::new(500)),
+            (Addr::unchecked("studio_addr"), Uint128::new(333)),
+            (primary.clone(), Uint128::new(168)),
+        ]);
+
+        // No splits pays everything to the primary sale recipient
+        assert_eq!(split_proceeds(Uint128::new(1001), &[], &primary).unwrap(), vec![(primary.clone(), Uint128::new(1001))]);
+
+        // Zero amounts are left out
+        let full_splits = vec![
+            RevenueSplit { recipient: "artist_addr".to_string(), share: Decimal::percent(50) },
+            RevenueSplit { recipient: "studio_addr".to_string(), share: Decimal::percent(50) },
+        ];
+        assert_eq!(split_proceeds(Uint128::new(1), &full_splits, &primary).unwrap(), vec![(primary.clone(), Uint128::new(1))]);
+        assert_eq!(split_proceeds(Uint128::new(10), &full_splits, &primary).unwrap(), vec![
+            (Addr::unchecked("artist_addr"), Uint128::new(5)),
+            (Addr::unchecked("studio_addr"), Uint128::new(5)),
+        ]);
+        assert!(split_proceeds(Uint128::zero(), &full_splits, &primary).unwrap().is_empty());
+
+        let mut err_string;
+
+        err_string = validate_revenue_splits(&api, &[
+            RevenueSplit { recipient: "INVALID_ADDR".to_string(), share: Decimal::percent(10) },
+        ]).unwrap_err().to_string();
+        assert!(err_string.contains("Invalid revenue split recipient"));
+
+        err_string = validate_revenue_splits(&api, &[
+            RevenueSplit { recipient: "artist_addr".to_string(), share: Decimal::percent(10) },
+            RevenueSplit { recipient: "artist_addr".to_string(), share: Decimal::percent(10) },
+        ]).unwrap_err().to_string();
+        assert!(err_string.contains("Duplicate revenue split recipient"));
+
+        err_string = validate_revenue_splits(&api, &[
+            RevenueSplit { recipient: "artist_addr".to_string(), share: Decimal::zero() },
+        ]).unwrap_err().to_string();
+        assert!(err_string.contains("Revenue split share must be positive"));
+
+        err_string = validate_revenue_splits(&api, &[
+            RevenueSplit { recipient: "artist_addr".to_string(), share: Decimal::percent(60) },
+            RevenueSplit { recipient: "studio_addr".to_string(), share: Decimal::percent(41) },
+        ]).unwrap_err().to_string();
+        assert!(err_string.contains("Revenue split shares total more than one"));
+
+        err_string = validate_revenue_splits(&api, &[
+            RevenueSplit { recipient: "artist_addr".to_string(), share: Decimal::MAX },
+            RevenueSplit { recipient: "studio_addr".to_string(), share: Decimal::MAX },
+        ]).unwrap_err().to_string();
+        assert!(err_string.contains("Revenue split shares overflow"));
+    }
+
+    #[test]
+    fn platform_fee_validation() {
+        let api = MockApi::default();
+        let mut platform_fee = PlatformFee {
+            fee_address: "platform_addr".to_string(),
+            share: Decimal::percent(100),
+        };
+
+        validate_platform_fee(&api, &platform_fee).unwrap();
+
+        platform_fee.share = Decimal::percent(101);
+        let err_string = validate_platform_fee(&api, &platform_fee).unwrap_err().to_string();
+        assert!(err_string.contains("Share cannot be greater than 100%"));
+
+        platform_fee.share = Decimal::percent(5);
+        platform_fee.fee_address = "INVALID_ADDR".to_string();
+        let err_string = validate_platform_fee(&api, &platform_fee).unwrap_err().to_string();
+        assert!(err_string.contains("Invalid platform fee address"));
+    }
+
+    #[test]
+    fn sale_phase_validation() {
+        let phase = |name: &str, start: u128, end: u128| SalePhase {
+            name: name.to_string(),
+            start_timestamp: Uint128::new(start),
+            end_timestamp: Uint128::new(end),
+            price: Uint128::new(100),
+            denom: "inj".to_string(),
+            max_mints_per_wallet: None,
+            eligibility: PhaseEligibility::SignedRequest,
+        };
+
+        let phases = vec![phase("allowlist", 1000, 2000), phase("public", 2001, 3000)];
+        validate_sale_phases(&phases).unwrap();
+        validate_sale_phases(&[]).unwrap();
+
+        assert_eq!(active_phase(&phases, &Timestamp::from_seconds(999)), None);
+        assert_eq!(active_phase(&phases, &Timestamp::from_seconds(1000)), Some(&phases[0]));
+        assert_eq!(active_phase(&phases, &Timestamp::from_seconds(2000)), Some(&phases[0]));
+        assert_eq!(active_phase(&phases, &Timestamp::from_seconds(2001)), Some(&phases[1]));
+        assert_eq!(active_phase(&phases, &Timestamp::from_seconds(3001)), None);
+
+        let mut err_string;
+
+        err_string = validate_sale_phases(&[phase("", 1000, 2000)]).unwrap_err().to_string();
+        assert!(err_string.contains("Sale phase name cannot be empty"));
+
+        err_string = validate_sale_phases(&[phase("public", 1000, 2000), phase("public", 3000, 4000)]).unwrap_err().to_string();
+        assert!(err_string.contains("Duplicate sale phase name"));
+
+        let mut no_denom = phase("public", 1000, 2000);
+        no_denom.denom = "".to_string();
+        err_string = validate_sale_phases(&[no_denom]).unwrap_err().to_string();
+        assert!(err_string.contains("denom cannot be empty"));
+
+        err_string = validate_sale_phases(&[phase("public", 2000, 2000)]).unwrap_err().to_string();
+        assert!(err_string.contains("must end after it starts"));
+
+        let mut bad_root = phase("allowlist", 1000, 2000);
+        bad_root.eligibility = PhaseEligibility::Allowlist { merkle_root: Some("not hex".to_string()) };
+        err_string = validate_sale_phases(&[bad_root]).unwrap_err().to_string();
+        assert!(err_string.contains("Sale phase (allowlist) merkle root is invalid"));
+
+        err_string = validate_sale_phases(&[phase("allowlist", 1000, 2000), phase("public", 2000, 3000)]).unwrap_err().to_string();
+        assert!(err_string.contains("Sale phase (public) must start after the previous phase (allowlist) ends"));
+    }
+
+    #[test]
+    fn dutch_auction_pricing() {
+        let mut auction = DutchAuction {
+            start_price: Uint128::new(1000),
+            floor_price: Uint128::new(100),
+            price_decrement: Uint128::new(200),
+            decrement_interval: 60,
+            start_timestamp: Uint128::new(1000),
+            rebates_enabled: false,
+        };
+
+        validate_dutch_auction(&auction).unwrap();
+
+        assert_eq!(dutch_auction_price(&auction, &Timestamp::from_seconds(500)), Uint128::new(1000));
+        assert_eq!(dutch_auction_price(&auction, &Timestamp::from_seconds(1059)), Uint128::new(1000));
+        assert_eq!(dutch_auction_price(&auction, &Timestamp::from_seconds(1060)), Uint128::new(800));
+        assert_eq!(dutch_auction_price(&auction, &Timestamp::from_seconds(1179)), Uint128::new(600));
+        assert_eq!(dutch_auction_price(&auction, &Timestamp::from_seconds(1300)), Uint128::new(100));
+        assert_eq!(dutch_auction_price(&auction, &Timestamp::from_nanos(u64::MAX)), Uint128::new(100));
+
+        // Clearing price is final at the floor, or at the last sale price once sold out
+        let mut store = cosmwasm_std::testing::MockStorage::new();
+        assert_eq!(auction_clearing_price(&store, &auction, None, &Timestamp::from_seconds(1060)).unwrap(), None);
+        assert_eq!(auction_clearing_price(&store, &auction, None, &Timestamp::from_seconds(1300)).unwrap(), Some(Uint128::new(100)));
+
+        save_item_wrapped(&mut store, &TOKEN_INDEX, &5).unwrap();
+        save_item_wrapped(&mut store, &AUCTION_LAST_PRICE, &Uint128::new(800)).unwrap();
+        assert_eq!(auction_clearing_price(&store, &auction, Some(6), &Timestamp::from_seconds(1060)).unwrap(), None);
+        assert_eq!(auction_clearing_price(&store, &auction, Some(5), &Timestamp::from_seconds(1060)).unwrap(), Some(Uint128::new(800)));
+
+        // Selling out above the floor keeps the last sale price after the floor time, and a fixed price is kept
+        assert_eq!(auction_clearing_price(&store, &auction, Some(5), &Timestamp::from_seconds(1300)).unwrap(), Some(Uint128::new(800)));
+        save_item_wrapped(&mut store, &AUCTION_CLEARING_PRICE, &Uint128::new(800)).unwrap();
+        assert_eq!(auction_clearing_price(&store, &auction, None, &Timestamp::from_seconds(1300)).unwrap(), Some(Uint128::new(800)));
+
+        let mut err_string;
+
+        auction.floor_price = Uint128::new(1001);
+        err_string = validate_dutch_auction(&auction).unwrap_err().to_string();
+        assert!(err_string.contains("Dutch auction floor price cannot be above the start price"));
+
+        auction.floor_price = Uint128::new(100);
+        auction.price_decrement = Uint128::zero();
+        err_string = validate_dutch_auction(&auction).unwrap_err().to_string();
+        assert!(err_string.contains("Dutch auction price decrement must be positive"));
+
+        auction.price_decrement = Uint128::new(200);
+        auction.decrement_interval = 0;
+        err_string = validate_dutch_auction(&auction).unwrap_err().to_string();
+        assert!(err_string.contains("Dutch auction decrement interval must be positive"));
+    }
+
+    #[test]
+    fn public_sale_validation() {
+        let api = MockApi::default();
+        let mut public_sale = PublicSaleSettings {
+            price: Uint128::new(100),
+            denom: "inj".to_string(),
+            start_timestamp: Uint128::new(1000),
+            end_timestamp: Some(Uint128::new(2000)),
+            base_uri: "https://example.com/".to_string(),
+            sale_recipient: "sale_recipient_addr".to_string(),
+        };
+
+        validate_public_sale(&api, &public_sale).unwrap();
+
+        public_sale.end_timestamp = Some(Uint128::new(1000));
+        let mut err_string = validate_public_sale(&api, &public_sale).unwrap_err().to_string();
+        assert!(err_string.contains("Public sale must end after it starts"));
+
+        public_sale.end_timestamp = None;
+        public_sale.denom = "".to_string();
+        err_string = validate_public_sale(&api, &public_sale).unwrap_err().to_string();
+        assert!(err_string.contains("Public sale denom cannot be empty"));
+
+        public_sale.denom = "inj".to_string();
+        public_sale.sale_recipient = "INVALID_ADDR".to_string();
+        err_string = validate_public_sale(&api, &public_sale).unwrap_err().to_string();
+        assert!(err_string.contains("Invalid public sale recipient"));
+    }
+}
 
-}
\ No newline at end of file
@@ -9,7 +9,9 @@ mod error;
 mod execute;
 mod helpers;
 mod lookup;
+mod merkle;
 mod query;
+mod signing;
 mod state;
 mod upgrades;
 
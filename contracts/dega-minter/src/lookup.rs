@@ -1,155 +1,129 @@
-// use cosmwasm_std::{Binary, ContractResult, CustomQuery, Deps, from_json, QueryRequest, StdError, StdResult, SystemResult, to_json_vec};
-// use serde::{Deserialize, Serialize};
-
-//
-// #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
-// #[serde(rename_all = "snake_case")]
-// pub(crate) enum DegaInjRoute {
-//     Auth,
-// }
-//
-// #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
-// #[serde(rename_all = "snake_case")]
-// pub(crate) struct DegaInjQueryWrapper {
-//     pub(crate) route: DegaInjRoute,
-//     pub(crate) query_data: DegaInjectiveQuery,
-// }
-//
-// /// InjectiveQuery is an override of QueryRequest::Custom to access Injective-specific modules
-// #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
-// #[serde(rename_all = "snake_case")]
-// pub(crate) enum DegaInjectiveQuery {
-//
-//     Account {
-//         address: String
-//     },
-// }
-//
-// impl CustomQuery for DegaInjQueryWrapper {}
-//
-// impl From<DegaInjectiveQuery> for QueryRequest<DegaInjectiveQuery> {
-//     fn from(msg: DegaInjectiveQuery) -> Self {
-//         QueryRequest::Custom(msg)
-//     }
-// }
-//
-// pub(crate) fn _query_account(deps: Deps, address: String) -> StdResult<Binary> {
-//
-//     // let res: GranterGrantsResponse = deps.querier.query(&request.into())?;
-//     //let res = deps.querier.query(&request.into())?;
-//     //Ok(res)
-//
-//     let query = QueryRequest::Custom(
-//         DegaInjQueryWrapper {
-//             route: DegaInjRoute::Auth,
-//             query_data: DegaInjectiveQuery::Account {
-//                 address: address.clone(),
-//             },
-//         }
-//     );
-//
-//     // Need to bring in prost for this to work so we can serialize to protobuf
-//     // let query =
-//     //     QueryRequest::Stargate {
-//     //         path: "/cosmos.auth.v1beta1.Query/AccountInfo".to_string(),
-//     //         data:
-//     //             to_json_binary(
-//     //                 &QueryAccountInfoRequest {
-//     //                     address: address.clone(),
-//     //                 }
-//     //             ).map_err(|serialize_err| {
-//     //                 StdError::generic_err(format!("Serializing QueryRequest: {serialize_err}"))
-//     //             }
-//     //             )?,
-//     //     };
-//
-//     //Err(StdError::generic_err("Intentional Error".to_string()))?;
-//
-//     let raw_query = to_json_vec(&query).map_err(|serialize_err| {
-//         StdError::generic_err(format!("Serializing QueryRequest: {serialize_err}"))
-//     })?;
-//
-//     // Contents of query call
-//     let response_result: StdResult<QueryAccountInfoResponse> = match deps.querier.raw_query(&raw_query) {
-//         SystemResult::Err(system_err) => Err(StdError::generic_err(format!(
-//             "Querier system error: {system_err}"
-//         ))),
-//         SystemResult::Ok(ContractResult::Err(contract_err)) => Err(StdError::generic_err(
-//             format!("Querier contract error: {contract_err}"),
-//         )),
-//         SystemResult::Ok(ContractResult::Ok(value)) => from_json(value),
-//     };
-//
-//     //let response_as_string: String = String::from(response);
-//
-//     //Ok(Binary(response_as_string.into_bytes()))
-//
-//     let response = response_result.map_err(|e|
-//         StdError::generic_err(format!("Error requesting address info: {}", e))
-//     )?;
-//
-//     let account_address = response.info.ok_or(
-//         StdError::generic_err(format!("Signer account for specified address not found: {}", address.clone()))
-//     )?;
-//     Ok(Binary(account_address.pub_key.ok_or(
-//         StdError::generic_err(format!("Signer pubkey for specified address not found: {}", address))
-//     )?.value))
-// }
-//
-// #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
-// pub(crate) struct QueryAccountInfoRequest {
-//     /// address is the account address string.
-//     //#[prost(string, tag = "1")]
-//     pub(crate) address: String,
-// }
-//
-// #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
-// pub(crate) struct QueryAccountInfoResponse {
-//     pub(crate) info: Option<BaseAccount>,
-// }
-//
-// #[allow(clippy::derive_partial_eq_without_eq)]
-// #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
-// pub(crate) struct BaseAccount {
-//
-//     pub(crate) address: String,
-//
-//     pub(crate) pub_key: Option<Any>,
-//
-//     pub(crate) account_number: u64,
-//
-//     pub(crate) sequence: u64,
-// }
-//
-// #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
-// pub(crate) struct Any {
-//     pub(crate) type_url: String,
-//     pub(crate) value: Vec<u8>,
-// }
-
-// fn get_pubkey_for_address(deps: &Deps, address: String) -> Result<Vec<u8>, StdError> {
-//     //let account_info_response = AuthQuerier::new(&deps.querier).account_info(address.clone()).map_err(|e| StdError::generic_err(format!("Error during query for account: {}", e)))?;
-//
-//     let query_request = QueryRequest::Stargate {
-//         path: "/cosmos.auth.v1beta1.Query/AccountInfo".to_string(),
-//         data: to_json_binary(&QueryAccountInfoRequest {
-//             address: address.clone(),
-//         }).map_err(|e|
-//             StdError::generic_err(format!("Error serializing binary for QueryAccountInfoRequest: {}", e))
-//         )?,
-//     };
-//
-//     let response = deps.querier.query::<QueryAccountInfoResponse>(&query_request)
-//         .map_err(|e|
-//             StdError::generic_err(format!("Error requesting address info: {}", address.clone()))
-//     )?;
-//
-//
-//     let account_address = response.info.ok_or(
-//         StdError::generic_err(format!("Signer account for specified address not found: {}", address.clone()))
-//     )?;
-//     Ok(account_address.pub_key.ok_or(
-//         StdError::generic_err(format!("Signer pubkey for specified address not found: {}", address))
-//     )?.value)
-// }
+use cosmwasm_std::{Binary, Deps, QueryRequest, StdError, StdResult};
+use prost::Message;
+use serde::{Deserialize, Serialize};
 
+pub(crate) const ACCOUNT_INFO_QUERY_PATH: &str = "/cosmos.auth.v1beta1.Query/AccountInfo";
+
+pub(crate) const SECP256K1_PUB_KEY_TYPE: &str = "/cosmos.crypto.secp256k1.PubKey";
+pub(crate) const ETH_SECP256K1_PUB_KEY_TYPE: &str = "/injective.crypto.v1beta1.ethsecp256k1.PubKey";
+
+// Request is sent protobuf encoded, as required for stargate queries
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct QueryAccountInfoRequest {
+    #[prost(string, tag = "1")]
+    pub(crate) address: String,
+}
+
+// Responses to whitelisted stargate queries are returned by the chain as proto3 JSON
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct QueryAccountInfoResponse {
+    pub(crate) info: Option<BaseAccount>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BaseAccount {
+    pub(crate) address: String,
+    pub(crate) pub_key: Option<PubKeyAny>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct PubKeyAny {
+    #[serde(rename = "@type")]
+    pub(crate) type_url: String,
+    pub(crate) key: Binary,
+}
+
+pub(crate) fn encode_account_info_request(address: &str) -> Binary {
+    let request = QueryAccountInfoRequest {
+        address: address.to_string(),
+    };
+
+    Binary(request.encode_to_vec())
+}
+
+// Returns the base64 encoded compressed public key of the account at the provided address
+pub(crate) fn query_pub_key_for_address(deps: Deps, address: &str) -> StdResult<String> {
+
+    let query_request = QueryRequest::Stargate {
+        path: ACCOUNT_INFO_QUERY_PATH.to_string(),
+        data: encode_account_info_request(address),
+    };
+
+    let response: QueryAccountInfoResponse = deps.querier.query(&query_request)
+        .map_err(|e| StdError::generic_err(format!("Error requesting account info for address ({}): {}", address, e)))?;
+
+    let account = response.info.ok_or_else(||
+        StdError::generic_err(format!("Signer account for specified address not found: {}", address))
+    )?;
+
+    let pub_key = account.pub_key.ok_or_else(||
+        StdError::generic_err(format!("Signer pubkey for specified address not found: {}", address))
+    )?;
+
+    match pub_key.type_url.as_str() {
+        SECP256K1_PUB_KEY_TYPE | ETH_SECP256K1_PUB_KEY_TYPE => Ok(pub_key.key.to_base64()),
+        _ => Err(StdError::generic_err(
+            format!("Unsupported pubkey type for signer address ({}): {}", address, pub_key.type_url)
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+    use crate::test_helpers::{get_signer_pub_key, mock_dependencies_with_accounts, SIGNER_ACCOUNT_ADDR};
+    use k256::ecdsa::SigningKey;
+    use k256::elliptic_curve::rand_core::OsRng;
+    use super::*;
+
+    #[test]
+    fn request_encoding() {
+        let encoded = encode_account_info_request(SIGNER_ACCOUNT_ADDR);
+        let decoded = QueryAccountInfoRequest::decode(encoded.as_slice()).unwrap();
+        assert_eq!(decoded.address, SIGNER_ACCOUNT_ADDR.to_string());
+    }
+
+    #[test]
+    fn pub_key_lookup() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        // Both cosmos and injective (ethsecp256k1) key types resolve to the compressed key
+        for key_type in [SECP256K1_PUB_KEY_TYPE, ETH_SECP256K1_PUB_KEY_TYPE] {
+            let deps = mock_dependencies_with_accounts(vec![
+                (SIGNER_ACCOUNT_ADDR, Some((key_type, signer_pub_key.clone()))),
+            ]);
+            assert_eq!(query_pub_key_for_address(deps.as_ref(), SIGNER_ACCOUNT_ADDR).unwrap(), signer_pub_key);
+        }
+    }
+
+    #[test]
+    fn pub_key_lookup_errors() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let mut err_string;
+
+        // Account not found
+        let deps = mock_dependencies_with_accounts(vec![]);
+        err_string = query_pub_key_for_address(deps.as_ref(), SIGNER_ACCOUNT_ADDR).unwrap_err().to_string();
+        assert!(err_string.contains("Signer account for specified address not found"));
+
+        // Account without a pubkey (has never signed a transaction)
+        let deps = mock_dependencies_with_accounts(vec![(SIGNER_ACCOUNT_ADDR, None)]);
+        err_string = query_pub_key_for_address(deps.as_ref(), SIGNER_ACCOUNT_ADDR).unwrap_err().to_string();
+        assert!(err_string.contains("Signer pubkey for specified address not found"));
+
+        // Unsupported key type
+        let deps = mock_dependencies_with_accounts(vec![
+            (SIGNER_ACCOUNT_ADDR, Some(("/cosmos.crypto.ed25519.PubKey", signer_pub_key.clone()))),
+        ]);
+        err_string = query_pub_key_for_address(deps.as_ref(), SIGNER_ACCOUNT_ADDR).unwrap_err().to_string();
+        assert!(err_string.contains("Unsupported pubkey type for signer address"));
+
+        // Querier without stargate support
+        let deps = mock_dependencies();
+        err_string = query_pub_key_for_address(deps.as_ref(), SIGNER_ACCOUNT_ADDR).unwrap_err().to_string();
+        assert!(err_string.contains("Error requesting account info for address"));
+    }
+}
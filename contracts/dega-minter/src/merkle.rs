@@ -0,0 +1,88 @@
+use cosmwasm_std::{StdError, StdResult};
+use sha2::{Sha256, digest::Digest};
+use dega_inj::minter::{PhaseEligibility, SalePhase};
+
+// Leaf committing an address to the number of tokens it may mint: SHA-256 of the address followed by the
+// allowance as a big endian u32
+pub(crate) fn allowlist_leaf(address: &str, allowance: u32) -> [u8; 32] {
+    let mut leaf = address.as_bytes().to_vec();
+    leaf.extend_from_slice(&allowance.to_be_bytes());
+    Sha256::digest(leaf).into()
+}
+
+// Parent of two nodes, hashed in sorted order so proofs don't need to carry the side of each sibling
+pub(crate) fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update(first);
+    hasher.update(second);
+    hasher.finalize().into()
+}
+
+pub(crate) fn parse_merkle_hash(hash_hex: &str) -> StdResult<[u8; 32]> {
+    let hash_bytes = hex::decode(hash_hex)
+        .map_err(|e| StdError::generic_err(format!("Invalid merkle hash, not hex encoded ({}): {}", hash_hex, e)))?;
+
+    hash_bytes.try_into()
+        .map_err(|_| StdError::generic_err(format!("Invalid merkle hash, not 32 bytes long: {}", hash_hex)))
+}
+
+// Folds the hex encoded sibling hashes of the proof into the leaf and compares the result against the root
+pub(crate) fn verify_merkle_proof(root_hex: &str, leaf: [u8; 32], proof: &[String]) -> StdResult<bool> {
+    let root = parse_merkle_hash(root_hex)?;
+
+    let mut computed = leaf;
+    for sibling_hex in proof {
+        computed = hash_pair(&computed, &parse_merkle_hash(sibling_hex)?);
+    }
+
+    Ok(computed == root)
+}
+
+// Root allowlist proofs are checked against: the sale phase's own root, falling back to the minter's
+pub(crate) fn allowlist_root(minter_root: &Option<String>, sale_phase: Option<&SalePhase>) -> Option<String> {
+    match sale_phase.map(|phase| &phase.eligibility) {
+        Some(PhaseEligibility::Allowlist { merkle_root: Some(phase_root) }) => Some(phase_root.clone()),
+        _ => minter_root.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merkle_proofs() {
+        let leaves = [
+            allowlist_leaf("inj1first", 1),
+            allowlist_leaf("inj1second", 2),
+            allowlist_leaf("inj1third", 3),
+        ];
+
+        // Three leaf tree, the odd leaf is paired with itself
+        let left = hash_pair(&leaves[0], &leaves[1]);
+        let right = hash_pair(&leaves[2], &leaves[2]);
+        let root_hex = hex::encode(hash_pair(&left, &right));
+
+        let proof = vec![hex::encode(leaves[0]), hex::encode(right)];
+        assert!(verify_merkle_proof(&root_hex, leaves[1], &proof).unwrap());
+
+        let proof = vec![hex::encode(leaves[2]), hex::encode(left)];
+        assert!(verify_merkle_proof(&root_hex, leaves[2], &proof).unwrap());
+
+        // The allowance is part of the leaf
+        let proof = vec![hex::encode(leaves[0]), hex::encode(right)];
+        assert!(!verify_merkle_proof(&root_hex, allowlist_leaf("inj1second", 5), &proof).unwrap());
+
+        // Single leaf tree
+        assert!(verify_merkle_proof(&hex::encode(leaves[0]), leaves[0], &[]).unwrap());
+
+        let mut err_string;
+
+        err_string = verify_merkle_proof("not hex", leaves[0], &[]).unwrap_err().to_string();
+        assert!(err_string.contains("Invalid merkle hash, not hex encoded"));
+
+        err_string = verify_merkle_proof(&root_hex, leaves[0], &["1234".to_string()]).unwrap_err().to_string();
+        assert!(err_string.contains("Invalid merkle hash, not 32 bytes long"));
+    }
+}
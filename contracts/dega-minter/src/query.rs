@@ -1,9 +1,12 @@
-use cosmwasm_std::{Binary, Deps, Env, Order, StdError, StdResult, to_json_binary};
-use sha2::{Sha256, digest::Digest};
-use dega_inj::helpers::{load_item_wrapped, map_keys_wrapped, to_json_binary_wrapped};
-use dega_inj::minter::{AdminsResponse, CheckSigResponse, DegaMinterConfigResponse, QueryMsg, SignerSourceType, VerifiableMsg};
-use crate::helpers::verify_compressed_pub_key;
-use crate::state::{ADMIN_LIST, COLLECTION_ADDRESS, DEGA_MINTER_SETTINGS};
+use cosmwasm_std::{Addr, Binary, Deps, Env, Order, StdError, StdResult, to_json_binary, Uint128};
+use cw_storage_plus::Bound;
+use dega_inj::helpers::{load_item_wrapped, map_keys_wrapped, map_range_wrapped};
+use dega_inj::minter::{AdminsResponse, AllowlistProof, CheckAllowlistResponse, CheckSigResponse, CurrentPhaseResponse, CurrentPriceResponse, DegaMinterConfigResponse, MintCountResponse, PendingAdminActionsResponse, PhasesResponse, ProposalResponse, ProposalsResponse, ProposalStatus, QueryMsg, Role, RolesResponse, SignerKeyResponse, SignerSourceType, SignersResponse, SupplyResponse, UuidCountResponse, UuidsResponse, UuidStatus, UuidStatusResponse, VerifiableMsg};
+use crate::helpers::{active_phase, auction_clearing_price, dutch_auction_price, has_role, is_signer_active, PRIMARY_SIGNER_LABEL, query_limit, SIGNER_ADDRESS_LABEL, verify_compressed_pub_key};
+use crate::lookup::query_pub_key_for_address;
+use crate::merkle::{allowlist_leaf, allowlist_root, verify_merkle_proof};
+use crate::signing::{hash_message, inj_address_from_eth_address, parse_signer_address, recover_eth_address, split_signature};
+use crate::state::{ADMIN_ACTIONS, ADMIN_LIST, AUCTION_SALE, COLLECTION_ADDRESS, DEGA_MINTER_SETTINGS, MINT_COUNTS, Proposal, PROPOSALS, ROLES, SALE_PHASES, SIGNER_KEYS, TOKEN_INDEX, UUID_COUNT, UUID_REGISTRY, UuidRecord};
 
 
 pub fn run_query(
@@ -28,8 +31,23 @@ pub fn run_query(
             )
         },
         QueryMsg::Config {} => to_json_binary(&query_config(deps, env)?),
-        QueryMsg::Admins {} => to_json_binary(&query_admins(deps, env)?),
+        QueryMsg::Admins { start_after, limit } => to_json_binary(&query_admins(deps, env, start_after, limit)?),
         QueryMsg::IsAdmin { address } => to_json_binary(&query_is_admin(deps, env, address)?),
+        QueryMsg::Roles { address } => to_json_binary(&query_roles(deps, env, address)?),
+        QueryMsg::HasRole { address, role } => to_json_binary(&query_has_role(deps, env, address, role)?),
+        QueryMsg::Signers {} => to_json_binary(&query_signers(deps, env)?),
+        QueryMsg::Supply {} => to_json_binary(&query_supply(deps, env)?),
+        QueryMsg::MintCount { address } => to_json_binary(&query_mint_count(deps, env, address)?),
+        QueryMsg::CheckAllowlist { address, proof } => to_json_binary(&query_check_allowlist(deps, env, address, proof)?),
+        QueryMsg::Phases {} => to_json_binary(&query_phases(deps, env)?),
+        QueryMsg::CurrentPhase {} => to_json_binary(&query_current_phase(deps, env)?),
+        QueryMsg::CurrentPrice {} => to_json_binary(&query_current_price(deps, env)?),
+        QueryMsg::UuidStatus { uuid } => to_json_binary(&query_uuid_status(deps, env, uuid)?),
+        QueryMsg::Uuids { start_after, limit } => to_json_binary(&query_uuids(deps, env, start_after, limit)?),
+        QueryMsg::UuidCount {} => to_json_binary(&query_uuid_count(deps, env)?),
+        QueryMsg::PendingAdminActions {} => to_json_binary(&query_pending_admin_actions(deps, env)?),
+        QueryMsg::Proposal { id } => to_json_binary(&query_proposal(deps, env, id)?),
+        QueryMsg::Proposals {} => to_json_binary(&query_proposals(deps, env)?),
     }
 }
 
@@ -46,13 +64,15 @@ pub(crate) fn query_config(deps: Deps, _env: Env) -> StdResult<DegaMinterConfigR
     })
 }
 
-pub(crate) fn query_admins(deps: Deps, _env: Env) -> StdResult<AdminsResponse> {
+pub(crate) fn query_admins(deps: Deps, _env: Env, start_after: Option<String>, limit: Option<u32>) -> StdResult<AdminsResponse> {
 
     let mut admins: Vec<String> = vec![];
 
-    for admin_key in map_keys_wrapped(deps.storage, &ADMIN_LIST, None, None, Order::Ascending) {
+    let start = start_after.map(|address| Bound::exclusive(Addr::unchecked(address)));
+
+    for admin_key in map_keys_wrapped(deps.storage, &ADMIN_LIST, start, None, Order::Ascending).take(query_limit(limit)) {
         admins.push(
-            admin_key.map_err(|e| StdError::generic_err(format!("Error while loading admin key: {}", e)))?
+            admin_key.map_err(|e| StdError::generic_err(format!("Error while loading admin key: {}", e)))?.to_string()
         );
     }
 
@@ -61,75 +81,443 @@ pub(crate) fn query_admins(deps: Deps, _env: Env) -> StdResult<AdminsResponse> {
     })
 }
 
-pub fn query_check_sig(deps: Deps, _env: Env, message: VerifiableMsg, signature: String, signer_source: SignerSourceType) -> Result<CheckSigResponse, StdError> {
+// A signer is either identified by its public key, or by its address which is matched against the
+// address recovered from the signature, or failing that, resolved to a public key via the auth module
+enum SignerCandidate {
+    PubKey(String),
+    Address(String),
+}
 
-    let message_bytes = match message {
-        VerifiableMsg::String(msg) => msg.into_bytes(),
-        VerifiableMsg::MintRequest(msg) => {
-            let msg_binary = to_json_binary_wrapped(&msg)
-                .map_err(|e| StdError::generic_err(format!("Error during encode request to JSON: {}", e)))?;
-            msg_binary.to_vec()
-        }
-    };
+pub fn query_check_sig(deps: Deps, env: Env, message: VerifiableMsg, signature: String, signer_source: SignerSourceType) -> Result<CheckSigResponse, StdError> {
 
-    let hash_bytes = Sha256::digest(message_bytes);
-    let hash_hex_string = hex::encode(hash_bytes);
+    let settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+                                       .map_err(|e| StdError::generic_err(format!("Error getting dega minter settings: {}", e)))?;
+
+    let hash_bytes = hash_message(&settings.signature_scheme, &settings.mint_request_encoding, &message)?;
+    let hash_hex_string = hex::encode(&hash_bytes);
 
     let sig_binary = Binary::from_base64(&signature)
         .map_err(|e| StdError::generic_err(format!("Error during decode signature from base64: {}", e)))?;
-    let sig_bytes: &[u8] = sig_binary.as_slice();
+    let (sig_bytes, recovery_id) = split_signature(sig_binary.as_slice())?;
 
-    let pub_key_string = match signer_source {
-        SignerSourceType::PubKeyBinary(pub_key_string) => pub_key_string,
-        SignerSourceType::ConfigSignerPubKey => {
-            let settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
-                                               .map_err(|e| StdError::generic_err(format!("Error getting dega minter settings: {}", e)))?;
+    let mut error = None;
 
-            settings.signer_pub_key
+    let recovered_address = match recovery_id {
+        Some(recovery_id) => match recover_eth_address(deps.api, &hash_bytes, sig_bytes, recovery_id) {
+            Ok(address) => Some(address),
+            Err(e) => {
+                error = Some(e.to_string());
+                None
+            },
         },
+        None => None,
     };
 
-    let pub_key_bytes = verify_compressed_pub_key(pub_key_string)?;
+    let checks_active_signers = signer_source == SignerSourceType::ActiveSigners;
+
+    let signers: Vec<(Option<String>, SignerCandidate)> = match signer_source {
+        SignerSourceType::PubKeyBinary(pub_key_string) => vec![(None, SignerCandidate::PubKey(pub_key_string))],
+        SignerSourceType::ConfigSignerPubKey => {
+            vec![(Some(PRIMARY_SIGNER_LABEL.to_string()), SignerCandidate::PubKey(settings.signer_pub_key))]
+        },
+        SignerSourceType::ActiveSigners => {
+            let mut signers = vec![(Some(PRIMARY_SIGNER_LABEL.to_string()), SignerCandidate::PubKey(settings.signer_pub_key))];
+
+            if let Some(signer_address) = settings.signer_address {
+                signers.push((Some(SIGNER_ADDRESS_LABEL.to_string()), SignerCandidate::Address(signer_address)));
+            }
+
+            for signer_entry in map_range_wrapped(deps.storage, &SIGNER_KEYS, None, None, Order::Ascending) {
+                let (label, signer) = signer_entry
+                    .map_err(|e| StdError::generic_err(format!("Error while loading signer key: {}", e)))?;
+
+                if is_signer_active(&signer, &env.block.time) {
+                    signers.push((Some(label), SignerCandidate::PubKey(signer.pub_key)));
+                }
+            }
+
+            signers
+        },
+        SignerSourceType::ConfigSignerAddress => {
+            let signer_address = settings.signer_address
+                .ok_or_else(|| StdError::generic_err("No signer address configured"))?;
+
+            vec![(Some(SIGNER_ADDRESS_LABEL.to_string()), SignerCandidate::Address(signer_address))]
+        },
+        SignerSourceType::Address(address) => {
+            let signer_address = if address.starts_with("0x") {
+                inj_address_from_eth_address(&parse_signer_address(&address)?)
+            } else {
+                deps.api.addr_validate(&address)
+                    .map_err(|e| StdError::generic_err(format!("Invalid signer address: {}", e)))?;
+                address
+            };
+
+            vec![(None, SignerCandidate::Address(signer_address))]
+        },
+    };
 
-    let verify_result = deps.api.secp256k1_verify(
-        &hash_bytes,
-        sig_bytes,
-        pub_key_bytes.as_slice(),
-    ).map_err(
-        |e| StdError::generic_err(format!("Error during secp256k1_verify: {}", e))
-    );
+    let verify_pub_key = |pub_key_string: String| -> StdResult<StdResult<bool>> {
+        let pub_key_bytes = verify_compressed_pub_key(pub_key_string)?;
 
-    let (is_valid, error) = match verify_result {
-        Ok(result) => (result, None),
-        Err(e) => (false, Some(e.to_string())),
+        Ok(deps.api.secp256k1_verify(
+            &hash_bytes,
+            sig_bytes,
+            pub_key_bytes.as_slice(),
+        ).map_err(
+            |e| StdError::generic_err(format!("Error during secp256k1_verify: {}", e))
+        ))
     };
 
+    for (signer_label, signer) in signers {
+        let candidate_result = match (signer, &recovered_address) {
+            (SignerCandidate::PubKey(pub_key_string), _) => verify_pub_key(pub_key_string),
+            (SignerCandidate::Address(address), Some(recovered_address)) => {
+                parse_signer_address(&address).map(|address| Ok(address == *recovered_address))
+            },
+            (SignerCandidate::Address(address), None) => {
+                query_pub_key_for_address(deps, &address).and_then(verify_pub_key)
+            },
+        };
+
+        // A single signer which can't be checked fails the query, among the active signers the next one is tried
+        let verify_result = match candidate_result {
+            Ok(verify_result) => verify_result,
+            Err(e) if checks_active_signers => Err(e),
+            Err(e) => return Err(e),
+        };
+
+        match verify_result {
+            Ok(true) => {
+                return Ok(CheckSigResponse {
+                    is_valid: true,
+                    message_hash_hex: hash_hex_string,
+                    error: None,
+                    signer_label,
+                    recovered_address: recovered_address.as_ref().map(inj_address_from_eth_address),
+                });
+            },
+            Ok(false) => {},
+            Err(e) => {
+                if error.is_none() {
+                    error = Some(e.to_string());
+                }
+            },
+        }
+    }
+
     Ok(CheckSigResponse {
-        is_valid,
+        is_valid: false,
         message_hash_hex: hash_hex_string,
         error,
+        signer_label: None,
+        recovered_address: recovered_address.as_ref().map(inj_address_from_eth_address),
+    })
+}
+
+pub(crate) fn query_signers(deps: Deps, env: Env) -> StdResult<SignersResponse> {
+
+    let mut signers: Vec<SignerKeyResponse> = vec![];
+
+    for signer_entry in map_range_wrapped(deps.storage, &SIGNER_KEYS, None, None, Order::Ascending) {
+        let (label, signer) = signer_entry
+            .map_err(|e| StdError::generic_err(format!("Error while loading signer key: {}", e)))?;
+
+        let is_active = is_signer_active(&signer, &env.block.time);
+
+        signers.push(SignerKeyResponse {
+            label,
+            signer,
+            is_active,
+        });
+    }
+
+    Ok(SignersResponse {
+        signers
+    })
+}
+
+pub(crate) fn query_supply(deps: Deps, _env: Env) -> StdResult<SupplyResponse> {
+    let settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+                                       .map_err(|e| StdError::generic_err(format!("Error getting dega minter settings: {}", e)))?;
+
+    let minted = TOKEN_INDEX.may_load(deps.storage)
+        .map_err(|e| StdError::generic_err(format!("Error getting token index: {}", e)))?
+        .unwrap_or_default();
+
+    Ok(SupplyResponse {
+        max_supply: settings.max_supply,
+        minted,
+        remaining: settings.max_supply.map(|max_supply| max_supply.saturating_sub(minted)),
+    })
+}
+
+pub(crate) fn query_mint_count(deps: Deps, _env: Env, address: String) -> StdResult<MintCountResponse> {
+    let settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+                                       .map_err(|e| StdError::generic_err(format!("Error getting dega minter settings: {}", e)))?;
+
+    let addr = deps.api.addr_validate(&address)
+        .map_err(|e| StdError::generic_err(format!("Invalid address: {}", e)))?;
+
+    let count = MINT_COUNTS.may_load(deps.storage, addr)
+        .map_err(|e| StdError::generic_err(format!("Error getting wallet mint count: {}", e)))?
+        .unwrap_or_default();
+
+    Ok(MintCountResponse {
+        address,
+        count,
+        limit: settings.max_mints_per_wallet,
+    })
+}
+
+pub(crate) fn query_uuid_status(deps: Deps, _env: Env, uuid: String) -> StdResult<UuidStatusResponse> {
+    let uuid_record = UUID_REGISTRY.may_load(deps.storage, uuid.clone())
+        .map_err(|e| StdError::generic_err(format!("Error getting UUID registry entry: {}", e)))?;
+
+    Ok(uuid_status_response(uuid, uuid_record))
+}
+
+pub(crate) fn query_uuids(deps: Deps, _env: Env, start_after: Option<String>, limit: Option<u32>) -> StdResult<UuidsResponse> {
+
+    let mut uuids = vec![];
+
+    let start = start_after.map(Bound::exclusive);
+
+    for uuid_entry in map_range_wrapped(deps.storage, &UUID_REGISTRY, start, None, Order::Ascending).take(query_limit(limit)) {
+        let (uuid, uuid_record) = uuid_entry
+            .map_err(|e| StdError::generic_err(format!("Error while loading UUID registry entry: {}", e)))?;
+
+        uuids.push(uuid_status_response(uuid, Some(uuid_record)));
+    }
+
+    Ok(UuidsResponse {
+        uuids,
+    })
+}
+
+pub(crate) fn query_uuid_count(deps: Deps, _env: Env) -> StdResult<UuidCountResponse> {
+
+    let count = UUID_COUNT.may_load(deps.storage)
+        .map_err(|e| StdError::generic_err(format!("Error getting UUID count: {}", e)))?
+        .unwrap_or_default();
+
+    Ok(UuidCountResponse {
+        count,
+    })
+}
+
+fn uuid_status_response(uuid: String, uuid_record: Option<UuidRecord>) -> UuidStatusResponse {
+    let (status, token_ids) = match uuid_record {
+        Some(uuid_record) if uuid_record.revoked => (UuidStatus::Revoked, vec![]),
+        Some(uuid_record) => (UuidStatus::Used, uuid_record.token_ids),
+        None => (UuidStatus::Unused, vec![]),
+    };
+
+    UuidStatusResponse {
+        uuid,
+        status,
+        token_ids,
+    }
+}
+
+pub(crate) fn query_pending_admin_actions(deps: Deps, _env: Env) -> StdResult<PendingAdminActionsResponse> {
+
+    let mut actions = vec![];
+
+    for action_entry in map_range_wrapped(deps.storage, &ADMIN_ACTIONS, None, None, Order::Ascending) {
+        let (_, pending_action) = action_entry
+            .map_err(|e| StdError::generic_err(format!("Error while loading admin action: {}", e)))?;
+
+        actions.push(pending_action);
+    }
+
+    Ok(PendingAdminActionsResponse {
+        actions
+    })
+}
+
+pub(crate) fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<ProposalResponse> {
+    let proposal = PROPOSALS.may_load(deps.storage, id)
+        .map_err(|e| StdError::generic_err(format!("Error getting proposal: {}", e)))?
+        .ok_or_else(|| StdError::generic_err(format!("No proposal with id: {}", id)))?;
+
+    Ok(proposal_response(&env, id, proposal))
+}
+
+pub(crate) fn query_proposals(deps: Deps, env: Env) -> StdResult<ProposalsResponse> {
+
+    let mut proposals = vec![];
+
+    for proposal_entry in map_range_wrapped(deps.storage, &PROPOSALS, None, None, Order::Ascending) {
+        let (id, proposal) = proposal_entry
+            .map_err(|e| StdError::generic_err(format!("Error while loading proposal: {}", e)))?;
+
+        let response = proposal_response(&env, id, proposal);
+
+        if response.status == ProposalStatus::Open {
+            proposals.push(response);
+        }
+    }
+
+    Ok(ProposalsResponse {
+        proposals
+    })
+}
+
+fn proposal_response(env: &Env, id: u64, proposal: Proposal) -> ProposalResponse {
+    let status = if proposal.executed {
+        ProposalStatus::Executed
+    } else if Uint128::from(env.block.time.seconds()) >= proposal.expiration {
+        ProposalStatus::Expired
+    } else {
+        ProposalStatus::Open
+    };
+
+    ProposalResponse {
+        id,
+        action: proposal.action,
+        proposer: proposal.proposer.to_string(),
+        approvals: proposal.approvals.iter().map(|address| address.to_string()).collect(),
+        expiration: proposal.expiration,
+        status,
+    }
+}
+
+pub(crate) fn query_check_allowlist(deps: Deps, env: Env, address: String, proof: AllowlistProof) -> StdResult<CheckAllowlistResponse> {
+
+    let settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+                                       .map_err(|e| StdError::generic_err(format!("Error getting dega minter settings: {}", e)))?;
+
+    let phases = query_phases(deps, env.clone())?.phases;
+    let merkle_root = allowlist_root(&settings.allowlist_merkle_root, active_phase(&phases, &env.block.time));
+
+    let leaf = allowlist_leaf(&address, proof.allowance);
+
+    let (is_valid, error) = match &merkle_root {
+        Some(merkle_root) => (verify_merkle_proof(merkle_root, leaf, &proof.proof)?, None),
+        None => (false, Some("No allowlist merkle root configured".to_string())),
+    };
+
+    Ok(CheckAllowlistResponse {
+        is_valid,
+        leaf_hash_hex: hex::encode(leaf),
+        merkle_root,
+        error,
+    })
+}
+
+pub(crate) fn query_phases(deps: Deps, _env: Env) -> StdResult<PhasesResponse> {
+    let phases = SALE_PHASES.may_load(deps.storage)
+        .map_err(|e| StdError::generic_err(format!("Error getting sale phases: {}", e)))?
+        .unwrap_or_default();
+
+    Ok(PhasesResponse {
+        phases,
+    })
+}
+
+pub(crate) fn query_current_phase(deps: Deps, env: Env) -> StdResult<CurrentPhaseResponse> {
+    let phases = query_phases(deps, env.clone())?.phases;
+
+    Ok(CurrentPhaseResponse {
+        phase: active_phase(&phases, &env.block.time).cloned(),
+    })
+}
+
+// Price of a public mint at the block time: the active phase price, or else the dutch auction or public sale price
+pub(crate) fn query_current_price(deps: Deps, env: Env) -> StdResult<CurrentPriceResponse> {
+    let settings = load_item_wrapped(deps.storage, &DEGA_MINTER_SETTINGS)
+                                       .map_err(|e| StdError::generic_err(format!("Error getting dega minter settings: {}", e)))?;
+
+    let auction_clearing_price = match &settings.dutch_auction {
+        Some(dutch_auction) => auction_clearing_price(deps.storage, dutch_auction, settings.max_supply, &env.block.time)
+            .map_err(|e| StdError::generic_err(format!("Error calculating auction clearing price: {}", e)))?,
+        None => None,
+    };
+
+    if let Some(sale_phase) = query_current_phase(deps, env.clone())?.phase {
+        return Ok(CurrentPriceResponse {
+            price: sale_phase.price,
+            denom: sale_phase.denom,
+            auction_clearing_price,
+        });
+    }
+
+    let public_sale = settings.public_sale
+        .ok_or_else(|| StdError::generic_err("Public sale is not enabled"))?;
+
+    let price = settings.dutch_auction.as_ref()
+        .map_or(public_sale.price, |dutch_auction| dutch_auction_price(dutch_auction, &env.block.time));
+
+    // A dutch auction keeps selling in the denom of its first sale
+    let auction_sale = match &settings.dutch_auction {
+        Some(_) => AUCTION_SALE.may_load(deps.storage)
+            .map_err(|e| StdError::generic_err(format!("Error getting auction sale: {}", e)))?,
+        None => None,
+    };
+
+    Ok(CurrentPriceResponse {
+        price,
+        denom: auction_sale.map_or(public_sale.denom, |auction_sale| auction_sale.denom),
+        auction_clearing_price,
     })
 }
 
 pub(crate) fn query_is_admin(deps: Deps, _env: Env, address: String) -> StdResult<bool> {
 
-    deps.api.addr_validate(&address)
+    let address = deps.api.addr_validate(&address)
         .map_err(|e| StdError::generic_err(format!("Invalid address: {}", e)))?;
 
     Ok(ADMIN_LIST.has(deps.storage, address))
 }
 
+pub(crate) fn query_roles(deps: Deps, _env: Env, address: String) -> StdResult<RolesResponse> {
+
+    let admin_address = deps.api.addr_validate(&address)
+        .map_err(|e| StdError::generic_err(format!("Invalid address: {}", e)))?;
+
+    let mut roles = vec![];
+
+    if ADMIN_LIST.has(deps.storage, admin_address.clone()) {
+        roles.push(Role::SuperAdmin);
+    }
+
+    let granted_roles = ROLES.may_load(deps.storage, admin_address)
+        .map_err(|e| StdError::generic_err(format!("Error getting roles: {}", e)))?
+        .unwrap_or_default();
+
+    roles.extend(granted_roles);
+
+    Ok(RolesResponse {
+        address,
+        roles,
+    })
+}
+
+pub(crate) fn query_has_role(deps: Deps, _env: Env, address: String, role: Role) -> StdResult<bool> {
+
+    let address = deps.api.addr_validate(&address)
+        .map_err(|e| StdError::generic_err(format!("Invalid address: {}", e)))?;
+
+    has_role(deps.storage, &address, &role)
+        .map_err(|e| StdError::generic_err(format!("Error getting roles: {}", e)))
+}
+
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{StdError, to_json_binary};
+    use cosmwasm_std::{Binary, Deps, StdError, Timestamp, to_json_binary, Uint128};
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use k256::ecdsa::SigningKey;
     use k256::elliptic_curve::rand_core::OsRng;
-    use dega_inj::minter::{CheckSigResponse, QueryMsg, SignerSourceType, VerifiableMsg};
+    use sha2::{Digest, Sha256};
+    use dega_inj::minter::{AllowlistProof, CheckAllowlistResponse, CheckSigResponse, MintRequestEncoding, QueryMsg, SignatureScheme, SignerKeyInfo, SignerSourceType, SignersResponse, VerifiableMsg};
     use dega_inj::test_helpers::{add_load_error_item, add_load_error_map, clear_load_error_items, set_binary_for_json_error};
-    use crate::query::{query_check_sig, run_query};
-    use crate::state::{ADMIN_LIST, COLLECTION_ADDRESS, DEGA_MINTER_SETTINGS};
-    use crate::test_helpers::{get_inj_wei_from_kilo_inj, get_signer_pub_key, query_typed, sign_mint_request, template_mint_msg, template_minter};
+    use crate::query::{query_check_sig, query_signers, run_query};
+    use crate::state::{ADMIN_LIST, COLLECTION_ADDRESS, DEGA_MINTER_SETTINGS, SIGNER_KEYS, UUID_REGISTRY};
+    use crate::lookup::ETH_SECP256K1_PUB_KEY_TYPE;
+    use crate::merkle::allowlist_leaf;
+    use crate::signing::parse_signer_address;
+    use crate::test_helpers::{BUYER_ADDR, build_allowlist, get_inj_wei_from_kilo_inj, get_signer_inj_address, get_signer_pub_key, INVALID_ADDR, mock_dependencies_with_accounts, NORMAL_USER_ADDR, query_typed, sign_mint_request, sign_mint_request_with_scheme, SIGNER_ACCOUNT_ADDR, template_instantiate_msg, template_mint_msg, template_minter, template_minter_via_msg, template_reply_msg};
     #[test]
     fn check_sig_string() {
         // Create a first keypair and store the pubkey in the minter
@@ -263,11 +651,20 @@ mod tests {
         deps = mock_dependencies();
         template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
         add_load_error_map(&ADMIN_LIST);
-        err_msg = run_query(deps.as_ref(), mock_env(), QueryMsg::Admins {})
+        err_msg = run_query(deps.as_ref(), mock_env(), QueryMsg::Admins { start_after: None, limit: None })
             .unwrap_err().to_string();
         assert!(err_msg.contains("Error while loading admin key"));
         clear_load_error_items();
 
+        // Error during uuids and unable to load the UUID registry
+        deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+        add_load_error_map(&UUID_REGISTRY);
+        err_msg = run_query(deps.as_ref(), mock_env(), QueryMsg::Uuids { start_after: None, limit: None })
+            .unwrap_err().to_string();
+        assert!(err_msg.contains("Error while loading UUID registry entry"));
+        clear_load_error_items();
+
         // Error during check_sig and unable to serialize the mint request
         deps = mock_dependencies();
         set_binary_for_json_error(Some(to_json_binary(&mint_msg).unwrap()));
@@ -311,4 +708,332 @@ mod tests {
         }).unwrap_err().to_string();
         assert!(err_msg.contains("Invalid address"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn check_sig_active_signers() {
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        let signing_key_two = SigningKey::random(&mut OsRng);
+        let signer_pub_key_two = get_signer_pub_key(&signing_key_two);
+
+        let env = mock_env();
+        let now = env.block.time.seconds();
+
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+        let mint_msg = template_mint_msg(&env, price_wei);
+        let mint_msg_wrapped = VerifiableMsg::MintRequest(mint_msg.clone());
+        let mint_msg_sig_one = sign_mint_request(signing_key_one, mint_msg.clone());
+        let mint_msg_sig_two = sign_mint_request(signing_key_two, mint_msg.clone());
+
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        SIGNER_KEYS.save(deps.as_mut().storage, "backend-2".to_string(), &SignerKeyInfo {
+            pub_key: signer_pub_key_two.clone(),
+            activation_timestamp: None,
+            expiration_timestamp: Some(Uint128::from(now + 100)),
+        }).unwrap();
+
+        // The primary key reports its label
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(),
+                                       mint_msg_sig_one.clone(), SignerSourceType::ActiveSigners).unwrap();
+        assert!(response.is_valid);
+        assert_eq!(response.signer_label, Some("primary".to_string()));
+
+        // The additional key reports its label
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(),
+                                       mint_msg_sig_two.clone(), SignerSourceType::ActiveSigners).unwrap();
+        assert!(response.is_valid);
+        assert_eq!(response.signer_label, Some("backend-2".to_string()));
+
+        // The additional key is not the config key
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(),
+                                       mint_msg_sig_two.clone(), SignerSourceType::ConfigSignerPubKey).unwrap();
+        assert!(!response.is_valid);
+        assert_eq!(response.signer_label, None);
+
+        // Expired keys no longer match
+        let mut later_env = env.clone();
+        later_env.block.time = Timestamp::from_seconds(now + 101);
+        let response = query_check_sig(deps.as_ref(), later_env.clone(), mint_msg_wrapped.clone(),
+                                       mint_msg_sig_two.clone(), SignerSourceType::ActiveSigners).unwrap();
+        assert!(!response.is_valid);
+
+        // Signers query reports activity at the current block time
+        let signers: SignersResponse = query_typed(deps.as_ref(), QueryMsg::Signers {}).unwrap();
+        assert_eq!(signers.signers.len(), 1);
+        assert_eq!(signers.signers[0].label, "backend-2".to_string());
+        assert!(signers.signers[0].is_active);
+        let signers = query_signers(deps.as_ref(), later_env).unwrap();
+        assert!(!signers.signers[0].is_active);
+
+        // Errors loading the signer keys
+        add_load_error_map(&SIGNER_KEYS);
+        let err_msg = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(),
+                                      mint_msg_sig_two.clone(), SignerSourceType::ActiveSigners).unwrap_err().to_string();
+        assert!(err_msg.contains("Error while loading signer key"));
+        let err_msg = run_query(deps.as_ref(), env.clone(), QueryMsg::Signers {}).unwrap_err().to_string();
+        assert!(err_msg.contains("Error while loading signer key"));
+        clear_load_error_items();
+
+        add_load_error_item(&DEGA_MINTER_SETTINGS);
+        let err_msg = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(),
+                                      mint_msg_sig_two.clone(), SignerSourceType::ActiveSigners).unwrap_err().to_string();
+        assert!(err_msg.contains("Error getting dega minter settings"));
+        clear_load_error_items();
+    }
+
+    #[test]
+    fn check_sig_signer_address() {
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        // Key held by the account looked up by address
+        let address_signing_key = SigningKey::random(&mut OsRng);
+        let address_signer_pub_key = get_signer_pub_key(&address_signing_key);
+
+        let env = mock_env();
+        let price_wei = get_inj_wei_from_kilo_inj(100);
+        let mint_msg = template_mint_msg(&env, price_wei);
+        let mint_msg_wrapped = VerifiableMsg::MintRequest(mint_msg.clone());
+        let address_sig = sign_mint_request(address_signing_key, mint_msg.clone());
+
+        let mut deps = mock_dependencies_with_accounts(vec![
+            (SIGNER_ACCOUNT_ADDR, Some((ETH_SECP256K1_PUB_KEY_TYPE, address_signer_pub_key.clone()))),
+        ]);
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Checking against a provided address
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                       SignerSourceType::Address(SIGNER_ACCOUNT_ADDR.to_string())).unwrap();
+        assert!(response.is_valid);
+        assert_eq!(response.signer_label, None);
+
+        // No signer address configured yet
+        let err_msg = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                      SignerSourceType::ConfigSignerAddress).unwrap_err().to_string();
+        assert!(err_msg.contains("No signer address configured"));
+        assert!(!query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                 SignerSourceType::ActiveSigners).unwrap().is_valid);
+
+        // Configure the signer address
+        let mut settings = DEGA_MINTER_SETTINGS.load(&deps.storage).unwrap();
+        settings.signer_address = Some(SIGNER_ACCOUNT_ADDR.to_string());
+        DEGA_MINTER_SETTINGS.save(deps.as_mut().storage, &settings).unwrap();
+
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                       SignerSourceType::ConfigSignerAddress).unwrap();
+        assert!(response.is_valid);
+        assert_eq!(response.signer_label, Some("signer_address".to_string()));
+
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                       SignerSourceType::ActiveSigners).unwrap();
+        assert!(response.is_valid);
+        assert_eq!(response.signer_label, Some("signer_address".to_string()));
+
+        // Invalid address provided to check against
+        let err_msg = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                      SignerSourceType::Address(INVALID_ADDR.to_string())).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid signer address"));
+
+        // Address with no account
+        let err_msg = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                      SignerSourceType::Address(NORMAL_USER_ADDR.to_string())).unwrap_err().to_string();
+        assert!(err_msg.contains("Signer account for specified address not found"));
+
+        // Among the active signers, a configured address with no account doesn't stop the signer keys being checked
+        settings.signer_address = Some(NORMAL_USER_ADDR.to_string());
+        DEGA_MINTER_SETTINGS.save(deps.as_mut().storage, &settings).unwrap();
+
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                       SignerSourceType::ActiveSigners).unwrap();
+        assert!(!response.is_valid);
+        assert!(response.error.unwrap().contains("Signer account for specified address not found"));
+
+        SIGNER_KEYS.save(deps.as_mut().storage, "backend-2".to_string(), &SignerKeyInfo {
+            pub_key: address_signer_pub_key.clone(),
+            activation_timestamp: None,
+            expiration_timestamp: None,
+        }).unwrap();
+
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                       SignerSourceType::ActiveSigners).unwrap();
+        assert!(response.is_valid);
+        assert_eq!(response.signer_label, Some("backend-2".to_string()));
+    }
+
+    #[test]
+    fn check_sig_eth_schemes() {
+        let signing_key_one = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key_one);
+
+        let address_signing_key = SigningKey::random(&mut OsRng);
+        let signer_inj_address = get_signer_inj_address(&address_signing_key);
+
+        let env = mock_env();
+        let mint_msg = template_mint_msg(&env, get_inj_wei_from_kilo_inj(100));
+        let mint_msg_wrapped = VerifiableMsg::MintRequest(mint_msg.clone());
+
+        // No accounts on chain, signers are only identified through recovery
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        for scheme in [SignatureScheme::Sha256, SignatureScheme::Eip191, SignatureScheme::Eip712] {
+            let mut settings = DEGA_MINTER_SETTINGS.load(&deps.storage).unwrap();
+            settings.signature_scheme = scheme.clone();
+            settings.signer_address = None;
+            DEGA_MINTER_SETTINGS.save(deps.as_mut().storage, &settings).unwrap();
+
+            let primary_sig = sign_mint_request_with_scheme(&signing_key_one, mint_msg.clone(), &scheme, &MintRequestEncoding::Json);
+            let address_sig = sign_mint_request_with_scheme(&address_signing_key, mint_msg.clone(), &scheme, &MintRequestEncoding::Json);
+
+            // Pubkey verification accepts 65 byte signatures
+            let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), primary_sig.clone(),
+                                           SignerSourceType::ActiveSigners).unwrap();
+            assert!(response.is_valid);
+            assert_eq!(response.signer_label, Some("primary".to_string()));
+            assert_eq!(response.recovered_address, Some(get_signer_inj_address(&signing_key_one)));
+
+            // Signature made under a different scheme is not accepted
+            let other_scheme = if scheme == SignatureScheme::Sha256 { SignatureScheme::Eip191 } else { SignatureScheme::Sha256 };
+            let other_sig = sign_mint_request_with_scheme(&signing_key_one, mint_msg.clone(), &other_scheme, &MintRequestEncoding::Json);
+            assert!(!query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), other_sig,
+                                     SignerSourceType::ConfigSignerPubKey).unwrap().is_valid);
+
+            // Recovered address matched in both inj and 0x forms
+            let eth_address = format!("0x{}", hex::encode(parse_signer_address(&signer_inj_address).unwrap()));
+            for address in [signer_inj_address.clone(), eth_address] {
+                let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                               SignerSourceType::Address(address)).unwrap();
+                assert!(response.is_valid);
+                assert_eq!(response.recovered_address, Some(signer_inj_address.clone()));
+            }
+
+            // Recovered address doesn't match another address
+            let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), primary_sig.clone(),
+                                           SignerSourceType::Address(signer_inj_address.clone())).unwrap();
+            assert!(!response.is_valid);
+
+            assert!(!query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                     SignerSourceType::ActiveSigners).unwrap().is_valid);
+
+            // Configured signer address is matched without an account lookup
+            settings.signer_address = Some(signer_inj_address.clone());
+            DEGA_MINTER_SETTINGS.save(deps.as_mut().storage, &settings).unwrap();
+
+            let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), address_sig.clone(),
+                                           SignerSourceType::ActiveSigners).unwrap();
+            assert!(response.is_valid);
+            assert_eq!(response.signer_label, Some("signer_address".to_string()));
+        }
+
+        // String messages can't be signed as typed data
+        let err_msg = query_check_sig(deps.as_ref(), env.clone(), VerifiableMsg::String("message".to_string()),
+                                      base64::encode([0u8; 65]), SignerSourceType::ConfigSignerPubKey).unwrap_err().to_string();
+        assert!(err_msg.contains("EIP-712 signatures are only supported for mint requests"));
+
+        // Invalid recovery id
+        let mut bad_sig = Binary::from_base64(&sign_mint_request_with_scheme(&signing_key_one, mint_msg.clone(), &SignatureScheme::Eip712, &MintRequestEncoding::Json)).unwrap().to_vec();
+        bad_sig[64] = 5;
+        let err_msg = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), base64::encode(bad_sig),
+                                      SignerSourceType::ConfigSignerPubKey).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid signature recovery id"));
+
+        // Unrecoverable signature is reported as an error
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), base64::encode([0u8; 65]),
+                                       SignerSourceType::ConfigSignerPubKey).unwrap();
+        assert!(!response.is_valid);
+        assert!(response.recovered_address.is_none());
+        assert!(response.error.unwrap().contains("Error during secp256k1_recover_pubkey"));
+
+        // Invalid 0x address
+        let err_msg = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), base64::encode([0u8; 65]),
+                                      SignerSourceType::Address("0x1234".to_string())).unwrap_err().to_string();
+        assert!(err_msg.contains("Invalid signer address, not 20 bytes long"));
+    }
+
+    #[test]
+    fn check_allowlist() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let mut deps = mock_dependencies();
+        let mut instantiate_msg = template_instantiate_msg(signer_pub_key);
+        let (merkle_root, proofs) = build_allowlist(&[(BUYER_ADDR, 2), (NORMAL_USER_ADDR, 1)]);
+        template_minter_via_msg(&mut deps.as_mut(), &instantiate_msg, &template_reply_msg(), false).unwrap();
+
+        let check = |deps: Deps, address: &str, proof: &AllowlistProof| query_typed::<CheckAllowlistResponse>(deps, QueryMsg::CheckAllowlist {
+            address: address.to_string(),
+            proof: proof.clone(),
+        });
+
+        // No root configured
+        let response = check(deps.as_ref(), BUYER_ADDR, &proofs[0]).unwrap();
+        assert!(!response.is_valid);
+        assert_eq!(response.merkle_root, None);
+        assert_eq!(response.error, Some("No allowlist merkle root configured".to_string()));
+
+        let mut deps = mock_dependencies();
+        instantiate_msg.minter_params.dega_minter_settings.allowlist_merkle_root = Some(merkle_root.clone());
+        template_minter_via_msg(&mut deps.as_mut(), &instantiate_msg, &template_reply_msg(), false).unwrap();
+
+        let response = check(deps.as_ref(), BUYER_ADDR, &proofs[0]).unwrap();
+        assert_eq!(response, CheckAllowlistResponse {
+            is_valid: true,
+            leaf_hash_hex: hex::encode(allowlist_leaf(BUYER_ADDR, 2)),
+            merkle_root: Some(merkle_root.clone()),
+            error: None,
+        });
+
+        assert!(check(deps.as_ref(), NORMAL_USER_ADDR, &proofs[1]).unwrap().is_valid);
+        assert!(!check(deps.as_ref(), NORMAL_USER_ADDR, &proofs[0]).unwrap().is_valid);
+
+        let mut malformed_proof = proofs[0].clone();
+        malformed_proof.proof = vec!["not hex".to_string()];
+        let err_string = check(deps.as_ref(), BUYER_ADDR, &malformed_proof).unwrap_err().to_string();
+        assert!(err_string.contains("Invalid merkle hash, not hex encoded"));
+    }
+
+    #[test]
+    fn check_sig_canonical_encoding() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signer_pub_key = get_signer_pub_key(&signing_key);
+
+        let env = mock_env();
+        let mint_msg = template_mint_msg(&env, get_inj_wei_from_kilo_inj(100));
+        let mint_msg_wrapped = VerifiableMsg::MintRequest(mint_msg.clone());
+
+        let json_sig = sign_mint_request(signing_key.clone(), mint_msg.clone());
+        let canonical_sig = crate::test_helpers::sign_msg_bytes(signing_key.clone(), &mint_msg.to_canonical_bytes());
+
+        let mut deps = mock_dependencies();
+        template_minter(&mut deps.as_mut(), signer_pub_key.clone(), false).unwrap();
+
+        // Legacy JSON encoding by default
+        assert!(query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), json_sig.clone(),
+                                SignerSourceType::ConfigSignerPubKey).unwrap().is_valid);
+        assert!(!query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), canonical_sig.clone(),
+                                 SignerSourceType::ConfigSignerPubKey).unwrap().is_valid);
+
+        let mut settings = DEGA_MINTER_SETTINGS.load(&deps.storage).unwrap();
+        settings.mint_request_encoding = MintRequestEncoding::CanonicalV1;
+        DEGA_MINTER_SETTINGS.save(deps.as_mut().storage, &settings).unwrap();
+
+        let response = query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), canonical_sig.clone(),
+                                       SignerSourceType::ConfigSignerPubKey).unwrap();
+        assert!(response.is_valid);
+        assert_eq!(response.message_hash_hex, hex::encode(Sha256::digest(mint_msg.to_canonical_bytes())));
+        assert!(!query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), json_sig.clone(),
+                                 SignerSourceType::ConfigSignerPubKey).unwrap().is_valid);
+
+        // Personal sign over the canonical encoding
+        settings.signature_scheme = SignatureScheme::Eip191;
+        DEGA_MINTER_SETTINGS.save(deps.as_mut().storage, &settings).unwrap();
+
+        let personal_sig = sign_mint_request_with_scheme(&signing_key, mint_msg.clone(), &SignatureScheme::Eip191, &MintRequestEncoding::CanonicalV1);
+        assert!(query_check_sig(deps.as_ref(), env.clone(), mint_msg_wrapped.clone(), personal_sig,
+                                SignerSourceType::ConfigSignerPubKey).unwrap().is_valid);
+    }
+}
+
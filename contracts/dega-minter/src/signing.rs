@@ -0,0 +1,242 @@
+use cosmwasm_std::{Api, StdError, StdResult, Uint256};
+use sha2::{Sha256, digest::Digest};
+use sha3::Keccak256;
+use subtle_encoding::bech32;
+use dega_inj::helpers::to_json_binary_wrapped;
+use dega_inj::minter::{MintRequest, MintRequestEncoding, SignatureScheme, VerifiableMsg};
+
+pub(crate) const INJ_BECH32_PREFIX: &str = "inj";
+
+pub(crate) const EIP191_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+
+pub(crate) const EIP712_DOMAIN_TYPE: &str = "EIP712Domain(string name,string version)";
+pub(crate) const EIP712_DOMAIN_NAME: &str = "DEGA Minter";
+pub(crate) const EIP712_DOMAIN_VERSION: &str = "1";
+pub(crate) const EIP712_MINT_REQUEST_TYPE: &str = "MintRequest(\
+    string to,\
+    string primary_sale_recipient,\
+    string uri,\
+    string[] uris,\
+    uint32 quantity,\
+    uint256 price,\
+    string currency,\
+    uint256 validity_start_timestamp,\
+    uint256 validity_end_timestamp,\
+    string uuid,\
+    string collection,\
+    string chain_id,\
+    string minter\
+)";
+
+pub(crate) fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+// Hashes the message into the 32 byte digest the signer is expected to have signed
+pub(crate) fn hash_message(scheme: &SignatureScheme, encoding: &MintRequestEncoding, message: &VerifiableMsg) -> StdResult<Vec<u8>> {
+    match scheme {
+        SignatureScheme::Sha256 => Ok(Sha256::digest(message_bytes(encoding, message)?).to_vec()),
+        SignatureScheme::Eip191 => Ok(eip191_hash(&message_bytes(encoding, message)?).to_vec()),
+        SignatureScheme::Eip712 => match message {
+            VerifiableMsg::MintRequest(request) => Ok(eip712_mint_request_hash(request).to_vec()),
+            VerifiableMsg::String(_) => Err(StdError::generic_err(
+                "EIP-712 signatures are only supported for mint requests"
+            )),
+        },
+    }
+}
+
+fn message_bytes(encoding: &MintRequestEncoding, message: &VerifiableMsg) -> StdResult<Vec<u8>> {
+    match (message, encoding) {
+        (VerifiableMsg::String(msg), _) => Ok(msg.as_bytes().to_vec()),
+        (VerifiableMsg::MintRequest(msg), MintRequestEncoding::Json) => {
+            let msg_binary = to_json_binary_wrapped(msg)
+                .map_err(|e| StdError::generic_err(format!("Error during encode request to JSON: {}", e)))?;
+            Ok(msg_binary.to_vec())
+        },
+        (VerifiableMsg::MintRequest(msg), MintRequestEncoding::CanonicalV1) => Ok(msg.to_canonical_bytes()),
+    }
+}
+
+// Hash produced by personal_sign in Ethereum wallets
+pub(crate) fn eip191_hash(message: &[u8]) -> [u8; 32] {
+    let mut prefixed = format!("{}{}", EIP191_PREFIX, message.len()).into_bytes();
+    prefixed.extend_from_slice(message);
+    keccak256(&prefixed)
+}
+
+pub(crate) fn eip712_domain_separator() -> [u8; 32] {
+    let mut encoded = keccak256(EIP712_DOMAIN_TYPE.as_bytes()).to_vec();
+    encoded.extend_from_slice(&keccak256(EIP712_DOMAIN_NAME.as_bytes()));
+    encoded.extend_from_slice(&keccak256(EIP712_DOMAIN_VERSION.as_bytes()));
+    keccak256(&encoded)
+}
+
+// Hash produced by eth_signTypedData_v4 for a MintRequest under the minter's domain
+pub(crate) fn eip712_mint_request_hash(request: &MintRequest) -> [u8; 32] {
+    let mut encoded = keccak256(EIP712_MINT_REQUEST_TYPE.as_bytes()).to_vec();
+    encoded.extend_from_slice(&keccak256(request.to.as_bytes()));
+    encoded.extend_from_slice(&keccak256(request.primary_sale_recipient.as_bytes()));
+    encoded.extend_from_slice(&keccak256(request.uri.as_bytes()));
+    encoded.extend_from_slice(&keccak256(&request.uris.iter().flat_map(|uri| keccak256(uri.as_bytes())).collect::<Vec<u8>>()));
+    encoded.extend_from_slice(&Uint256::from(request.quantity).to_be_bytes());
+    encoded.extend_from_slice(&request.price.to_be_bytes());
+    encoded.extend_from_slice(&keccak256(request.currency.as_bytes()));
+    encoded.extend_from_slice(&Uint256::from(request.validity_start_timestamp).to_be_bytes());
+    encoded.extend_from_slice(&Uint256::from(request.validity_end_timestamp).to_be_bytes());
+    encoded.extend_from_slice(&keccak256(request.uuid.as_bytes()));
+    encoded.extend_from_slice(&keccak256(request.collection.as_bytes()));
+    encoded.extend_from_slice(&keccak256(request.chain_id.as_bytes()));
+    encoded.extend_from_slice(&keccak256(request.minter.as_bytes()));
+    let struct_hash = keccak256(&encoded);
+
+    let mut typed_data = vec![0x19, 0x01];
+    typed_data.extend_from_slice(&eip712_domain_separator());
+    typed_data.extend_from_slice(&struct_hash);
+    keccak256(&typed_data)
+}
+
+// Splits a signature into its 64 byte (r, s) part and, for 65 byte (r, s, v) signatures, the recovery id
+pub(crate) fn split_signature(signature: &[u8]) -> StdResult<(&[u8], Option<u8>)> {
+    if signature.len() != 65 {
+        return Ok((signature, None));
+    }
+
+    let recovery_id = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        v => return Err(StdError::generic_err(format!("Invalid signature recovery id: {}", v))),
+    };
+
+    Ok((&signature[..64], Some(recovery_id)))
+}
+
+// Recovers the 20 byte Ethereum style address of the key which produced the signature
+pub(crate) fn recover_eth_address(api: &dyn Api, hash: &[u8], signature: &[u8], recovery_id: u8) -> StdResult<[u8; 20]> {
+    let pub_key = api.secp256k1_recover_pubkey(hash, signature, recovery_id)
+        .map_err(|e| StdError::generic_err(format!("Error during secp256k1_recover_pubkey: {}", e)))?;
+
+    eth_address_from_pub_key(&pub_key)
+}
+
+pub(crate) fn eth_address_from_pub_key(uncompressed_pub_key: &[u8]) -> StdResult<[u8; 20]> {
+    if uncompressed_pub_key.len() != 65 || uncompressed_pub_key[0] != 0x04 {
+        return Err(StdError::generic_err("Invalid uncompressed public key"));
+    }
+
+    let pub_key_hash = keccak256(&uncompressed_pub_key[1..]);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&pub_key_hash[12..]);
+
+    Ok(address)
+}
+
+pub(crate) fn inj_address_from_eth_address(eth_address: &[u8; 20]) -> String {
+    bech32::encode(INJ_BECH32_PREFIX, eth_address)
+}
+
+// Parses a bech32 or 0x hex address into its 20 address bytes
+pub(crate) fn parse_signer_address(address: &str) -> StdResult<[u8; 20]> {
+    let address_bytes = match address.strip_prefix("0x") {
+        Some(hex_address) => hex::decode(hex_address)
+            .map_err(|e| StdError::generic_err(format!("Invalid hex signer address ({}): {}", address, e)))?,
+        None => bech32::decode(address)
+            .map_err(|e| StdError::generic_err(format!("Invalid bech32 signer address ({}): {}", address, e)))?.1,
+    };
+
+    address_bytes.try_into()
+        .map_err(|_| StdError::generic_err(format!("Invalid signer address, not 20 bytes long: {}", address)))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use k256::ecdsa::{SigningKey, VerifyingKey};
+    use k256::elliptic_curve::rand_core::OsRng;
+    use crate::test_helpers::{sign_hash_recoverable, template_mint_msg};
+    use super::*;
+
+    #[test]
+    fn hashing() {
+        assert_eq!(
+            hex::encode(keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+
+        // Well known personal_sign hash of "hello world"
+        assert_eq!(
+            hex::encode(eip191_hash(b"hello world")),
+            "d9eba16ed0ecae432b71fe008c98cc872bb4cc214d3220a36f365326cf807d68"
+        );
+
+        let request = template_mint_msg(&mock_env(), 100u128.into());
+        let mut other_request = request.clone();
+        other_request.uuid = "OTHER_UUID".to_string();
+        assert_ne!(eip712_mint_request_hash(&request), eip712_mint_request_hash(&other_request));
+        let mut bundle_request = request.clone();
+        bundle_request.uris = vec![request.uri.clone()];
+        assert_ne!(eip712_mint_request_hash(&request), eip712_mint_request_hash(&bundle_request));
+
+        let json = MintRequestEncoding::Json;
+        let canonical = MintRequestEncoding::CanonicalV1;
+        let message = VerifiableMsg::MintRequest(request.clone());
+        assert_eq!(hash_message(&SignatureScheme::Eip712, &json, &message).unwrap(), eip712_mint_request_hash(&request).to_vec());
+        assert_eq!(hash_message(&SignatureScheme::Eip712, &canonical, &message).unwrap(), eip712_mint_request_hash(&request).to_vec());
+        assert_ne!(hash_message(&SignatureScheme::Sha256, &json, &message).unwrap(), hash_message(&SignatureScheme::Eip191, &json, &message).unwrap());
+
+        // Canonical encoding applies to mint requests only
+        assert_eq!(hash_message(&SignatureScheme::Sha256, &canonical, &message).unwrap(), Sha256::digest(request.to_canonical_bytes()).to_vec());
+        assert_eq!(hash_message(&SignatureScheme::Eip191, &canonical, &message).unwrap(), eip191_hash(&request.to_canonical_bytes()).to_vec());
+        let string_message = VerifiableMsg::String("message".to_string());
+        assert_eq!(hash_message(&SignatureScheme::Sha256, &canonical, &string_message).unwrap(),
+                   hash_message(&SignatureScheme::Sha256, &json, &string_message).unwrap());
+
+        let err_string = hash_message(&SignatureScheme::Eip712, &json, &string_message)
+            .unwrap_err().to_string();
+        assert!(err_string.contains("EIP-712 signatures are only supported for mint requests"));
+    }
+
+    #[test]
+    fn address_recovery() {
+        let deps = mock_dependencies();
+        let signing_key = SigningKey::random(&mut OsRng);
+        let uncompressed_pub_key = VerifyingKey::from(&signing_key).to_encoded_point(false);
+        let eth_address = eth_address_from_pub_key(uncompressed_pub_key.as_bytes()).unwrap();
+
+        let hash = eip191_hash(b"message");
+        let signature = sign_hash_recoverable(&signing_key, &hash, true);
+        let (sig_bytes, recovery_id) = split_signature(&signature).unwrap();
+
+        assert_eq!(sig_bytes.len(), 64);
+        assert_eq!(recover_eth_address(&deps.api, &hash, sig_bytes, recovery_id.unwrap()).unwrap(), eth_address);
+
+        // Both address formats resolve to the same bytes
+        let inj_address = inj_address_from_eth_address(&eth_address);
+        assert!(inj_address.starts_with("inj1"));
+        assert_eq!(parse_signer_address(&inj_address).unwrap(), eth_address);
+        assert_eq!(parse_signer_address(&format!("0x{}", hex::encode(eth_address))).unwrap(), eth_address);
+
+        // 64 byte signatures carry no recovery id
+        assert_eq!(split_signature(&signature[..64]).unwrap().1, None);
+    }
+
+    #[test]
+    fn address_errors() {
+        let mut err_string;
+
+        err_string = split_signature(&[5u8; 65]).unwrap_err().to_string();
+        assert!(err_string.contains("Invalid signature recovery id"));
+
+        err_string = eth_address_from_pub_key(&[2u8; 33]).unwrap_err().to_string();
+        assert!(err_string.contains("Invalid uncompressed public key"));
+
+        err_string = parse_signer_address("0xnothex").unwrap_err().to_string();
+        assert!(err_string.contains("Invalid hex signer address"));
+
+        err_string = parse_signer_address("not_bech32").unwrap_err().to_string();
+        assert!(err_string.contains("Invalid bech32 signer address"));
+
+        err_string = parse_signer_address("0x1234").unwrap_err().to_string();
+        assert!(err_string.contains("Invalid signer address, not 20 bytes long"));
+    }
+}
@@ -1,11 +1,69 @@
-use cosmwasm_std::{Addr, Empty};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_storage_plus::{Item, Map};
-use dega_inj::minter::{DegaMinterConfigSettings};
+use dega_inj::minter::{DegaMinterConfigSettings, PendingAdminAction, PlatformFee, ProposalAction, RevenueSplit, Role, SalePhase, SignerKeyInfo};
 
 
 pub(crate) const DEGA_MINTER_SETTINGS: Item<DegaMinterConfigSettings> = Item::new("dega_minter_settings");
-pub(crate) const ADMIN_LIST: Map<String,Empty> = Map::new("admin_list");
-pub(crate) const UUID_REGISTRY: Map<String,Empty> = Map::new("uuid_registry");
+// Keyed by validated address, entries stored as unvalidated strings before 1.2.0 are normalized by its upgrade step
+pub(crate) const ADMIN_LIST: Map<Addr,Empty> = Map::new("admin_list");
+// Roles granted to addresses besides super admin, which is membership of the admin list. Keyed by validated address
+// like the admin list, with entries stored as unvalidated strings before 1.2.0 normalized by its upgrade step
+pub(crate) const ROLES: Map<Addr,Vec<Role>> = Map::new("roles");
+// Admin and signer changes waiting out the admin action delay, keyed by id
+pub(crate) const ADMIN_ACTIONS: Map<u64,PendingAdminAction> = Map::new("admin_actions");
+pub(crate) const ADMIN_ACTION_COUNT: Item<u64> = Item::new("admin_action_count");
+// Multisig proposals keyed by id, kept after execution so their approvals can still be queried
+pub(crate) const PROPOSALS: Map<u64,Proposal> = Map::new("proposals");
+pub(crate) const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+pub(crate) const UUID_REGISTRY: Map<String,UuidRecord> = Map::new("uuid_registry");
+// Number of used, not revoked, UUIDs in the registry, counted from 1.2.0 onward
+pub(crate) const UUID_COUNT: Item<u64> = Item::new("uuid_count");
+// Used UUIDs keyed by the end of their request's validity window, after which they can no longer be replayed
+pub(crate) const UUID_EXPIRATIONS: Map<(u64,String),Empty> = Map::new("uuid_expirations");
 pub(crate) const COLLECTION_ADDRESS: Item<Addr> = Item::new("collection_address");
 pub(crate) const TOKEN_INDEX: Item<u64> = Item::new("token_index");
+pub(crate) const SIGNER_KEYS: Map<String,SignerKeyInfo> = Map::new("signer_keys");
+pub(crate) const MINT_COUNTS: Map<Addr,u32> = Map::new("mint_counts");
+pub(crate) const SALE_PHASES: Item<Vec<SalePhase>> = Item::new("sale_phases");
+pub(crate) const PHASE_MINT_COUNTS: Map<(String,Addr),u32> = Map::new("phase_mint_counts");
+// Price of the latest dutch auction mint
+pub(crate) const AUCTION_LAST_PRICE: Item<Uint128> = Item::new("auction_last_price");
+// Dutch auction clearing price, fixed by the first rebate claim once it is final
+pub(crate) const AUCTION_CLEARING_PRICE: Item<Uint128> = Item::new("auction_clearing_price");
+// Payment terms in force when the dutch auction first sold, which its later mints and held payments are settled under
+pub(crate) const AUCTION_SALE: Item<AuctionSale> = Item::new("auction_sale");
+// Tokens bought and total paid per dutch auction buyer, until their rebate is claimed
+pub(crate) const AUCTION_PURCHASES: Map<Addr,(u32,Uint128)> = Map::new("auction_purchases");
 
+
+#[cw_serde]
+pub(crate) struct AuctionSale {
+    pub(crate) denom: String,
+    pub(crate) sale_recipient: Addr,
+    pub(crate) platform_fee: Option<PlatformFee>,
+    pub(crate) revenue_splits: Vec<RevenueSplit>,
+}
+
+// Entries registered before revocations and token ids were recorded are stored as empty objects, and load as used
+#[cw_serde]
+#[derive(Default)]
+pub(crate) struct UuidRecord {
+    #[serde(default)]
+    pub(crate) revoked: bool,
+    #[serde(default)]
+    pub(crate) token_ids: Vec<u64>,
+    // Validity end of the mint request which used the UUID, epoch seconds
+    #[serde(default)]
+    pub(crate) validity_end_timestamp: Option<Uint128>,
+}
+
+#[cw_serde]
+pub(crate) struct Proposal {
+    pub(crate) action: ProposalAction,
+    pub(crate) proposer: Addr,
+    pub(crate) approvals: Vec<Addr>,
+    // End of the approval window, epoch seconds
+    pub(crate) expiration: Uint128,
+    pub(crate) executed: bool,
+}
@@ -1,8 +1,10 @@
-use cosmwasm_std::{Decimal, Deps, DepsMut, Env, from_json, Reply, Response, StdResult, SubMsgResponse, SubMsgResult, to_json_binary, Uint128, Uint256};
-use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use cosmwasm_std::{Binary, ContractResult, Decimal, Deps, DepsMut, Empty, Env, from_json, MessageInfo, OwnedDeps, Querier, QuerierResult, QueryRequest, Reply, Response, StdResult, SubMsgResponse, SubMsgResult, SystemError, SystemResult, to_json_binary, Uint128, Uint256};
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR, MockApi, MockQuerier, MockStorage};
 use digest::Digest;
 use prost::Message;
-use dega_inj::minter::{DegaMinterConfigSettings, DegaMinterParams, InstantiateMsg, MintRequest, QueryMsg};
+use dega_inj::minter::{AdminAction, AllowlistProof, DegaMinterConfigSettings, DegaMinterParams, InstantiateMsg, MintRequest, MintRequestEncoding, OverpaymentMode, PendingAdminActionsResponse, QueryMsg, SignatureScheme, VerifiableMsg};
 
 use k256::{ecdsa::signature::DigestSigner, ecdsa::SigningKey};
 use k256::ecdsa::{Signature, VerifyingKey};
@@ -17,7 +19,11 @@ use dega_inj::cw721::{CollectionInfoResponse, CollectionParams, RoyaltySettingsR
 use crate::contract::{INSTANTIATE_DEGA_CW721_REPLY_ID, run_instantiate, run_reply};
 use crate::entry::{instantiate, reply};
 use crate::error::ContractError;
+use crate::execute::{execute_accept_admin_nomination, execute_admin_action};
+use crate::lookup::{ACCOUNT_INFO_QUERY_PATH, BaseAccount, PubKeyAny, QueryAccountInfoRequest, QueryAccountInfoResponse};
 use crate::query::run_query;
+use crate::merkle::{allowlist_leaf, hash_pair};
+use crate::signing::{eth_address_from_pub_key, hash_message, inj_address_from_eth_address};
 
 #[derive(Clone, PartialEq, Message)]
 struct MsgInstantiateContractResponse {
@@ -48,6 +54,57 @@ pub(crate) const BUYER_ADDR: &str = "buyer_addr";
 pub(crate) const PRIMARY_SALE_RECIPIENT_ADDR: &str = "primary_sale_recipient_addr";
 pub(crate) const MINT_URI: &str = "http://example.com/";
 pub(crate) const INVALID_ADDR: &str = "INVALID_ADDR"; // upper case will be normalized to lower case and fail the validation check
+pub(crate) const SIGNER_ACCOUNT_ADDR: &str = "signer_account_addr";
+
+// Mock querier which answers auth module account info stargate queries
+// Accounts map to an optional (pubkey type url, base64 pubkey) pair
+pub(crate) struct AccountMockQuerier {
+    base: MockQuerier,
+    accounts: HashMap<String, Option<(String, String)>>,
+}
+
+impl Querier for AccountMockQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let request: QueryRequest<Empty> = match from_json(bin_request) {
+            Ok(request) => request,
+            Err(e) => return SystemResult::Err(SystemError::InvalidRequest {
+                error: format!("Parsing query request: {}", e),
+                request: bin_request.into(),
+            }),
+        };
+
+        match request {
+            QueryRequest::Stargate { path, data } if path == ACCOUNT_INFO_QUERY_PATH => {
+                let account_request = QueryAccountInfoRequest::decode(data.as_slice()).unwrap();
+                let info = self.accounts.get(&account_request.address).map(|maybe_pub_key| BaseAccount {
+                    address: account_request.address.clone(),
+                    pub_key: maybe_pub_key.as_ref().map(|(type_url, key)| PubKeyAny {
+                        type_url: type_url.clone(),
+                        key: Binary::from_base64(key).unwrap(),
+                    }),
+                });
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&QueryAccountInfoResponse { info }).unwrap()))
+            },
+            _ => self.base.raw_query(bin_request),
+        }
+    }
+}
+
+pub(crate) fn mock_dependencies_with_accounts(
+    accounts: Vec<(&str, Option<(&str, String)>)>,
+) -> OwnedDeps<MockStorage, MockApi, AccountMockQuerier> {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: AccountMockQuerier {
+            base: MockQuerier::default(),
+            accounts: accounts.into_iter()
+                .map(|(address, pub_key)| (address.to_string(), pub_key.map(|(type_url, key)| (type_url.to_string(), key))))
+                .collect(),
+        },
+        custom_query_type: PhantomData,
+    }
+}
 
 
 pub(crate) fn get_signer_pub_key(signing_key: &SigningKey) -> String {
@@ -80,12 +137,16 @@ pub(crate) fn template_mint_msg(mock_env: &Env, price: Uint128) -> MintRequest {
         to: buyer,
         primary_sale_recipient,
         uri,
+        uris: vec![],
+        quantity: 1,
         price: Uint256::from(price),
         currency: "inj".to_string(),
         validity_start_timestamp: Uint128::from(start_time),
         validity_end_timestamp: Uint128::from(end_time),
         uuid: "UUID".to_string(),
         collection: COLLECTION_CONTRACT_ADDR.to_string(),
+        chain_id: mock_env.block.chain_id.clone(),
+        minter: mock_env.contract.address.to_string(),
     }
 }
 
@@ -105,6 +166,56 @@ pub(crate) fn sign_msg_bytes(signing_key: SigningKey, msg_bytes: &[u8]) -> Strin
     base64::encode(signature.to_bytes())
 }
 
+// Signs a prehashed message producing a 65 byte (r, s, v) signature as Ethereum wallets do
+// Ethereum style signatures use a v of 27 or 28 rather than the raw 0 or 1 recovery id
+pub(crate) fn sign_hash_recoverable(signing_key: &SigningKey, hash: &[u8], eth_v: bool) -> Vec<u8> {
+    let (signature, recovery_id) = signing_key.sign_prehash_recoverable(hash).unwrap();
+    let mut signature_bytes = signature.to_bytes().to_vec();
+    signature_bytes.push(recovery_id.to_byte() + if eth_v { 27 } else { 0 });
+    signature_bytes
+}
+
+pub(crate) fn sign_mint_request_with_scheme(
+    signing_key: &SigningKey,
+    mint_request: MintRequest,
+    scheme: &SignatureScheme,
+    encoding: &MintRequestEncoding,
+) -> String {
+    let hash = hash_message(scheme, encoding, &VerifiableMsg::MintRequest(mint_request)).unwrap();
+    base64::encode(sign_hash_recoverable(signing_key, &hash, true))
+}
+
+// Injective address of the signing key, derived the same way as an Ethereum address
+pub(crate) fn get_signer_inj_address(signing_key: &SigningKey) -> String {
+    let uncompressed_pub_key = VerifyingKey::from(signing_key).to_encoded_point(false);
+    inj_address_from_eth_address(&eth_address_from_pub_key(uncompressed_pub_key.as_bytes()).unwrap())
+}
+
+// Builds an allowlist merkle tree, returning the hex root and the proof of each entry.
+// Odd nodes out are paired with themselves.
+pub(crate) fn build_allowlist(entries: &[(&str, u32)]) -> (String, Vec<AllowlistProof>) {
+    let mut level: Vec<[u8; 32]> = entries.iter().map(|(address, allowance)| allowlist_leaf(address, *allowance)).collect();
+    let mut positions: Vec<usize> = (0..entries.len()).collect();
+    let mut proofs: Vec<AllowlistProof> = entries.iter().map(|(_, allowance)| AllowlistProof {
+        allowance: *allowance,
+        proof: vec![],
+    }).collect();
+
+    while level.len() > 1 {
+        for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+            let sibling = level.get(*position ^ 1).unwrap_or(&level[*position]);
+            proof.proof.push(hex::encode(sibling));
+            *position /= 2;
+        }
+
+        level = level.chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+    }
+
+    (hex::encode(level[0]), proofs)
+}
+
 pub(crate) fn template_minter(deps: &mut DepsMut, signer_pub_key: String, use_entry: bool)
     -> Result<(Response,Response), ContractError> {
 
@@ -144,6 +255,19 @@ pub(crate) fn template_instantiate_msg(signer_pub_key: String) -> InstantiateMsg
             dega_minter_settings: DegaMinterConfigSettings {
                 signer_pub_key,
                 minting_paused: false,
+                signer_address: None,
+                signature_scheme: SignatureScheme::Sha256,
+                mint_request_encoding: MintRequestEncoding::Json,
+                revenue_splits: vec![],
+                platform_fee: None,
+                max_supply: None,
+                max_mints_per_wallet: None,
+                public_sale: None,
+                allowlist_merkle_root: None,
+                dutch_auction: None,
+                overpayment_mode: OverpaymentMode::Strict,
+                admin_action_delay: 0,
+                multisig: None,
             },
             initial_admin: USER_ADMIN_ADDR.into(),
         },
@@ -193,4 +317,20 @@ pub(crate) fn query_typed<T>(deps: Deps, msg: QueryMsg) -> StdResult<T>
     where T: for<'de> serde::Deserialize<'de>
 {
     from_json(run_query(deps, mock_env(), msg)?)
-}
\ No newline at end of file
+}
+// Carries out every queued admin action in order, with admin nominations accepted by their nominee. The delay has
+// always passed on the template minter, which has none.
+pub(crate) fn run_pending_admin_actions(deps: &mut DepsMut, env: &Env, executor: &MessageInfo) -> Result<(), ContractError> {
+    let pending_actions = query_typed::<PendingAdminActionsResponse>(deps.as_ref(), QueryMsg::PendingAdminActions {})
+        .unwrap().actions;
+
+    for pending_action in pending_actions {
+        match &pending_action.action {
+            AdminAction::AddAdmin { address } =>
+                execute_accept_admin_nomination(deps, env, &mock_info(address, &[]), pending_action.id)?,
+            _ => execute_admin_action(deps, env, executor, pending_action.id)?,
+        };
+    }
+
+    Ok(())
+}
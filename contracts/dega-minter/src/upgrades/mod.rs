@@ -1 +1,71 @@
-pub(crate) mod v1_0_0;
\ No newline at end of file
+use cosmwasm_std::{DepsMut, Env, Response};
+use semver::Version;
+use crate::error::ContractError;
+
+pub(crate) mod v1_0_0;
+pub(crate) mod v1_1_0;
+pub(crate) mod v1_2_0;
+
+pub(crate) type UpgradeFn = fn(DepsMut, &Env, Response) -> Result<Response, ContractError>;
+
+// Registered upgrade steps, in ascending version order.
+// Each step is run when migrating from a version below it to a version at or above it.
+pub(crate) const UPGRADES: &[(&str, UpgradeFn)] = &[
+    ("1.0.0", v1_0_0::upgrade),
+    ("1.1.0", v1_1_0::upgrade),
+    ("1.2.0", v1_2_0::upgrade),
+];
+
+pub(crate) fn run_upgrades(
+    mut deps: DepsMut,
+    env: &Env,
+    from_version: &Version,
+    to_version: &Version,
+    mut response: Response,
+) -> Result<Response, ContractError> {
+
+    for (upgrade_version, upgrade) in UPGRADES {
+        let upgrade_version = Version::parse(upgrade_version)
+            .map_err(|e| ContractError::Migration(format!("Invalid upgrade version ({}): {}", upgrade_version, e)))?;
+
+        if *from_version < upgrade_version && upgrade_version <= *to_version {
+            response = upgrade(deps.branch(), env, response)?;
+        }
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use super::*;
+
+    #[test]
+    fn upgrade_chain() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // Registered versions must be valid and in ascending order
+        let versions: Vec<Version> = UPGRADES.iter().map(|(v, _)| Version::parse(v).unwrap()).collect();
+        assert!(versions.windows(2).all(|w| w[0] < w[1]));
+
+        // Upgrade from before 1.0.0 runs the 1.0.0 step
+        let response = run_upgrades(deps.as_mut(), &env, &Version::new(0, 9, 0), &Version::new(1, 0, 0), Response::new()).unwrap();
+        assert_eq!(response.events.len(), 1);
+        assert_eq!(response.events[0].ty, "migrate-1.0.0");
+
+        // Upgrade from 1.0.0 onward does not re-run the 1.0.0 step
+        let response = run_upgrades(deps.as_mut(), &env, &Version::new(1, 0, 0), &Version::new(1, 0, 1), Response::new()).unwrap();
+        assert!(response.events.is_empty());
+
+        // Upgrade from 1.0.0 to 1.1.0 only runs the 1.1.0 step
+        let response = run_upgrades(deps.as_mut(), &env, &Version::new(1, 0, 0), &Version::new(1, 1, 0), Response::new()).unwrap();
+        assert_eq!(response.events.len(), 1);
+        assert_eq!(response.events[0].ty, "migrate-1.1.0");
+
+        // Steps above the target version are not run
+        let response = run_upgrades(deps.as_mut(), &env, &Version::new(0, 8, 0), &Version::new(0, 9, 0), Response::new()).unwrap();
+        assert!(response.events.is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::error::ContractError;
+use crate::helpers::save_used_uuid;
+use crate::state::{UUID_REGISTRY, UuidRecord};
+
+use cosmwasm_std::{DepsMut, Env, Event, Order, Response, StdResult, Uint128};
+use dega_inj::helpers::map_range_wrapped;
+
+// Validity ends were not recorded before this version, so requests signed before the upgrade are assumed to
+// expire within this long of it
+pub(crate) const LEGACY_UUID_VALIDITY_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+// Runs over the whole UUID registry in the migration transaction, with a registry and an expiration index write
+// per legacy entry, so gas grows linearly with the number of mint requests used before the upgrade. Minters with
+// very large registries should run the migration with a raised gas limit.
+pub(crate) fn upgrade(deps: DepsMut, env: &Env, response: Response) -> Result<Response, ContractError> {
+
+    let uuid_entries = map_range_wrapped(deps.storage, &UUID_REGISTRY, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, UuidRecord)>>>()
+        .map_err(|e| ContractError::Std("Error loading UUID registry".to_string(), e))?;
+
+    let validity_end_timestamp = Uint128::from(env.block.time.plus_seconds(LEGACY_UUID_VALIDITY_SECONDS).seconds());
+
+    let mut migrated_count = 0u64;
+    for (uuid, uuid_record) in uuid_entries {
+        if uuid_record.revoked || uuid_record.validity_end_timestamp.is_some() {
+            continue;
+        }
+
+        let uuid_record = UuidRecord {
+            validity_end_timestamp: Some(validity_end_timestamp),
+            ..uuid_record
+        };
+
+        save_used_uuid(deps.storage, &uuid, &uuid_record)
+            .map_err(|e| ContractError::Std("Error while migrating UUID".to_string(), e))?;
+
+        migrated_count += 1;
+    }
+
+    let event = Event::new("migrate-1.1.0")
+        .add_attribute("migrated_uuids", migrated_count.to_string());
+
+    Ok(response.add_event(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Empty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cw_storage_plus::Map;
+    use crate::state::UUID_EXPIRATIONS;
+    use super::*;
+
+    #[test]
+    fn legacy_uuids() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // Entries registered before validity ends were recorded are empty objects
+        let legacy_registry: Map<String, Empty> = Map::new("uuid_registry");
+        legacy_registry.save(deps.as_mut().storage, "LEGACY_UUID".to_string(), &Empty {}).unwrap();
+
+        let revoked_record = UuidRecord { revoked: true, ..Default::default() };
+        UUID_REGISTRY.save(deps.as_mut().storage, "REVOKED_UUID".to_string(), &revoked_record).unwrap();
+
+        let response = upgrade(deps.as_mut(), &env, Response::new()).unwrap();
+        assert_eq!(response.events[0].ty, "migrate-1.1.0");
+        assert_eq!(response.events[0].attributes[0].value, "1");
+
+        let expiration = env.block.time.seconds() + LEGACY_UUID_VALIDITY_SECONDS;
+        let legacy_record = UUID_REGISTRY.load(deps.as_ref().storage, "LEGACY_UUID".to_string()).unwrap();
+        assert_eq!(legacy_record.validity_end_timestamp, Some(Uint128::from(expiration)));
+        assert!(UUID_EXPIRATIONS.has(deps.as_ref().storage, (expiration, "LEGACY_UUID".to_string())));
+
+        // Revoked entries have no validity end to expire at
+        assert_eq!(UUID_REGISTRY.load(deps.as_ref().storage, "REVOKED_UUID".to_string()).unwrap(), revoked_record);
+
+        // Running again has nothing left to migrate
+        let response = upgrade(deps.as_mut(), &env, Response::new()).unwrap();
+        assert_eq!(response.events[0].attributes[0].value, "0");
+    }
+}
@@ -0,0 +1,159 @@
+use crate::error::ContractError;
+use crate::state::{ADMIN_LIST, ROLES, UUID_COUNT, UUID_REGISTRY, UuidRecord};
+
+use cosmwasm_std::{Addr, DepsMut, Empty, Env, Event, Order, Response, StdResult};
+use dega_inj::minter::Role;
+use dega_inj::helpers::{map_keys_wrapped, map_range_wrapped, save_item_wrapped, save_map_item_wrapped};
+
+// Admins and role holders were stored as unvalidated strings before this version. Mixed case entries are re-keyed
+// under their normalized address, and entries which aren't valid addresses at all are removed, as no sender can
+// match them.
+// Used UUIDs were not counted before this version either, so their count is initialized from the registry.
+// Like the 1.1.0 step, this reads the whole registry in the migration transaction.
+pub(crate) fn upgrade(deps: DepsMut, _env: &Env, response: Response) -> Result<Response, ContractError> {
+
+    let admin_keys = map_keys_wrapped(deps.storage, &ADMIN_LIST, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()
+        .map_err(|e| ContractError::Std("Error loading admin list".to_string(), e))?;
+
+    let mut normalized_admins = vec![];
+    let mut invalid_admins = vec![];
+
+    for admin_key in admin_keys {
+        if deps.api.addr_validate(admin_key.as_str()).is_ok() {
+            continue;
+        }
+
+        ADMIN_LIST.remove(deps.storage, admin_key.clone());
+
+        match deps.api.addr_validate(&admin_key.as_str().to_lowercase()) {
+            Ok(normalized_admin) => {
+                save_map_item_wrapped(deps.storage, &ADMIN_LIST, normalized_admin, &Empty {})
+                    .map_err(|e| ContractError::Std("Error while saving normalized admin".to_string(), e))?;
+
+                normalized_admins.push(admin_key.to_string());
+            },
+            Err(_) => invalid_admins.push(admin_key.to_string()),
+        }
+    }
+
+    if ADMIN_LIST.is_empty(deps.storage) {
+        return Err(ContractError::Migration(
+            format!("No valid admins would remain after normalizing the admin list | Invalid admins: {}",
+                    invalid_admins.join(","))
+        ));
+    }
+
+    let role_entries = map_range_wrapped(deps.storage, &ROLES, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Vec<Role>)>>>()
+        .map_err(|e| ContractError::Std("Error loading roles".to_string(), e))?;
+
+    let mut normalized_role_holders = vec![];
+    let mut invalid_role_holders = vec![];
+
+    for (role_holder, roles) in role_entries {
+        if deps.api.addr_validate(role_holder.as_str()).is_ok() {
+            continue;
+        }
+
+        ROLES.remove(deps.storage, role_holder.clone());
+
+        match deps.api.addr_validate(&role_holder.as_str().to_lowercase()) {
+            Ok(normalized_role_holder) => {
+                // The normalized address may have been granted roles under its own key as well
+                let mut merged_roles = ROLES.may_load(deps.storage, normalized_role_holder.clone())
+                    .map_err(|e| ContractError::Std("Error loading roles".to_string(), e))?
+                    .unwrap_or_default();
+
+                for role in roles {
+                    if !merged_roles.contains(&role) {
+                        merged_roles.push(role);
+                    }
+                }
+
+                save_map_item_wrapped(deps.storage, &ROLES, normalized_role_holder, &merged_roles)
+                    .map_err(|e| ContractError::Std("Error while saving normalized roles".to_string(), e))?;
+
+                normalized_role_holders.push(role_holder.to_string());
+            },
+            Err(_) => invalid_role_holders.push(role_holder.to_string()),
+        }
+    }
+
+    let uuid_records = map_range_wrapped(deps.storage, &UUID_REGISTRY, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, UuidRecord)>>>()
+        .map_err(|e| ContractError::Std("Error loading UUID registry".to_string(), e))?;
+
+    let uuid_count = uuid_records.iter()
+        .filter(|(_, uuid_record)| !uuid_record.revoked)
+        .count() as u64;
+
+    save_item_wrapped(deps.storage, &UUID_COUNT, &uuid_count)
+        .map_err(|e| ContractError::Std("Error while saving UUID count".to_string(), e))?;
+
+    let event = Event::new("migrate-1.2.0")
+        .add_attribute("normalized_admins", normalized_admins.join(","))
+        .add_attribute("invalid_admins", invalid_admins.join(","))
+        .add_attribute("uuid_count", uuid_count.to_string())
+        .add_attribute("normalized_role_holders", normalized_role_holders.join(","))
+        .add_attribute("invalid_role_holders", invalid_role_holders.join(","));
+
+    Ok(response.add_event(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use crate::test_helpers::{BUYER_ADDR, NEW_ADMIN_ADDR, USER_ADMIN_ADDR};
+    use super::*;
+
+    #[test]
+    fn legacy_admins_and_uuids() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        for admin in [USER_ADMIN_ADDR, "New_Admin_Addr", "ab"] {
+            ADMIN_LIST.save(deps.as_mut().storage, Addr::unchecked(admin), &Empty {}).unwrap();
+        }
+
+        ROLES.save(deps.as_mut().storage, Addr::unchecked(BUYER_ADDR), &vec![Role::Pauser]).unwrap();
+        ROLES.save(deps.as_mut().storage, Addr::unchecked("Buyer_Addr"), &vec![Role::Pauser, Role::SignerManager]).unwrap();
+        ROLES.save(deps.as_mut().storage, Addr::unchecked("cd"), &vec![Role::Pauser]).unwrap();
+
+        for uuid in ["UUID_1", "UUID_2"] {
+            UUID_REGISTRY.save(deps.as_mut().storage, uuid.to_string(), &UuidRecord::default()).unwrap();
+        }
+        let revoked_record = UuidRecord { revoked: true, ..Default::default() };
+        UUID_REGISTRY.save(deps.as_mut().storage, "REVOKED_UUID".to_string(), &revoked_record).unwrap();
+
+        let response = upgrade(deps.as_mut(), &env, Response::new()).unwrap();
+        assert_eq!(response.events[0].ty, "migrate-1.2.0");
+        assert_eq!(response.events[0].attributes[0].value, "New_Admin_Addr");
+        assert_eq!(response.events[0].attributes[1].value, "ab");
+        assert_eq!(response.events[0].attributes[2].value, "2");
+        assert_eq!(response.events[0].attributes[3].value, "Buyer_Addr");
+        assert_eq!(response.events[0].attributes[4].value, "cd");
+        assert_eq!(UUID_COUNT.load(deps.as_ref().storage).unwrap(), 2);
+
+        let role_entries = ROLES.range(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<(Addr, Vec<Role>)>>>().unwrap();
+        assert_eq!(role_entries, vec![(Addr::unchecked(BUYER_ADDR), vec![Role::Pauser, Role::SignerManager])]);
+
+        let admins = ADMIN_LIST.keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>().unwrap();
+        assert_eq!(admins, vec![Addr::unchecked(NEW_ADMIN_ADDR), Addr::unchecked(USER_ADMIN_ADDR)]);
+
+        // Running again has nothing left to migrate
+        let response = upgrade(deps.as_mut(), &env, Response::new()).unwrap();
+        assert_eq!(response.events[0].attributes[0].value, "");
+        assert_eq!(response.events[0].attributes[1].value, "");
+        assert_eq!(response.events[0].attributes[3].value, "");
+        assert_eq!(response.events[0].attributes[4].value, "");
+
+        // Migration is refused if it would leave the minter without admins
+        let mut deps = mock_dependencies();
+        ADMIN_LIST.save(deps.as_mut().storage, Addr::unchecked("ab"), &Empty {}).unwrap();
+        let err = upgrade(deps.as_mut(), &env, Response::new()).unwrap_err().to_string();
+        assert!(err.contains("No valid admins would remain after normalizing the admin list"));
+    }
+}
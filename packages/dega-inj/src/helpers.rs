@@ -1,4 +1,4 @@
-use cosmwasm_std::{Binary, StdResult, Storage, to_json_binary};
+use cosmwasm_std::{Binary, Decimal, StdError, StdResult, Storage, to_json_binary};
 use cw2::set_contract_version;
 use cw_storage_plus::{Bound, Item, KeyDeserialize, Map, PrimaryKey};
 use serde::de::DeserializeOwned;
@@ -72,6 +72,28 @@ pub fn map_keys_wrapped<'a, 'c, K, T>(
     map.keys(store, min, max, order)
 }
 
+pub fn map_range_wrapped<'a, 'c, K, T>(
+    store: &'c dyn Storage,
+    map: &Map<'a, K,T>,
+    min: Option<Bound<'a, K>>,
+    max: Option<Bound<'a, K>>,
+    order: cosmwasm_std::Order,
+) -> Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'c>
+    where
+        T: 'c + Serialize + DeserializeOwned,
+        K: PrimaryKey<'a> + KeyDeserialize,
+        K::Output: 'static,
+{
+    #[cfg(any(feature = "test-mode", test))]
+    {
+        let namespace = map.namespace();
+        if let Err(e) = crate::test_helpers::check_for_load_error::<T>(namespace) {
+            return Box::new(std::iter::once(Err(e)))
+        }
+    }
+    map.range(store, min, max, order)
+}
+
 pub fn set_contract_version_wrapped<T, U>(
     store: &mut dyn Storage,
     name: T,
@@ -113,6 +135,17 @@ pub fn to_json_binary_wrapped<T>(data: &T) -> StdResult<Binary>
     binary_result
 }
 
+// Royalty and platform fee shares are fractions of a payment, so at most 100%
+pub fn share_validate(share: Decimal) -> StdResult<Decimal> {
+    if share > Decimal::one() {
+        return Err(StdError::generic_err(
+            "Share cannot be greater than 100%".to_string(),
+        ));
+    }
+
+    Ok(share)
+}
+
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::to_json_binary;
@@ -1,6 +1,7 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Uint128, Uint256};
-use crate::cw721::CollectionParams;
+use cosmwasm_std::{Decimal, Uint128, Uint256};
+use cw20::Cw20ReceiveMsg;
+use crate::cw721::{CollectionParams, RoyaltySettingsResponse};
 
 
 #[cw_serde]
@@ -27,12 +28,169 @@ pub struct DegaMinterParams {
 pub struct DegaMinterConfigSettings {
     pub signer_pub_key: String,
     pub minting_paused: bool,
+    // Address of a signer whose public key is looked up from the chain's auth module
+    pub signer_address: Option<String>,
+    // Hashing scheme mint request signatures are produced with, defaults to SHA-256
+    #[serde(default)]
+    pub signature_scheme: SignatureScheme,
+    // Byte encoding of mint requests hashed under the SHA-256 and EIP-191 schemes, defaults to JSON
+    #[serde(default)]
+    pub mint_request_encoding: MintRequestEncoding,
+    // Shares of the proceeds carved out for other recipients before the primary sale recipient is paid
+    #[serde(default)]
+    pub revenue_splits: Vec<RevenueSplit>,
+    // Cut of each primary sale paid to the platform before any other recipient
+    #[serde(default)]
+    pub platform_fee: Option<PlatformFee>,
+    // Maximum number of tokens the minter will ever mint, unlimited if not set. Can only be lowered.
+    #[serde(default)]
+    pub max_supply: Option<u64>,
+    // Maximum number of tokens a single recipient can receive through mint requests, unlimited if not set
+    #[serde(default)]
+    pub max_mints_per_wallet: Option<u32>,
+    // Fixed price sale open to anyone without a signed mint request, disabled if not set
+    #[serde(default)]
+    pub public_sale: Option<PublicSaleSettings>,
+    // Hex encoded SHA-256 merkle root of (address, allowance) leaves for allowlist mints, see AllowlistProof. While set,
+    // mints outside of public phases need a proof
+    #[serde(default)]
+    pub allowlist_merkle_root: Option<String>,
+    // Declining price replacing the public sale price outside of sale phases
+    #[serde(default)]
+    pub dutch_auction: Option<DutchAuction>,
+    // Handling of payments above the price, rejected unless set to refund mode
+    #[serde(default)]
+    pub overpayment_mode: OverpaymentMode,
+    // Seconds queued admin actions wait before they can be carried out, changed through an admin action itself
+    #[serde(default)]
+    pub admin_action_delay: u64,
+    // Approval by several admins of settings, admin, role, signer, sale phase and royalty changes, which are proposed
+    // instead of applied when set. Pausing or unpausing minting on its own is left to pausers.
+    #[serde(default)]
+    pub multisig: Option<MultisigSettings>,
 }
 
 #[cw_serde]
+pub struct MultisigSettings {
+    pub threshold: u32, // Number of admin approvals, the proposer's included, needed to execute a proposal
+    pub proposal_duration: u64, // Seconds a proposal stays open for approvals
+}
+
+#[cw_serde]
+pub struct DutchAuction {
+    pub start_price: Uint128, // Price per token in the public sale denom until the auction starts
+    pub floor_price: Uint128, // Lowest price the auction declines to
+    pub price_decrement: Uint128, // Amount the price drops by at the end of each interval
+    pub decrement_interval: u64, // Seconds
+    pub start_timestamp: Uint128, // Epoch seconds
+    // When enabled, payments above the floor price are held until the clearing price is final,
+    // and every buyer can then be refunded the difference to the clearing price
+    pub rebates_enabled: bool,
+}
+
+#[cw_serde]
+pub struct PublicSaleSettings {
+    pub price: Uint128, // Price per token in the sale denom
+    pub denom: String,
+    pub start_timestamp: Uint128, // Epoch seconds
+    pub end_timestamp: Option<Uint128>, // Epoch seconds, open ended if not set
+    pub base_uri: String, // Token uri is the base uri followed by the token id
+    pub sale_recipient: String, // Address
+}
+
+#[cw_serde]
+pub struct SalePhase {
+    pub name: String, // Unique among the minter's phases
+    pub start_timestamp: Uint128, // Epoch seconds
+    pub end_timestamp: Uint128, // Epoch seconds
+    pub price: Uint128, // Price per token in the phase denom
+    pub denom: String,
+    pub max_mints_per_wallet: Option<u32>, // Tokens a single recipient can mint during the phase, unlimited if not set
+    pub eligibility: PhaseEligibility,
+}
+
+#[cw_serde]
+pub enum PhaseEligibility {
+    // Mints authorized by a signed mint request
+    SignedRequest,
+    // Anyone, through a public mint
+    Public,
+    // Holders of an allowlist proof, checked against the phase's own root or else the minter's root
+    Allowlist {
+        merkle_root: Option<String>,
+    },
+}
+
+// Proof that SHA-256(address bytes || allowance as big endian u32) is a leaf of the allowlist merkle tree.
+// Parent nodes are the SHA-256 of their two children in ascending byte order.
+#[cw_serde]
+pub struct AllowlistProof {
+    pub allowance: u32, // Tokens the address may mint
+    pub proof: Vec<String>, // Hex encoded sibling hashes, from the leaf up to the root
+}
+
+#[cw_serde]
+pub struct PlatformFee {
+    pub fee_address: String, // Address
+    pub share: Decimal, // Fraction of the sale price, at most one
+}
+
+#[cw_serde]
+pub struct RevenueSplit {
+    pub recipient: String, // Address
+    pub share: Decimal, // Fraction of the proceeds, all shares together must not exceed one
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub enum OverpaymentMode {
+    // Payments must match the price exactly
+    #[default]
+    Strict,
+    // The excess above the price is returned to the payer
+    Refund,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub enum SignatureScheme {
+    // SHA-256 of the raw message bytes
+    #[default]
+    Sha256,
+    // Keccak-256 of the message bytes with the EIP-191 personal_sign prefix
+    Eip191,
+    // EIP-712 typed data hash of the mint request
+    Eip712,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub enum MintRequestEncoding {
+    // Serde JSON of the mint request, as produced by to_json_binary
+    #[default]
+    Json,
+    // Canonical binary encoding, see MintRequest::to_canonical_bytes
+    CanonicalV1,
+}
+
+#[cw_serde]
+#[derive(Default)]
 pub struct UpdateDegaMinterConfigSettingsMsg {
     pub signer_pub_key: Option<String>,
     pub minting_paused: Option<bool>,
+    pub signer_address: Option<Option<String>>,
+    pub signature_scheme: Option<SignatureScheme>,
+    pub mint_request_encoding: Option<MintRequestEncoding>,
+    pub revenue_splits: Option<Vec<RevenueSplit>>,
+    pub platform_fee: Option<Option<PlatformFee>>,
+    pub max_supply: Option<u64>,
+    pub max_mints_per_wallet: Option<Option<u32>>,
+    pub public_sale: Option<Option<PublicSaleSettings>>,
+    pub allowlist_merkle_root: Option<Option<String>>,
+    pub dutch_auction: Option<Option<DutchAuction>>,
+    pub overpayment_mode: Option<OverpaymentMode>,
+    pub admin_action_delay: Option<u64>,
+    pub multisig: Option<Option<MultisigSettings>>,
 }
 
 #[cw_serde]
@@ -48,13 +206,98 @@ pub enum ExecuteMsg {
         request: MintRequest,
         signature: String,
     },
+    // The signer public key, signer address and admin action delay are not updated directly but queued as admin
+    // actions, the other settings are updated immediately
     UpdateSettings {
         settings: UpdateDegaMinterConfigSettingsMsg,
     },
+    // Queues the admin change as an admin action, added admins must accept their nomination
     UpdateAdmin {
         address: String,
         command: UpdateAdminCommand,
     },
+    // Granting or revoking the super admin role queues adding or removing the address as an admin
+    GrantRole {
+        address: String,
+        role: Role,
+    },
+    RevokeRole {
+        address: String,
+        role: Role,
+    },
+    // Signers are added and removed through queued admin actions
+    AddSigner {
+        label: String,
+        signer: SignerKeyInfo,
+    },
+    RemoveSigner {
+        label: String,
+    },
+    // Carries out a queued admin action once its delay has passed, other than adding an admin
+    ExecuteAdminAction {
+        id: u64,
+    },
+    // Carries out a queued admin nomination of the sender once its delay has passed
+    AcceptAdminNomination {
+        id: u64,
+    },
+    // Removes a queued admin action, open to admins and the proposer of the action
+    CancelAdminAction {
+        id: u64,
+    },
+    // Updates the royalty settings of the collection, open to admins and collection editors
+    UpdateRoyaltySettings {
+        royalty_settings: Option<RoyaltySettingsResponse>,
+    },
+    // Adds the sender's approval to an open multisig proposal, which is executed once the threshold is reached
+    ApproveProposal {
+        id: u64,
+    },
+    // Admin mint of one token per (recipient address, token uri) pair, without signature or payment
+    Airdrop {
+        recipients: Vec<(String, String)>,
+    },
+    // Mint paid for in CW20 tokens, the hook message is a ReceiveMsg
+    Receive(Cw20ReceiveMsg),
+    // Mint of the next token to the sender at the public sale price, without a signed mint request
+    PublicMint {},
+    // Public mint restricted to the allowlist, the proof is for the sender's address. Outside of sale phases it is held
+    // to the public sale window, earlier allowlist sales are scheduled as allowlist phases
+    AllowlistMint {
+        proof: AllowlistProof,
+    },
+    // Refunds the address (the sender if not set) the difference between what it paid in the dutch auction
+    // and the clearing price, and releases the rest of its held payments to the sale recipients
+    ClaimAuctionRebate {
+        address: Option<String>,
+    },
+    // Replaces the scheduled sale phases, phases must be in chronological order and not overlap
+    UpdatePhases {
+        phases: Vec<SalePhase>,
+    },
+    // Registers the UUIDs of issued mint requests as revoked, so the requests can no longer be used to mint
+    RevokeMintRequests {
+        uuids: Vec<String>,
+    },
+    // Removes up to limit used UUIDs whose mint request validity has ended from the registry, open to anyone
+    PruneUuids {
+        limit: u32,
+    },
+}
+
+// Admins are super admins and hold every other role as well
+#[cw_serde]
+pub enum Role {
+    // Manages roles and admins, and any settings not covered by another role
+    SuperAdmin,
+    // Pauses and unpauses minting
+    Pauser,
+    // Manages signer keys, signing settings and revocation of mint requests
+    SignerManager,
+    // Edits the collection info and royalties of the collection contract
+    CollectionEditor,
+    // Airdrops tokens
+    Airdropper,
 }
 
 #[cw_serde]
@@ -63,17 +306,243 @@ pub enum UpdateAdminCommand {
     Remove,
 }
 
+// Changes to admins and signing keys, which wait out the admin action delay before they can be carried out
+#[cw_serde]
+pub enum AdminAction {
+    AddAdmin {
+        address: String,
+    },
+    RemoveAdmin {
+        address: String,
+    },
+    AddSigner {
+        label: String,
+        signer: SignerKeyInfo,
+    },
+    RemoveSigner {
+        label: String,
+    },
+    UpdateSignerPubKey {
+        signer_pub_key: String,
+    },
+    UpdateSignerAddress {
+        signer_address: Option<String>,
+    },
+    UpdateAdminActionDelay {
+        admin_action_delay: u64,
+    },
+}
+
+// Operations proposed for approval by the admins while multisig approval is enabled
+#[cw_serde]
+#[allow(clippy::large_enum_variant)]
+pub enum ProposalAction {
+    UpdateSettings {
+        settings: UpdateDegaMinterConfigSettingsMsg,
+    },
+    UpdateAdmin {
+        address: String,
+        command: UpdateAdminCommand,
+    },
+    UpdateRoyaltySettings {
+        royalty_settings: Option<RoyaltySettingsResponse>,
+    },
+    // Role changes other than to the super admin role, which are proposed as admin updates
+    GrantRole {
+        address: String,
+        role: Role,
+    },
+    RevokeRole {
+        address: String,
+        role: Role,
+    },
+    AddSigner {
+        label: String,
+        signer: SignerKeyInfo,
+    },
+    RemoveSigner {
+        label: String,
+    },
+    UpdatePhases {
+        phases: Vec<SalePhase>,
+    },
+}
+
+#[cw_serde]
+pub enum ProposalStatus {
+    Open,
+    Executed,
+    Expired,
+}
+
+#[cw_serde]
+pub struct PendingAdminAction {
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: String,
+    pub executable_after: Uint128, // Epoch seconds
+}
+
+// Hook messages accepted through a CW20 send to the minter
+#[cw_serde]
+pub enum ReceiveMsg {
+    Mint {
+        request: MintRequest,
+        signature: String,
+    },
+}
+
 #[cw_serde]
 pub struct MintRequest {
     pub to: String, // Address
     pub primary_sale_recipient: String, // Address
     pub uri: String, // string (URI)
+    // Token uris of the minted tokens in order, one per token in place of the uri when not empty
+    #[serde(default)]
+    pub uris: Vec<String>,
+    pub quantity: u32, // Number of tokens minted, each at the price
     pub price: Uint256, // uint256
-    pub currency: String, // Address
+    pub currency: String, // Native denom, or CW20 contract address
     pub validity_start_timestamp: Uint128, // uint128
     pub validity_end_timestamp: Uint128, // uint128
     pub uuid: String, // UUIDv4
     pub collection: String, // Address
+    pub chain_id: String, // Chain ID the request is valid on
+    pub minter: String, // Address of the minter contract the request is valid for
+}
+
+pub const MINT_REQUEST_CANONICAL_V1_DOMAIN: &str = "DEGA_INJ_MINT_REQUEST_V1";
+
+impl MintRequest {
+    /// Canonical binary encoding of the mint request, independent of JSON field order and formatting.
+    ///
+    /// The encoding is the concatenation of the following items, each prefixed with its length
+    /// in bytes as a 4 byte big-endian unsigned integer:
+    ///
+    /// 1. The domain separator `DEGA_INJ_MINT_REQUEST_V1` (UTF-8)
+    /// 2. `to` (UTF-8)
+    /// 3. `primary_sale_recipient` (UTF-8)
+    /// 4. `uri` (UTF-8)
+    /// 5. `uris` (each uri in order, UTF-8 and prefixed with its own length in the same way)
+    /// 6. `quantity` (4 byte big-endian unsigned integer)
+    /// 7. `price` (32 byte big-endian unsigned integer)
+    /// 8. `currency` (UTF-8)
+    /// 9. `validity_start_timestamp` (16 byte big-endian unsigned integer)
+    /// 10. `validity_end_timestamp` (16 byte big-endian unsigned integer)
+    /// 11. `uuid` (UTF-8)
+    /// 12. `collection` (UTF-8)
+    /// 13. `chain_id` (UTF-8)
+    /// 14. `minter` (UTF-8)
+    pub fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut uris = vec![];
+        for uri in &self.uris {
+            uris.extend_from_slice(&(uri.len() as u32).to_be_bytes());
+            uris.extend_from_slice(uri.as_bytes());
+        }
+
+        let fields: [&[u8]; 14] = [
+            MINT_REQUEST_CANONICAL_V1_DOMAIN.as_bytes(),
+            self.to.as_bytes(),
+            self.primary_sale_recipient.as_bytes(),
+            self.uri.as_bytes(),
+            &uris,
+            &self.quantity.to_be_bytes(),
+            &self.price.to_be_bytes(),
+            self.currency.as_bytes(),
+            &self.validity_start_timestamp.to_be_bytes(),
+            &self.validity_end_timestamp.to_be_bytes(),
+            self.uuid.as_bytes(),
+            self.collection.as_bytes(),
+            self.chain_id.as_bytes(),
+            self.minter.as_bytes(),
+        ];
+
+        let mut encoded = Vec::with_capacity(fields.iter().map(|field| field.len() + 4).sum());
+        for field in fields {
+            encoded.extend_from_slice(&(field.len() as u32).to_be_bytes());
+            encoded.extend_from_slice(field);
+        }
+
+        encoded
+    }
+}
+
+#[cw_serde]
+pub struct SignerKeyInfo {
+    pub pub_key: String, // Base64 compressed secp256k1 public key
+    pub activation_timestamp: Option<Uint128>, // Epoch seconds, active immediately if not set
+    pub expiration_timestamp: Option<Uint128>, // Epoch seconds, never expires if not set
+}
+
+#[cw_serde]
+pub struct SignerKeyResponse {
+    pub label: String,
+    pub signer: SignerKeyInfo,
+    pub is_active: bool,
+}
+
+#[cw_serde]
+pub struct SignersResponse {
+    pub signers: Vec<SignerKeyResponse>,
+}
+
+#[cw_serde]
+pub struct SupplyResponse {
+    pub max_supply: Option<u64>,
+    pub minted: u64,
+    pub remaining: Option<u64>, // Unlimited if not set
+}
+
+#[cw_serde]
+pub struct PhasesResponse {
+    pub phases: Vec<SalePhase>,
+}
+
+#[cw_serde]
+pub struct CurrentPhaseResponse {
+    pub phase: Option<SalePhase>, // None if no phase is active at the current block time
+}
+
+#[cw_serde]
+pub struct CurrentPriceResponse {
+    pub price: Uint128, // Price of a public mint at the current block time
+    pub denom: String,
+    // Price every dutch auction buyer ends up paying, once final
+    pub auction_clearing_price: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct MintCountResponse {
+    pub address: String,
+    pub count: u32, // Tokens minted to the address through mint requests
+    pub limit: Option<u32>, // Unlimited if not set
+}
+
+#[cw_serde]
+pub enum UuidStatus {
+    Unused,
+    Used,
+    Revoked,
+}
+
+#[cw_serde]
+pub struct UuidStatusResponse {
+    pub uuid: String,
+    pub status: UuidStatus,
+    // Tokens minted by the mint request, empty unless used or if the request was used before token ids were recorded
+    pub token_ids: Vec<u64>,
+}
+
+#[cw_serde]
+pub struct UuidsResponse {
+    pub uuids: Vec<UuidStatusResponse>,
+}
+
+#[cw_serde]
+pub struct UuidCountResponse {
+    // Used UUIDs which have not been pruned. Revoked UUIDs are listed by the Uuids query but never pruned, so they
+    // are left out of the count.
+    pub count: u64,
 }
 
 #[cw_serde]
@@ -81,6 +550,19 @@ pub struct CheckSigResponse {
     pub is_valid: bool,
     pub message_hash_hex: String,
     pub error: Option<String>,
+    // Label of the key that produced the signature, when checking against the minter's signers
+    pub signer_label: Option<String>,
+    // Injective address recovered from a 65 byte (r, s, v) signature
+    pub recovered_address: Option<String>,
+}
+
+#[cw_serde]
+pub struct CheckAllowlistResponse {
+    pub is_valid: bool,
+    pub leaf_hash_hex: String,
+    // Root the proof was checked against, from the active sale phase or else the minter settings
+    pub merkle_root: Option<String>,
+    pub error: Option<String>,
 }
 
 #[cw_serde]
@@ -88,6 +570,32 @@ pub struct AdminsResponse {
     pub admins: Vec<String>,
 }
 
+#[cw_serde]
+pub struct RolesResponse {
+    pub address: String,
+    pub roles: Vec<Role>, // Super admin for admins, followed by the roles granted to the address
+}
+
+#[cw_serde]
+pub struct ProposalResponse {
+    pub id: u64,
+    pub action: ProposalAction,
+    pub proposer: String,
+    pub approvals: Vec<String>, // Admins who approved the proposal, in order of approval
+    pub expiration: Uint128, // Epoch seconds
+    pub status: ProposalStatus,
+}
+
+#[cw_serde]
+pub struct ProposalsResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+#[cw_serde]
+pub struct PendingAdminActionsResponse {
+    pub actions: Vec<PendingAdminAction>,
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 #[allow(clippy::large_enum_variant)]
@@ -102,16 +610,88 @@ pub enum QueryMsg {
         signer_source: SignerSourceType,
     },
 
+    #[returns(CheckAllowlistResponse)]
+    CheckAllowlist {
+        address: String,
+        proof: AllowlistProof,
+    },
+
+    // Admins in ascending address order, after start_after when set
     #[returns(AdminsResponse)]
-    Admins {},
+    Admins {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 
     #[returns(bool)]
     IsAdmin {
         address: String,
     },
+
+    #[returns(RolesResponse)]
+    Roles {
+        address: String,
+    },
+
+    // Whether the address holds the role, either granted directly or as a super admin
+    #[returns(bool)]
+    HasRole {
+        address: String,
+        role: Role,
+    },
+
+    #[returns(SignersResponse)]
+    Signers {},
+
+    #[returns(SupplyResponse)]
+    Supply {},
+
+    #[returns(MintCountResponse)]
+    MintCount {
+        address: String,
+    },
+
+    #[returns(PhasesResponse)]
+    Phases {},
+
+    #[returns(CurrentPhaseResponse)]
+    CurrentPhase {},
+
+    #[returns(CurrentPriceResponse)]
+    CurrentPrice {},
+
+    #[returns(UuidStatusResponse)]
+    UuidStatus {
+        uuid: String,
+    },
+
+    // Used and revoked UUIDs still in the registry, in ascending UUID order after start_after when set
+    #[returns(UuidsResponse)]
+    Uuids {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // Number of used UUIDs still in the registry, revoked UUIDs are not counted
+    #[returns(UuidCountResponse)]
+    UuidCount {},
+
+    // Queued admin actions in the order they were proposed
+    #[returns(PendingAdminActionsResponse)]
+    PendingAdminActions {},
+
+    #[returns(ProposalResponse)]
+    Proposal {
+        id: u64,
+    },
+
+    // Multisig proposals still open for approval
+    #[returns(ProposalsResponse)]
+    Proposals {},
 }
 
 #[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum VerifiableMsg {
     String(String),
     MintRequest(MintRequest),
@@ -121,15 +701,17 @@ pub enum VerifiableMsg {
 pub enum SignerSourceType {
     ConfigSignerPubKey,
     PubKeyBinary(String),
-
-    // Bottom two disabled because pubkey lookup by address is not implemented
-    //ConfigSignerAddress,
-    //Address(String),
+    // The config signer key along with any additional signer keys active at the current block time
+    ActiveSigners,
+    // Public key looked up for the signer address in the config
+    ConfigSignerAddress,
+    // Public key looked up for the provided address, or matched against the address
+    // recovered from the signature. Accepts both inj bech32 and 0x hex addresses.
+    Address(String),
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::Decimal;
     use crate::cw721::{CollectionInfoResponse, RoyaltySettingsResponse};
     use crate::test_helpers::test_serde;
     use super::*;
@@ -146,6 +728,42 @@ mod tests {
                 dega_minter_settings: DegaMinterConfigSettings {
                     signer_pub_key: "pubkey".to_string(),
                     minting_paused: false,
+                    signer_address: Some("signer_addr".to_string()),
+                    signature_scheme: SignatureScheme::Eip191,
+                    mint_request_encoding: MintRequestEncoding::CanonicalV1,
+                    revenue_splits: vec![RevenueSplit {
+                        recipient: "artist_addr".to_string(),
+                        share: Decimal::percent(40),
+                    }],
+                    platform_fee: Some(PlatformFee {
+                        fee_address: "platform_addr".to_string(),
+                        share: Decimal::percent(5),
+                    }),
+                    max_supply: Some(1000),
+                    max_mints_per_wallet: Some(3),
+                    public_sale: Some(PublicSaleSettings {
+                        price: Uint128::new(100),
+                        denom: "inj".to_string(),
+                        start_timestamp: Uint128::new(1000),
+                        end_timestamp: None,
+                        base_uri: "https://example.com/".to_string(),
+                        sale_recipient: "sale_recipient_addr".to_string(),
+                    }),
+                    allowlist_merkle_root: Some("merkle_root".to_string()),
+                    dutch_auction: Some(DutchAuction {
+                        start_price: Uint128::new(1000),
+                        floor_price: Uint128::new(100),
+                        price_decrement: Uint128::new(50),
+                        decrement_interval: 600,
+                        start_timestamp: Uint128::new(1000),
+                        rebates_enabled: true,
+                    }),
+                    overpayment_mode: OverpaymentMode::Refund,
+                    admin_action_delay: 86400,
+                    multisig: Some(MultisigSettings {
+                        threshold: 2,
+                        proposal_duration: 604800,
+                    }),
                 },
                 initial_admin: "admin_addr".to_string(),
             },
@@ -176,22 +794,266 @@ mod tests {
             settings: UpdateDegaMinterConfigSettingsMsg {
                 signer_pub_key: Some("new_key".to_string()),
                 minting_paused: Some(true),
+                signer_address: Some(None),
+                signature_scheme: Some(SignatureScheme::Eip712),
+                mint_request_encoding: Some(MintRequestEncoding::CanonicalV1),
+                revenue_splits: Some(vec![]),
+                platform_fee: Some(None),
+                max_supply: Some(500),
+                max_mints_per_wallet: Some(None),
+                public_sale: Some(None),
+                allowlist_merkle_root: Some(None),
+                dutch_auction: Some(None),
+                overpayment_mode: Some(OverpaymentMode::Refund),
+                admin_action_delay: Some(3600),
+                multisig: Some(None),
             },
         });
 
+        // Settings saved before the signature scheme was added default to SHA-256
+        let legacy_settings: DegaMinterConfigSettings = cosmwasm_std::from_json(
+            r#"{"signer_pub_key":"pubkey","minting_paused":false,"signer_address":null}"#
+        ).unwrap();
+        assert_eq!(legacy_settings.signature_scheme, SignatureScheme::Sha256);
+        assert_eq!(legacy_settings.mint_request_encoding, MintRequestEncoding::Json);
+        assert!(legacy_settings.revenue_splits.is_empty());
+        assert!(legacy_settings.platform_fee.is_none());
+        assert!(legacy_settings.max_supply.is_none());
+        assert!(legacy_settings.max_mints_per_wallet.is_none());
+        assert!(legacy_settings.public_sale.is_none());
+        assert!(legacy_settings.allowlist_merkle_root.is_none());
+        assert!(legacy_settings.dutch_auction.is_none());
+        assert_eq!(legacy_settings.overpayment_mode, OverpaymentMode::Strict);
+        assert_eq!(legacy_settings.admin_action_delay, 0);
+        assert!(legacy_settings.multisig.is_none());
+
         test_serde(&UpdateAdminCommand::Add);
 
+        test_serde(&ExecuteMsg::GrantRole {
+            address: "address".to_string(),
+            role: Role::Pauser,
+        });
+
+        test_serde(&ExecuteMsg::RevokeRole {
+            address: "address".to_string(),
+            role: Role::SuperAdmin,
+        });
+
+        test_serde(&ExecuteMsg::ExecuteAdminAction {
+            id: 1,
+        });
+
+        test_serde(&ExecuteMsg::AcceptAdminNomination {
+            id: 2,
+        });
+
+        test_serde(&ExecuteMsg::CancelAdminAction {
+            id: 3,
+        });
+
+        test_serde(&PendingAdminActionsResponse {
+            actions: vec![PendingAdminAction {
+                id: 1,
+                action: AdminAction::AddSigner {
+                    label: "backup".to_string(),
+                    signer: SignerKeyInfo {
+                        pub_key: "pubkey".to_string(),
+                        activation_timestamp: None,
+                        expiration_timestamp: Some(Uint128::new(2000)),
+                    },
+                },
+                proposer: "admin_addr".to_string(),
+                executable_after: Uint128::new(1000),
+            }],
+        });
+
+        test_serde(&QueryMsg::PendingAdminActions {});
+
+        test_serde(&ExecuteMsg::UpdateRoyaltySettings {
+            royalty_settings: Some(RoyaltySettingsResponse {
+                payment_address: "payment_addr".to_string(),
+                share: Decimal::percent(10),
+            }),
+        });
+
+        test_serde(&ExecuteMsg::ApproveProposal {
+            id: 1,
+        });
+
+        test_serde(&ProposalsResponse {
+            proposals: vec![ProposalResponse {
+                id: 1,
+                action: ProposalAction::UpdateAdmin {
+                    address: "new_admin_addr".to_string(),
+                    command: UpdateAdminCommand::Add,
+                },
+                proposer: "admin_addr".to_string(),
+                approvals: vec!["admin_addr".to_string()],
+                expiration: Uint128::new(2000),
+                status: ProposalStatus::Open,
+            }],
+        });
+
+        test_serde(&QueryMsg::Proposal {
+            id: 1,
+        });
+
+        test_serde(&QueryMsg::Proposals {});
+
+        test_serde(&QueryMsg::Roles {
+            address: "address".to_string(),
+        });
+
+        test_serde(&QueryMsg::HasRole {
+            address: "address".to_string(),
+            role: Role::CollectionEditor,
+        });
+
+        test_serde(&RolesResponse {
+            address: "address".to_string(),
+            roles: vec![Role::SignerManager, Role::Airdropper],
+        });
+
+        test_serde(&ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "buyer_addr".to_string(),
+            amount: Uint128::new(100),
+            msg: cosmwasm_std::to_json_binary(&ReceiveMsg::Mint {
+                request: MintRequest {
+                    to: "receiver_addr".to_string(),
+                    primary_sale_recipient: "sale_recipient_addr".to_string(),
+                    uri: "https://example.com/".to_string(),
+                    uris: vec![],
+                    quantity: 1,
+                    price: Uint256::from(100u128),
+                    currency: "cw20_addr".to_string(),
+                    validity_start_timestamp: Uint128::new(1000),
+                    validity_end_timestamp: Uint128::new(2000),
+                    uuid: "uuid".to_string(),
+                    collection: "collection_addr".to_string(),
+                    chain_id: "injective-1".to_string(),
+                    minter: "minter_addr".to_string(),
+                },
+                signature: "signature".to_string(),
+            }).unwrap(),
+        }));
+
+        test_serde(&ExecuteMsg::PublicMint {});
+
+        let sale_phase = SalePhase {
+            name: "presale".to_string(),
+            start_timestamp: Uint128::new(1000),
+            end_timestamp: Uint128::new(2000),
+            price: Uint128::new(100),
+            denom: "inj".to_string(),
+            max_mints_per_wallet: Some(2),
+            eligibility: PhaseEligibility::SignedRequest,
+        };
+
+        test_serde(&ExecuteMsg::UpdatePhases {
+            phases: vec![sale_phase.clone()],
+        });
+
+        test_serde(&PhasesResponse {
+            phases: vec![sale_phase.clone()],
+        });
+
+        test_serde(&CurrentPhaseResponse {
+            phase: Some(sale_phase),
+        });
+
+        test_serde(&QueryMsg::CurrentPhase {});
+
+        test_serde(&ExecuteMsg::RevokeMintRequests {
+            uuids: vec!["uuid".to_string()],
+        });
+
+        test_serde(&ExecuteMsg::PruneUuids {
+            limit: 100,
+        });
+
+        test_serde(&UuidStatusResponse {
+            uuid: "uuid".to_string(),
+            status: UuidStatus::Used,
+            token_ids: vec![1, 2],
+        });
+
+        test_serde(&QueryMsg::UuidStatus {
+            uuid: "uuid".to_string(),
+        });
+
+        test_serde(&QueryMsg::Uuids {
+            start_after: Some("uuid".to_string()),
+            limit: Some(10),
+        });
+
+        test_serde(&UuidsResponse {
+            uuids: vec![UuidStatusResponse {
+                uuid: "uuid".to_string(),
+                status: UuidStatus::Revoked,
+                token_ids: vec![],
+            }],
+        });
+
+        test_serde(&QueryMsg::UuidCount {});
+
+        test_serde(&UuidCountResponse {
+            count: 2,
+        });
+
+        test_serde(&ExecuteMsg::ClaimAuctionRebate {
+            address: Some("buyer_addr".to_string()),
+        });
+
+        test_serde(&CurrentPriceResponse {
+            price: Uint128::new(100),
+            denom: "inj".to_string(),
+            auction_clearing_price: None,
+        });
+
+        let allowlist_proof = AllowlistProof {
+            allowance: 2,
+            proof: vec!["sibling_hash".to_string()],
+        };
+
+        test_serde(&PhaseEligibility::Allowlist {
+            merkle_root: None,
+        });
+
+        test_serde(&ExecuteMsg::AllowlistMint {
+            proof: allowlist_proof.clone(),
+        });
+
+        test_serde(&QueryMsg::CheckAllowlist {
+            address: "buyer_addr".to_string(),
+            proof: allowlist_proof,
+        });
+
+        test_serde(&CheckAllowlistResponse {
+            is_valid: true,
+            leaf_hash_hex: "leaf_hash".to_string(),
+            merkle_root: Some("merkle_root".to_string()),
+            error: None,
+        });
+
+        test_serde(&ExecuteMsg::Airdrop {
+            recipients: vec![("receiver_addr".to_string(), "https://example.com/1".to_string())],
+        });
+
         test_serde(&ExecuteMsg::Mint {
             request: MintRequest {
                 to: "receiver_addr".to_string(),
                 primary_sale_recipient: "sale_recipient_addr".to_string(),
                 uri: "https://example.com/".to_string(),
+                uris: vec![],
+                quantity: 1,
                 price: Uint256::from(100u128),
                 currency: "inj".to_string(),
                 validity_start_timestamp: Uint128::new(1000),
                 validity_end_timestamp: Uint128::new(1500),
                 uuid: "UUIDv4".to_string(),
                 collection: "collection_addr".to_string(),
+                chain_id: "injective-1".to_string(),
+                minter: "minter_addr".to_string(),
             },
             signature: "signature".to_string(),
         });
@@ -200,6 +1062,49 @@ mod tests {
             is_valid: false,
             message_hash_hex: "hashhex".to_string(),
             error: Some("error".to_string()),
+            signer_label: Some("primary".to_string()),
+            recovered_address: Some("inj_addr".to_string()),
+        });
+
+        test_serde(&SignatureScheme::Eip712);
+        test_serde(&OverpaymentMode::Refund);
+        test_serde(&MintRequestEncoding::CanonicalV1);
+
+        test_serde(&ExecuteMsg::AddSigner {
+            label: "backend-2".to_string(),
+            signer: SignerKeyInfo {
+                pub_key: "pubkey".to_string(),
+                activation_timestamp: Some(Uint128::new(1000)),
+                expiration_timestamp: None,
+            },
+        });
+
+        test_serde(&SupplyResponse {
+            max_supply: Some(1000),
+            minted: 10,
+            remaining: Some(990),
+        });
+
+        test_serde(&QueryMsg::MintCount {
+            address: "buyer_addr".to_string(),
+        });
+
+        test_serde(&MintCountResponse {
+            address: "buyer_addr".to_string(),
+            count: 2,
+            limit: Some(3),
+        });
+
+        test_serde(&SignersResponse {
+            signers: vec![SignerKeyResponse {
+                label: "backend-2".to_string(),
+                signer: SignerKeyInfo {
+                    pub_key: "pubkey".to_string(),
+                    activation_timestamp: None,
+                    expiration_timestamp: Some(Uint128::new(1500)),
+                },
+                is_active: true,
+            }],
         });
 
         test_serde(&QueryMsg::CheckSig {
@@ -208,10 +1113,91 @@ mod tests {
             signer_source: SignerSourceType::PubKeyBinary("pubkey".to_string()),
         });
 
+        test_serde(&QueryMsg::CheckSig {
+            message: VerifiableMsg::String("message".to_string()),
+            signature: "signature".to_string(),
+            signer_source: SignerSourceType::Address("signer_addr".to_string()),
+        });
+
         test_serde(&AdminsResponse {
             admins: vec!["admin1".to_string(), "admin2".to_string()],
         });
 
+        test_serde(&QueryMsg::Admins {
+            start_after: Some("admin1".to_string()),
+            limit: None,
+        });
+
+
+    }
+
+    #[test]
+    fn canonical_encoding() {
+        let request = MintRequest {
+            to: "to".to_string(),
+            primary_sale_recipient: "psr".to_string(),
+            uri: "u".to_string(),
+            uris: vec![],
+            quantity: 3,
+            price: Uint256::from(258u128),
+            currency: "inj".to_string(),
+            validity_start_timestamp: Uint128::new(1),
+            validity_end_timestamp: Uint128::new(2),
+            uuid: "id".to_string(),
+            collection: "c".to_string(),
+            chain_id: "ch".to_string(),
+            minter: "m".to_string(),
+        };
+
+        let mut expected: Vec<u8> = vec![];
+        expected.extend_from_slice(&[0, 0, 0, 24]);
+        expected.extend_from_slice(b"DEGA_INJ_MINT_REQUEST_V1");
+        expected.extend_from_slice(&[0, 0, 0, 2, b't', b'o']);
+        expected.extend_from_slice(&[0, 0, 0, 3, b'p', b's', b'r']);
+        expected.extend_from_slice(&[0, 0, 0, 1, b'u']);
+        expected.extend_from_slice(&[0, 0, 0, 0]);
+        expected.extend_from_slice(&[0, 0, 0, 4, 0, 0, 0, 3]);
+        expected.extend_from_slice(&[0, 0, 0, 32]);
+        expected.extend_from_slice(&[0u8; 30]);
+        expected.extend_from_slice(&[1, 2]);
+        expected.extend_from_slice(&[0, 0, 0, 3, b'i', b'n', b'j']);
+        expected.extend_from_slice(&[0, 0, 0, 16]);
+        expected.extend_from_slice(&[0u8; 15]);
+        expected.extend_from_slice(&[1]);
+        expected.extend_from_slice(&[0, 0, 0, 16]);
+        expected.extend_from_slice(&[0u8; 15]);
+        expected.extend_from_slice(&[2]);
+        expected.extend_from_slice(&[0, 0, 0, 2, b'i', b'd']);
+        expected.extend_from_slice(&[0, 0, 0, 1, b'c']);
+        expected.extend_from_slice(&[0, 0, 0, 2, b'c', b'h']);
+        expected.extend_from_slice(&[0, 0, 0, 1, b'm']);
+
+        assert_eq!(request.to_canonical_bytes(), expected);
 
+        // Length prefixes keep adjacent fields from being ambiguous
+        let mut shifted_request = request.clone();
+        shifted_request.to = "top".to_string();
+        shifted_request.primary_sale_recipient = "sr".to_string();
+        assert_ne!(request.to_canonical_bytes(), shifted_request.to_canonical_bytes());
+
+        // Field order in the JSON does not affect the encoding
+        let reordered: MintRequest = cosmwasm_std::from_json(
+            r#"{"minter":"m","chain_id":"ch","collection":"c","uuid":"id","validity_end_timestamp":"2","validity_start_timestamp":"1",
+                "currency":"inj","price":"258","quantity":3,"uri":"u","primary_sale_recipient":"psr","to":"to"}"#
+        ).unwrap();
+        assert_eq!(reordered.to_canonical_bytes(), expected);
+
+        // Each of the uris is prefixed with its own length
+        let mut bundle_request = request.clone();
+        bundle_request.uris = vec!["a".to_string(), "bc".to_string()];
+        let mut bundle_expected = expected[..46].to_vec();
+        bundle_expected.extend_from_slice(&[0, 0, 0, 11, 0, 0, 0, 1, b'a', 0, 0, 0, 2, b'b', b'c']);
+        bundle_expected.extend_from_slice(&expected[50..]);
+        assert_eq!(bundle_request.to_canonical_bytes(), bundle_expected);
+
+        let mut joined_request = request.clone();
+        joined_request.uris = vec!["abc".to_string()];
+        assert_ne!(bundle_request.to_canonical_bytes(), joined_request.to_canonical_bytes());
     }
 }
+